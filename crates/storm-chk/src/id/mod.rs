@@ -7,3 +7,6 @@ pub use self::tech::TechId;
 pub use self::unit::UnitId;
 pub use self::upgrade::UpgradeId;
 pub use self::weapon::WeaponId;
+
+pub(crate) use self::tech::default_researched;
+pub(crate) use self::upgrade::default_maximum;
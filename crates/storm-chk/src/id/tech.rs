@@ -63,4 +63,15 @@ impl TechId {
   pub const fn is_classic(&self) -> bool {
     (*self as usize) < Self::CLASSIC
   }
+
+  /// Returns `true` if the technology is researched without any settings
+  /// (abilities without research).
+  #[inline]
+  pub const fn is_default_researched(&self) -> bool {
+    default_researched(*self as usize)
+  }
+}
+
+pub(crate) const fn default_researched(index: usize) -> bool {
+  matches!(index, 4 | 6 | 12 | 14 | 18 | 23 | 28 | 29 | 34)
 }
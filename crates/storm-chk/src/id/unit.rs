@@ -1,14 +1,408 @@
-// TODO: All of this
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-#[repr(u8)]
-pub enum UnitId {
-  U1 = 0,
+#![allow(non_camel_case_types)]
+
+use core::ops::Index;
+use core::ops::IndexMut;
+
+use crate::types::Race;
+
+macro_rules! unit_ids {
+  ($($name:ident = $value:literal => $text:literal),* $(,)?) => {
+    /// A unit type.
+    ///
+    /// Hero variants are named after the unit they are based on, followed by
+    /// the name of the hero.
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    #[repr(u8)]
+    pub enum UnitId {
+      $($name = $value),*
+    }
+
+    impl UnitId {
+      /// All unit types, in order.
+      pub const ALL: [Self; Self::TOTAL] = [$(Self::$name),*];
+
+      /// Returns the name of the unit type, as shown by StarEdit.
+      pub const fn as_str(&self) -> &'static str {
+        match self {
+          $(Self::$name => $text),*
+        }
+      }
+    }
+  };
+}
+
+unit_ids! {
+  // ===========================================================================
+  // Units
+  // ===========================================================================
+  Marine = 0x00 => "Terran Marine",
+  Ghost = 0x01 => "Terran Ghost",
+  Vulture = 0x02 => "Terran Vulture",
+  Goliath = 0x03 => "Terran Goliath",
+  GoliathTurret = 0x04 => "Goliath Turret",
+  SiegeTankTankMode = 0x05 => "Terran Siege Tank (Tank Mode)",
+  SiegeTankTankModeTurret = 0x06 => "Tank Turret (Tank Mode)",
+  SCV = 0x07 => "Terran SCV",
+  Wraith = 0x08 => "Terran Wraith",
+  ScienceVessel = 0x09 => "Terran Science Vessel",
+  Firebat_GuiMontag = 0x0A => "Gui Montag (Firebat)",
+  Dropship = 0x0B => "Terran Dropship",
+  Battlecruiser = 0x0C => "Terran Battlecruiser",
+  SpiderMine = 0x0D => "Vulture Spider Mine",
+  NuclearMissile = 0x0E => "Nuclear Missile",
+  Civilian = 0x0F => "Terran Civilian",
+  Ghost_SarahKerrigan = 0x10 => "Sarah Kerrigan (Ghost)",
+  Goliath_AlanSchezar = 0x11 => "Alan Schezar (Goliath)",
+  GoliathTurret_AlanSchezar = 0x12 => "Alan Schezar Turret",
+  Vulture_JimRaynor = 0x13 => "Jim Raynor (Vulture)",
+  Marine_JimRaynor = 0x14 => "Jim Raynor (Marine)",
+  Wraith_TomKazansky = 0x15 => "Tom Kazansky (Wraith)",
+  ScienceVessel_Magellan = 0x16 => "Magellan (Science Vessel)",
+  SiegeTankTankMode_EdmundDuke = 0x17 => "Edmund Duke (Tank Mode)",
+  SiegeTankTankModeTurret_EdmundDuke = 0x18 => "Edmund Duke Turret (Tank Mode)",
+  SiegeTankSiegeMode_EdmundDuke = 0x19 => "Edmund Duke (Siege Mode)",
+  SiegeTankSiegeModeTurret_EdmundDuke = 0x1A => "Edmund Duke Turret (Siege Mode)",
+  Battlecruiser_ArcturusMengsk = 0x1B => "Arcturus Mengsk (Battlecruiser)",
+  Battlecruiser_Hyperion = 0x1C => "Hyperion (Battlecruiser)",
+  Battlecruiser_NoradII = 0x1D => "Norad II (Battlecruiser)",
+  SiegeTankSiegeMode = 0x1E => "Terran Siege Tank (Siege Mode)",
+  SiegeTankSiegeModeTurret = 0x1F => "Tank Turret (Siege Mode)",
+  Firebat = 0x20 => "Terran Firebat",
+  ScannerSweep = 0x21 => "Scanner Sweep",
+  Medic = 0x22 => "Terran Medic",
+  Larva = 0x23 => "Zerg Larva",
+  Egg = 0x24 => "Zerg Egg",
+  Zergling = 0x25 => "Zerg Zergling",
+  Hydralisk = 0x26 => "Zerg Hydralisk",
+  Ultralisk = 0x27 => "Zerg Ultralisk",
+  Broodling = 0x28 => "Zerg Broodling",
+  Drone = 0x29 => "Zerg Drone",
+  Overlord = 0x2A => "Zerg Overlord",
+  Mutalisk = 0x2B => "Zerg Mutalisk",
+  Guardian = 0x2C => "Zerg Guardian",
+  Queen = 0x2D => "Zerg Queen",
+  Defiler = 0x2E => "Zerg Defiler",
+  Scourge = 0x2F => "Zerg Scourge",
+  Ultralisk_Torrasque = 0x30 => "Torrasque (Ultralisk)",
+  Queen_Matriarch = 0x31 => "Matriarch (Queen)",
+  InfestedTerran = 0x32 => "Infested Terran",
+  InfestedTerran_InfestedKerrigan = 0x33 => "Infested Kerrigan (Infested Terran)",
+  Defiler_UncleanOne = 0x34 => "Unclean One (Defiler)",
+  Hydralisk_HunterKiller = 0x35 => "Hunter Killer (Hydralisk)",
+  Zergling_DevouringOne = 0x36 => "Devouring One (Zergling)",
+  Mutalisk_Kukulza = 0x37 => "Kukulza (Mutalisk)",
+  Guardian_Kukulza = 0x38 => "Kukulza (Guardian)",
+  Overlord_Yggdrasill = 0x39 => "Yggdrasill (Overlord)",
+  Valkyrie = 0x3A => "Terran Valkyrie",
+  MutaliskCocoon = 0x3B => "Mutalisk Cocoon",
+  Corsair = 0x3C => "Protoss Corsair",
+  DarkTemplar = 0x3D => "Protoss Dark Templar",
+  Devourer = 0x3E => "Zerg Devourer",
+  DarkArchon = 0x3F => "Protoss Dark Archon",
+  Probe = 0x40 => "Protoss Probe",
+  Zealot = 0x41 => "Protoss Zealot",
+  Dragoon = 0x42 => "Protoss Dragoon",
+  HighTemplar = 0x43 => "Protoss High Templar",
+  Archon = 0x44 => "Protoss Archon",
+  Shuttle = 0x45 => "Protoss Shuttle",
+  Scout = 0x46 => "Protoss Scout",
+  Arbiter = 0x47 => "Protoss Arbiter",
+  Carrier = 0x48 => "Protoss Carrier",
+  Interceptor = 0x49 => "Protoss Interceptor",
+  DarkTemplar_Hero = 0x4A => "Dark Templar (Hero)",
+  DarkTemplar_Zeratul = 0x4B => "Zeratul (Dark Templar)",
+  Archon_TassadarZeratul = 0x4C => "Tassadar/Zeratul (Archon)",
+  Zealot_Fenix = 0x4D => "Fenix (Zealot)",
+  Dragoon_Fenix = 0x4E => "Fenix (Dragoon)",
+  HighTemplar_Tassadar = 0x4F => "Tassadar (Templar)",
+  Scout_Mojo = 0x50 => "Mojo (Scout)",
+  Reaver_Warbringer = 0x51 => "Warbringer (Reaver)",
+  Carrier_Gantrithor = 0x52 => "Gantrithor (Carrier)",
+  Reaver = 0x53 => "Protoss Reaver",
+  Observer = 0x54 => "Protoss Observer",
+  Scarab = 0x55 => "Protoss Scarab",
+  Arbiter_Danimoth = 0x56 => "Danimoth (Arbiter)",
+  HighTemplar_Aldaris = 0x57 => "Aldaris (Templar)",
+  Scout_Artanis = 0x58 => "Artanis (Scout)",
+  Rhynadon = 0x59 => "Rhynadon (Badlands Critter)",
+  Bengalaas = 0x5A => "Bengalaas (Jungle Critter)",
+  CargoShip = 0x5B => "Unused - Cargo Ship",
+  MercenaryGunship = 0x5C => "Unused - Mercenary Gunship",
+  Scantid = 0x5D => "Scantid (Desert Critter)",
+  Kakaru = 0x5E => "Kakaru (Twilight Critter)",
+  Ragnasaur = 0x5F => "Ragnasaur (Ashworld Critter)",
+  Ursadon = 0x60 => "Ursadon (Ice World Critter)",
+  LurkerEgg = 0x61 => "Lurker Egg",
+  Corsair_Raszagal = 0x62 => "Raszagal (Corsair)",
+  Ghost_SamirDuran = 0x63 => "Samir Duran (Ghost)",
+  Ghost_AlexeiStukov = 0x64 => "Alexei Stukov (Ghost)",
+  MapRevealer = 0x65 => "Map Revealer",
+  Battlecruiser_GerardDuGalle = 0x66 => "Gerard DuGalle (Battlecruiser)",
+  Lurker = 0x67 => "Zerg Lurker",
+  InfestedTerran_InfestedDuran = 0x68 => "Infested Duran (Infested Terran)",
+  DisruptionWeb = 0x69 => "Disruption Web",
+  // ===========================================================================
+  // Buildings
+  // ===========================================================================
+  CommandCenter = 0x6A => "Terran Command Center",
+  ComsatStation = 0x6B => "Terran Comsat Station",
+  NuclearSilo = 0x6C => "Terran Nuclear Silo",
+  SupplyDepot = 0x6D => "Terran Supply Depot",
+  Refinery = 0x6E => "Terran Refinery",
+  Barracks = 0x6F => "Terran Barracks",
+  Academy = 0x70 => "Terran Academy",
+  Factory = 0x71 => "Terran Factory",
+  Starport = 0x72 => "Terran Starport",
+  ControlTower = 0x73 => "Terran Control Tower",
+  ScienceFacility = 0x74 => "Terran Science Facility",
+  CovertOps = 0x75 => "Terran Covert Ops",
+  PhysicsLab = 0x76 => "Terran Physics Lab",
+  Starbase = 0x77 => "Unused - Starbase",
+  MachineShop = 0x78 => "Terran Machine Shop",
+  RepairBay = 0x79 => "Unused - Repair Bay",
+  EngineeringBay = 0x7A => "Terran Engineering Bay",
+  Armory = 0x7B => "Terran Armory",
+  MissileTurret = 0x7C => "Terran Missile Turret",
+  Bunker = 0x7D => "Terran Bunker",
+  CrashedNoradII = 0x7E => "Norad II (Crashed Battlecruiser)",
+  IonCannon = 0x7F => "Ion Cannon",
+  UrajCrystal = 0x80 => "Uraj Crystal",
+  KhalisCrystal = 0x81 => "Khalis Crystal",
+  InfestedCommandCenter = 0x82 => "Infested Command Center",
+  Hatchery = 0x83 => "Zerg Hatchery",
+  Lair = 0x84 => "Zerg Lair",
+  Hive = 0x85 => "Zerg Hive",
+  NydusCanal = 0x86 => "Zerg Nydus Canal",
+  HydraliskDen = 0x87 => "Zerg Hydralisk Den",
+  DefilerMound = 0x88 => "Zerg Defiler Mound",
+  GreaterSpire = 0x89 => "Zerg Greater Spire",
+  QueensNest = 0x8A => "Zerg Queen's Nest",
+  EvolutionChamber = 0x8B => "Zerg Evolution Chamber",
+  UltraliskCavern = 0x8C => "Zerg Ultralisk Cavern",
+  Spire = 0x8D => "Zerg Spire",
+  SpawningPool = 0x8E => "Zerg Spawning Pool",
+  CreepColony = 0x8F => "Zerg Creep Colony",
+  SporeColony = 0x90 => "Zerg Spore Colony",
+  UnusedZergBuilding = 0x91 => "Unused Zerg Building",
+  SunkenColony = 0x92 => "Zerg Sunken Colony",
+  OvermindWithShell = 0x93 => "Zerg Overmind (With Shell)",
+  Overmind = 0x94 => "Zerg Overmind",
+  Extractor = 0x95 => "Zerg Extractor",
+  MatureChrysalis = 0x96 => "Mature Chrysalis",
+  Cerebrate = 0x97 => "Zerg Cerebrate",
+  CerebrateDaggoth = 0x98 => "Zerg Cerebrate Daggoth",
+  UnusedZergBuilding5 = 0x99 => "Unused Zerg Building 5",
+  Nexus = 0x9A => "Protoss Nexus",
+  RoboticsFacility = 0x9B => "Protoss Robotics Facility",
+  Pylon = 0x9C => "Protoss Pylon",
+  Assimilator = 0x9D => "Protoss Assimilator",
+  UnusedProtossBuilding1 = 0x9E => "Unused Protoss Building 1",
+  Observatory = 0x9F => "Protoss Observatory",
+  Gateway = 0xA0 => "Protoss Gateway",
+  UnusedProtossBuilding2 = 0xA1 => "Unused Protoss Building 2",
+  PhotonCannon = 0xA2 => "Protoss Photon Cannon",
+  CitadelOfAdun = 0xA3 => "Protoss Citadel of Adun",
+  CyberneticsCore = 0xA4 => "Protoss Cybernetics Core",
+  TemplarArchives = 0xA5 => "Protoss Templar Archives",
+  Forge = 0xA6 => "Protoss Forge",
+  Stargate = 0xA7 => "Protoss Stargate",
+  StasisCellPrison = 0xA8 => "Stasis Cell/Prison",
+  FleetBeacon = 0xA9 => "Protoss Fleet Beacon",
+  ArbiterTribunal = 0xAA => "Protoss Arbiter Tribunal",
+  RoboticsSupportBay = 0xAB => "Protoss Robotics Support Bay",
+  ShieldBattery = 0xAC => "Protoss Shield Battery",
+  KhaydarinCrystalFormation = 0xAD => "Khaydarin Crystal Formation",
+  Temple = 0xAE => "Protoss Temple",
+  XelNagaTemple = 0xAF => "Xel'Naga Temple",
+  // ===========================================================================
+  // Resources, Doodads and Special
+  // ===========================================================================
+  MineralField1 = 0xB0 => "Mineral Field (Type 1)",
+  MineralField2 = 0xB1 => "Mineral Field (Type 2)",
+  MineralField3 = 0xB2 => "Mineral Field (Type 3)",
+  Cave = 0xB3 => "Cave",
+  CaveIn = 0xB4 => "Cave-in",
+  Cantina = 0xB5 => "Cantina",
+  MiningPlatform = 0xB6 => "Mining Platform",
+  IndependentCommandCenter = 0xB7 => "Independent Command Center",
+  IndependentStarport = 0xB8 => "Independent Starport",
+  IndependentJumpGate = 0xB9 => "Independent Jump Gate",
+  Ruins = 0xBA => "Ruins",
+  KhaydarinCrystalFormationUnused = 0xBB => "Khaydarin Crystal Formation (Unused)",
+  VespeneGeyser = 0xBC => "Vespene Geyser",
+  WarpGate = 0xBD => "Warp Gate",
+  PsiDisrupter = 0xBE => "Psi Disrupter",
+  ZergMarker = 0xBF => "Zerg Marker",
+  TerranMarker = 0xC0 => "Terran Marker",
+  ProtossMarker = 0xC1 => "Protoss Marker",
+  ZergBeacon = 0xC2 => "Zerg Beacon",
+  TerranBeacon = 0xC3 => "Terran Beacon",
+  ProtossBeacon = 0xC4 => "Protoss Beacon",
+  ZergFlagBeacon = 0xC5 => "Zerg Flag Beacon",
+  TerranFlagBeacon = 0xC6 => "Terran Flag Beacon",
+  ProtossFlagBeacon = 0xC7 => "Protoss Flag Beacon",
+  PowerGenerator = 0xC8 => "Power Generator",
+  OvermindCocoon = 0xC9 => "Overmind Cocoon",
+  DarkSwarm = 0xCA => "Dark Swarm",
+  FloorMissileTrap = 0xCB => "Floor Missile Trap",
+  FloorHatch = 0xCC => "Floor Hatch (Unused)",
+  LeftUpperLevelDoor = 0xCD => "Left Upper Level Door",
+  RightUpperLevelDoor = 0xCE => "Right Upper Level Door",
+  LeftPitDoor = 0xCF => "Left Pit Door",
+  RightPitDoor = 0xD0 => "Right Pit Door",
+  FloorGunTrap = 0xD1 => "Floor Gun Trap",
+  LeftWallMissileTrap = 0xD2 => "Left Wall Missile Trap",
+  LeftWallFlameTrap = 0xD3 => "Left Wall Flame Trap",
+  RightWallMissileTrap = 0xD4 => "Right Wall Missile Trap",
+  RightWallFlameTrap = 0xD5 => "Right Wall Flame Trap",
+  StartLocation = 0xD6 => "Start Location",
+  Flag = 0xD7 => "Flag",
+  YoungChrysalis = 0xD8 => "Young Chrysalis",
+  PsiEmitter = 0xD9 => "Psi Emitter",
+  DataDisc = 0xDA => "Data Disc",
+  KhaydarinCrystal = 0xDB => "Khaydarin Crystal",
+  MineralCluster1 = 0xDC => "Mineral Cluster Type 1",
+  MineralCluster2 = 0xDD => "Mineral Cluster Type 2",
+  ProtossGasOrb1 = 0xDE => "Protoss Gas Orb Type 1",
+  ProtossGasOrb2 = 0xDF => "Protoss Gas Orb Type 2",
+  ZergGasSac1 = 0xE0 => "Zerg Gas Sac Type 1",
+  ZergGasSac2 = 0xE1 => "Zerg Gas Sac Type 2",
+  TerranGasTank1 = 0xE2 => "Terran Gas Tank Type 1",
+  TerranGasTank2 = 0xE3 => "Terran Gas Tank Type 2",
 }
 
 impl UnitId {
+  /// Total number of units.
   pub const TOTAL: usize = 0xE4;
 
+  /// Returns the unit type with the given `value`, if valid.
+  #[inline]
+  pub const fn from_u16(value: u16) -> Option<Self> {
+    if (value as usize) < Self::TOTAL {
+      Some(Self::ALL[value as usize])
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  pub const fn as_u16(self) -> u16 {
+    self as u16
+  }
+
+  /// Returns `true` if the unit is part of the original game, `false` for the
+  /// units added by Brood War.
+  ///
+  /// Classic and Brood War sections still store every unit type.
+  #[inline]
   pub const fn is_classic(&self) -> bool {
-    panic!("UnitId::is_classic")
+    !matches!(
+      self,
+      Self::Medic
+        | Self::Valkyrie
+        | Self::Corsair
+        | Self::DarkTemplar
+        | Self::Devourer
+        | Self::DarkArchon
+        | Self::Arbiter_Danimoth
+        | Self::Scout_Artanis
+        | Self::Scantid
+        | Self::Kakaru
+        | Self::Ursadon
+        | Self::LurkerEgg
+        | Self::Corsair_Raszagal
+        | Self::Ghost_SamirDuran
+        | Self::Ghost_AlexeiStukov
+        | Self::Battlecruiser_GerardDuGalle
+        | Self::Lurker
+        | Self::InfestedTerran_InfestedDuran
+        | Self::DisruptionWeb
+        | Self::UrajCrystal
+        | Self::KhalisCrystal
+        | Self::XelNagaTemple
+        | Self::WarpGate
+        | Self::PsiDisrupter
+        | Self::PowerGenerator
+        | Self::OvermindCocoon
+    )
+  }
+
+  /// Returns the race of the unit, if any.
+  pub const fn race(&self) -> Option<Race> {
+    let value: u8 = match *self as u8 {
+      0x00..=0x22 | 0x3A | 0x5B | 0x5C | 0x63 | 0x64 | 0x66 => 0x01,
+      0x23..=0x39 | 0x3B | 0x3E | 0x61 | 0x67 | 0x68 => 0x00,
+      0x3C | 0x3D | 0x3F..=0x58 | 0x62 => 0x02,
+      0x6A..=0x7F | 0xC0 | 0xC3 | 0xC6 | 0xC8 | 0xCB..=0xD5 | 0xE2 | 0xE3 => 0x01,
+      0x82..=0x99 | 0xBF | 0xC2 | 0xC5 | 0xC9 | 0xE0 | 0xE1 => 0x00,
+      0x9A..=0xAF | 0xBD | 0xBE | 0xC1 | 0xC4 | 0xC7 | 0xDE | 0xDF => 0x02,
+      _ => return None,
+    };
+
+    Some(Race::from_u8(value))
+  }
+
+  /// Returns `true` if the unit is a building.
+  pub const fn is_building(&self) -> bool {
+    matches!(
+      *self as u8,
+      0x6A..=0x7F | 0x82..=0xAF | 0xB3..=0xBE | 0xC8 | 0xC9 | 0xCB..=0xD5
+    )
+  }
+
+  /// Returns `true` if the unit is a worker (SCV, Drone or Probe).
+  pub const fn is_worker(&self) -> bool {
+    matches!(self, Self::SCV | Self::Drone | Self::Probe)
+  }
+
+  /// Returns `true` if the unit is a hero.
+  pub const fn is_hero(&self) -> bool {
+    matches!(
+      *self as u8,
+      0x0A | 0x10..=0x1D | 0x30 | 0x31 | 0x33..=0x39 | 0x4A..=0x52 | 0x56..=0x58
+        | 0x62..=0x64 | 0x66 | 0x68
+    )
+  }
+
+  /// Returns `true` if the unit is a resource (mineral field or geyser).
+  pub const fn is_resource(&self) -> bool {
+    matches!(
+      self,
+      Self::MineralField1 | Self::MineralField2 | Self::MineralField3 | Self::VespeneGeyser
+    )
+  }
+
+  /// Returns `true` if the unit is a doodad unit (doors and traps), controlled
+  /// by the "Set Doodad State" action.
+  pub const fn is_doodad(&self) -> bool {
+    matches!(*self as u8, 0xCB..=0xD5)
+  }
+
+  /// Returns `true` if the unit is a marker or beacon.
+  pub const fn is_beacon(&self) -> bool {
+    matches!(*self as u8, 0xBF..=0xC7)
+  }
+
+  /// Returns `true` if the unit is a powerup (flag, crystals, resource chunks).
+  pub const fn is_powerup(&self) -> bool {
+    matches!(*self as u8, 0x80 | 0x81 | 0xD7..=0xE3)
+  }
+}
+
+impl_fmt!(UnitId -> as_str as Display);
+
+impl<T> Index<UnitId> for [T; UnitId::TOTAL] {
+  type Output = T;
+
+  #[inline]
+  fn index(&self, index: UnitId) -> &Self::Output {
+    &self[index as usize]
+  }
+}
+
+impl<T> IndexMut<UnitId> for [T; UnitId::TOTAL] {
+  #[inline]
+  fn index_mut(&mut self, index: UnitId) -> &mut Self::Output {
+    &mut self[index as usize]
   }
 }
@@ -80,4 +80,19 @@ impl UpgradeId {
   pub const fn is_classic(&self) -> bool {
     (*self as usize) < Self::CLASSIC
   }
+
+  /// Returns the maximum level of the upgrade without any settings.
+  #[inline]
+  pub const fn default_maximum(&self) -> u8 {
+    default_maximum(*self as usize)
+  }
+}
+
+// Armor, weapons and shields have 3 levels, everything else 1
+pub(crate) const fn default_maximum(index: usize) -> u8 {
+  if index <= UpgradeId::ProtossPlasmaShields as usize {
+    3
+  } else {
+    1
+  }
 }
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -71,3 +73,13 @@ impl ParseChunk for Colr {
     })
   }
 }
+
+impl WriteChunk for Colr {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.color)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Colr { color });
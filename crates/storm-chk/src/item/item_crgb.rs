@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -33,3 +35,14 @@ impl ParseChunk for Crgb {
     })
   }
 }
+
+impl WriteChunk for Crgb {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice(&self.colors, |writer, row| writer.write_bytes(row))?;
+    writer.write_bytes(&self.choice)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Crgb { colors, choice });
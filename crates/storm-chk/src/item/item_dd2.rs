@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -33,6 +35,18 @@ impl ParseChunk for Dd2 {
   }
 }
 
+impl WriteChunk for Dd2 {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.doodads.iter() {
+      item.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+serde_struct!(Dd2 { doodads });
+
 // =============================================================================
 // Dd2 Data
 // =============================================================================
@@ -66,6 +80,16 @@ impl Dd2Data {
     })
   }
 
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u16_le(self.id)?;
+    writer.write_u16_le(self.x)?;
+    writer.write_u16_le(self.y)?;
+    writer.write_u8(self.owner)?;
+    writer.write_u8(self.flag)?;
+
+    Ok(())
+  }
+
   /// Returns `true` if the doodad is enabled.
   #[inline]
   pub const fn enabled(&self) -> bool {
@@ -78,3 +102,11 @@ impl Dd2Data {
     self.flag == 0x01
   }
 }
+
+serde_struct!(Dd2Data {
+  id,
+  x,
+  y,
+  owner,
+  flag
+});
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -43,3 +45,14 @@ impl ParseChunk for Dim {
     })
   }
 }
+
+impl WriteChunk for Dim {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u16_le(self.w)?;
+    writer.write_u16_le(self.h)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Dim { w, h });
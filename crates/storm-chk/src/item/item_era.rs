@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 use crate::types::Tileset;
 
@@ -42,3 +44,13 @@ impl ParseChunk for Era {
     })
   }
 }
+
+impl WriteChunk for Era {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u16_le(self.tileset.as_u16())?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Era { tileset });
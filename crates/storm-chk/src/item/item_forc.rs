@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -33,11 +35,27 @@ impl ParseChunk for Forc {
     Ok(Self {
       force: reader.read_array_u8()?,
       names: reader.read_array_u16()?,
-      props: reader.read_array_u8()?.map(ForcFlags::from_value),
+      props: reader.read_array_u8()?.map(ForcFlags::from_bits_retain),
     })
   }
 }
 
+impl WriteChunk for Forc {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.force)?;
+    writer.write_slice_u16(&self.names)?;
+    writer.write_bytes(&self.props.map(|props| props.bits()))?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Forc {
+  force,
+  names,
+  props
+});
+
 // =============================================================================
 // Forc Flags
 // =============================================================================
@@ -55,3 +73,5 @@ bitflags! {
     const VISION = 0x08;
   }
 }
+
+serde_flags!(ForcFlags);
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 use crate::types::Owner;
 use crate::types::Player;
@@ -33,3 +35,13 @@ impl ParseChunk for Iown {
     })
   }
 }
+
+impl WriteChunk for Iown {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.owner.map(Owner::as_u8))?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Iown { owner });
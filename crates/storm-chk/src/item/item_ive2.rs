@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -36,3 +38,13 @@ impl ParseChunk for Ive2 {
     })
   }
 }
+
+impl WriteChunk for Ive2 {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u16_le(self.version)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Ive2 { version });
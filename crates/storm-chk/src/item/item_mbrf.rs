@@ -1,10 +1,12 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::item::TrigData;
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -35,3 +37,15 @@ impl ParseChunk for Mbrf {
     })
   }
 }
+
+impl WriteChunk for Mbrf {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.triggers.iter() {
+      item.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+serde_struct!(Mbrf { triggers });
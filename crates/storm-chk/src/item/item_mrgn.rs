@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -35,6 +37,18 @@ impl ParseChunk for Mrgn {
   }
 }
 
+impl WriteChunk for Mrgn {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.locations.iter() {
+      item.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+serde_struct!(Mrgn { locations });
+
 // =============================================================================
 // Mrgn Data
 // =============================================================================
@@ -67,4 +81,24 @@ impl MrgnData {
       flags: reader.read_u16_le()?,
     })
   }
+
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u32_le(self.x1)?;
+    writer.write_u32_le(self.y1)?;
+    writer.write_u32_le(self.x2)?;
+    writer.write_u32_le(self.y2)?;
+    writer.write_u16_le(self.index)?;
+    writer.write_u16_le(self.flags)?;
+
+    Ok(())
+  }
 }
+
+serde_struct!(MrgnData {
+  x1,
+  y1,
+  x2,
+  y2,
+  index,
+  flags
+});
@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -34,3 +36,13 @@ impl ParseChunk for Mtxm {
     })
   }
 }
+
+impl WriteChunk for Mtxm {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice_u16(&self.tiles)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Mtxm { tiles });
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 use crate::types::Owner;
 use crate::types::Player;
@@ -38,3 +40,13 @@ impl ParseChunk for Ownr {
     })
   }
 }
+
+impl WriteChunk for Ownr {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.owner.map(Owner::as_u8))?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Ownr { owner });
@@ -1,9 +1,12 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
+use crate::id;
 use crate::id::TechId;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 use crate::types::Player;
 
@@ -54,6 +57,56 @@ impl Ptex {
   pub const fn is_global_default(&self, player: Player, technology: TechId) -> bool {
     self.global_default[player.as_usize()][technology as usize] == 0x01
   }
+
+  /// Returns whether `technology` is available and researched for `player`,
+  /// using the global settings if `player` uses global defaults.
+  #[inline]
+  pub const fn resolve(&self, player: Player, technology: TechId) -> (bool, bool) {
+    if self.is_global_default(player, technology) {
+      (
+        self.is_global_allowed(technology),
+        self.is_global_studied(technology),
+      )
+    } else {
+      (
+        self.is_allowed(player, technology),
+        self.is_studied(player, technology),
+      )
+    }
+  }
+
+  /// Set whether `technology` is available and researched for `player`,
+  /// overriding global defaults.
+  pub fn set_state(&mut self, player: Player, technology: TechId, allowed: bool, studied: bool) {
+    let index: usize = technology as usize;
+
+    self.player_allowed[player.as_usize()][index] = allowed.into();
+    self.player_studied[player.as_usize()][index] = studied.into();
+    self.global_default[player.as_usize()][index] = 0x00;
+  }
+
+  /// Set whether `technology` is available and researched by default.
+  pub fn set_global_state(&mut self, technology: TechId, allowed: bool, studied: bool) {
+    self.global_allowed[technology as usize] = allowed.into();
+    self.global_studied[technology as usize] = studied.into();
+  }
+}
+
+impl Default for Ptex {
+  /// Every technology available, abilities without research researched, all
+  /// players using global defaults.
+  fn default() -> Self {
+    let studied: [u8; TechId::BROOD_WAR] =
+      core::array::from_fn(|index| id::default_researched(index).into());
+
+    Self {
+      player_allowed: [[0x01; TechId::BROOD_WAR]; Player::TOTAL],
+      player_studied: [studied; Player::TOTAL],
+      global_allowed: [0x01; TechId::BROOD_WAR],
+      global_studied: studied,
+      global_default: [[0x01; TechId::BROOD_WAR]; Player::TOTAL],
+    }
+  }
 }
 
 impl From<Ptex> for Item {
@@ -76,3 +129,23 @@ impl ParseChunk for Ptex {
     })
   }
 }
+
+impl WriteChunk for Ptex {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice(&self.player_allowed, |writer, row| writer.write_bytes(row))?;
+    writer.write_slice(&self.player_studied, |writer, row| writer.write_bytes(row))?;
+    writer.write_bytes(&self.global_allowed)?;
+    writer.write_bytes(&self.global_studied)?;
+    writer.write_slice(&self.global_default, |writer, row| writer.write_bytes(row))?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Ptex {
+  player_allowed,
+  player_studied,
+  global_allowed,
+  global_studied,
+  global_default,
+});
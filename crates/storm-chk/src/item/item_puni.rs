@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::id::UnitId;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 use crate::types::Player;
 
@@ -39,6 +41,40 @@ impl Puni {
   pub const fn is_global_default(&self, player: Player, unit: UnitId) -> bool {
     self.global_default[player.as_usize()][unit as usize] == 0x01
   }
+
+  /// Returns `true` if `unit` is available for production by `player`, using
+  /// the global setting if `player` uses global defaults.
+  #[inline]
+  pub const fn resolve(&self, player: Player, unit: UnitId) -> bool {
+    if self.is_global_default(player, unit) {
+      self.is_global_allowed(unit)
+    } else {
+      self.is_allowed(player, unit)
+    }
+  }
+
+  /// Set the availability of `unit` for `player`, overriding global defaults.
+  pub fn set_allowed(&mut self, player: Player, unit: UnitId, allowed: bool) {
+    self.player_allowed[player.as_usize()][unit] = allowed.into();
+    self.global_default[player.as_usize()][unit] = 0x00;
+  }
+
+  /// Set the global availability of `unit`.
+  #[inline]
+  pub fn set_global_allowed(&mut self, unit: UnitId, allowed: bool) {
+    self.global_allowed[unit] = allowed.into();
+  }
+}
+
+impl Default for Puni {
+  /// Every unit available, all players using global defaults.
+  fn default() -> Self {
+    Self {
+      player_allowed: [[0x01; UnitId::TOTAL]; Player::TOTAL],
+      global_allowed: [0x01; UnitId::TOTAL],
+      global_default: [[0x01; UnitId::TOTAL]; Player::TOTAL],
+    }
+  }
 }
 
 impl From<Puni> for Item {
@@ -59,3 +95,19 @@ impl ParseChunk for Puni {
     })
   }
 }
+
+impl WriteChunk for Puni {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice(&self.player_allowed, |writer, row| writer.write_bytes(row))?;
+    writer.write_bytes(&self.global_allowed)?;
+    writer.write_slice(&self.global_default, |writer, row| writer.write_bytes(row))?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Puni {
+  player_allowed,
+  global_allowed,
+  global_default
+});
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 use crate::types::Player;
 use crate::types::Race;
@@ -35,3 +37,13 @@ impl ParseChunk for Side {
     })
   }
 }
+
+impl WriteChunk for Side {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.species.map(Race::as_u8))?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Side { species });
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -35,3 +37,14 @@ impl ParseChunk for Sprp {
     })
   }
 }
+
+impl WriteChunk for Sprp {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u16_le(self.name)?;
+    writer.write_u16_le(self.desc)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Sprp { name, desc });
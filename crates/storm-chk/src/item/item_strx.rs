@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::ChkString;
 use crate::types::Item;
 
@@ -51,7 +53,7 @@ impl ParseChunk for Strx {
     let offsets: Box<[u32]> = offsets
       .iter()
       .copied()
-      .map(|offset| offset - cursor)
+      .map(|offset| offset.wrapping_sub(cursor))
       .collect();
 
     // Read all the string data
@@ -65,3 +67,26 @@ impl ParseChunk for Strx {
     })
   }
 }
+
+impl WriteChunk for Strx {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    // Offsets are stored relative to the start of the section
+    let cursor: u32 = 4 + (self.entries << 2);
+
+    writer.write_u32_le(self.entries)?;
+
+    for offset in self.offsets.iter() {
+      writer.write_u32_le(offset.wrapping_add(cursor))?;
+    }
+
+    writer.write_bytes(&self.content)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Strx {
+  entries,
+  offsets,
+  content
+});
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -31,3 +33,13 @@ impl ParseChunk for Swnm {
     })
   }
 }
+
+impl WriteChunk for Swnm {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice_u32(&self.switches)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Swnm { switches });
@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::id::TechId;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -79,3 +81,23 @@ impl ParseChunk for Tecs {
     })
   }
 }
+
+impl WriteChunk for Tecs {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.defaults)?;
+    writer.write_slice_u16(&self.min_cost)?;
+    writer.write_slice_u16(&self.gas_cost)?;
+    writer.write_slice_u16(&self.time_cost)?;
+    writer.write_slice_u16(&self.cast_cost)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Tecs {
+  defaults,
+  min_cost,
+  gas_cost,
+  time_cost,
+  cast_cost
+});
@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::id::TechId;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -75,3 +77,23 @@ impl ParseChunk for Tecx {
     })
   }
 }
+
+impl WriteChunk for Tecx {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.defaults)?;
+    writer.write_slice_u16(&self.min_cost)?;
+    writer.write_slice_u16(&self.gas_cost)?;
+    writer.write_slice_u16(&self.time_cost)?;
+    writer.write_slice_u16(&self.cast_cost)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Tecx {
+  defaults,
+  min_cost,
+  gas_cost,
+  time_cost,
+  cast_cost
+});
@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -35,6 +37,18 @@ impl ParseChunk for Thg2 {
   }
 }
 
+impl WriteChunk for Thg2 {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.sprites.iter() {
+      item.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+serde_struct!(Thg2 { sprites });
+
 // =============================================================================
 // Thg2 Data
 // =============================================================================
@@ -67,4 +81,24 @@ impl Thg2Data {
       flags: reader.read_u16_le()?,
     })
   }
+
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u16_le(self.id)?;
+    writer.write_u16_le(self.x)?;
+    writer.write_u16_le(self.y)?;
+    writer.write_u8(self.owner)?;
+    writer.write_u8(self._padding)?;
+    writer.write_u16_le(self.flags)?;
+
+    Ok(())
+  }
 }
+
+serde_struct!(Thg2Data {
+  id,
+  x,
+  y,
+  owner,
+  _padding,
+  flags
+});
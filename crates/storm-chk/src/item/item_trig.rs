@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -35,11 +37,22 @@ impl ParseChunk for Trig {
   }
 }
 
+impl WriteChunk for Trig {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.triggers.iter() {
+      item.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+serde_struct!(Trig { triggers });
+
 // =============================================================================
 // Trigger Data
 // =============================================================================
 
-// TODO: bitflags
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub struct TrigData {
   /// Conditions.
@@ -47,7 +60,7 @@ pub struct TrigData {
   /// Actions.
   pub actions: [TrigAction; 0x40],
   /// Execution flags.
-  pub execution: u32,
+  pub execution: TrigFlags,
   /// The player(s) this trigger applies to.
   pub players: [u8; 0x1B],
   /// Index of the current action.
@@ -55,23 +68,47 @@ pub struct TrigData {
 }
 
 impl TrigData {
+  // Flags keep unknown bits, third-party editors are known to set them
   pub fn from_reader<R: ReadExt>(reader: &mut R) -> Result<Self> {
     Ok(Self {
       conditions: Trig::read_array(reader, TrigCondition::from_reader)?,
       actions: Trig::read_array(reader, TrigAction::from_reader)?,
-      execution: reader.read_u32_le()?,
+      execution: TrigFlags::from_bits_retain(reader.read_u32_le()?),
       players: reader.read_array_u8()?,
       current: reader.read_u8()?,
     })
   }
+
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.conditions.iter() {
+      item.to_writer(writer)?;
+    }
+
+    for item in self.actions.iter() {
+      item.to_writer(writer)?;
+    }
+
+    writer.write_u32_le(self.execution.bits())?;
+    writer.write_bytes(&self.players)?;
+    writer.write_u8(self.current)?;
+
+    Ok(())
+  }
 }
 
+serde_struct!(TrigData {
+  conditions,
+  actions,
+  execution,
+  players,
+  current
+});
+
 // =============================================================================
 // Trigger Condition
 // =============================================================================
 
-// TODO: bitflags
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
 pub struct TrigCondition {
   /// Location number for the condition.
   pub location: u32,
@@ -88,7 +125,7 @@ pub struct TrigCondition {
   /// Resource type/Score type/Switch number.
   pub kind: u8,
   /// Condition Flags.
-  pub flags: u8,
+  pub flags: TrigConditionFlags,
   /// Mask flag.
   pub mask: u16,
 }
@@ -103,18 +140,43 @@ impl TrigCondition {
       state: reader.read_u8()?,
       condition: reader.read_u8()?,
       kind: reader.read_u8()?,
-      flags: reader.read_u8()?,
+      flags: TrigConditionFlags::from_bits_retain(reader.read_u8()?),
       mask: reader.read_u16_le()?,
     })
   }
+
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u32_le(self.location)?;
+    writer.write_u32_le(self.group)?;
+    writer.write_u32_le(self.count)?;
+    writer.write_u16_le(self.unit_id)?;
+    writer.write_u8(self.state)?;
+    writer.write_u8(self.condition)?;
+    writer.write_u8(self.kind)?;
+    writer.write_u8(self.flags.bits())?;
+    writer.write_u16_le(self.mask)?;
+
+    Ok(())
+  }
 }
 
+serde_struct!(TrigCondition {
+  location,
+  group,
+  count,
+  unit_id,
+  state,
+  condition,
+  kind,
+  flags,
+  mask,
+});
+
 // =============================================================================
 // Trigger Action
 // =============================================================================
 
-// TODO: bitflags
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
 pub struct TrigAction {
   /// Action location (source/destination).
   pub location: u32,
@@ -143,7 +205,7 @@ pub struct TrigAction {
   /// Number of units.
   pub units: u8,
   /// Flags.
-  pub flags: u8,
+  pub flags: TrigActionFlags,
   /// Unused (?)
   pub _padding: u8,
   /// Mask flag.
@@ -162,9 +224,106 @@ impl TrigAction {
       kind: reader.read_u16_le()?,
       action: reader.read_u8()?,
       units: reader.read_u8()?,
-      flags: reader.read_u8()?,
+      flags: TrigActionFlags::from_bits_retain(reader.read_u8()?),
       _padding: reader.read_u8()?,
       mask: reader.read_u16_le()?,
     })
   }
+
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u32_le(self.location)?;
+    writer.write_u32_le(self.txt_index)?;
+    writer.write_u32_le(self.wav_index)?;
+    writer.write_u32_le(self.time)?;
+    writer.write_u32_le(self.group)?;
+    writer.write_u32_le(self.state)?;
+    writer.write_u16_le(self.kind)?;
+    writer.write_u8(self.action)?;
+    writer.write_u8(self.units)?;
+    writer.write_u8(self.flags.bits())?;
+    writer.write_u8(self._padding)?;
+    writer.write_u16_le(self.mask)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(TrigAction {
+  location,
+  txt_index,
+  wav_index,
+  time,
+  group,
+  state,
+  kind,
+  action,
+  units,
+  flags,
+  _padding,
+  mask,
+});
+
+// =============================================================================
+// Trigger Flags
+// =============================================================================
+
+bitflags! {
+  #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+  pub struct TrigFlags: u32 {
+    /// All conditions are met, executing actions.
+    const EXECUTING = 0x0000_0001;
+    /// Ignore the Defeat and Draw actions.
+    const IGNORE_DEFEAT_DRAW = 0x0000_0002;
+    /// Preserve the trigger (set by the Preserve Trigger action).
+    const PRESERVE = 0x0000_0004;
+    /// Ignore the trigger.
+    const DISABLED = 0x0000_0008;
+    /// Ignore waits, transmissions and other timed actions until the next
+    /// trigger loop.
+    const IGNORE_TIMED = 0x0000_0010;
+    /// The trigger has paused the game.
+    const PAUSED = 0x0000_0020;
+    /// Wait skipping is disabled until the next trigger loop.
+    const NO_WAIT_SKIP = 0x0000_0040;
+  }
 }
+
+serde_flags!(TrigFlags);
+
+// =============================================================================
+// Trigger Condition Flags
+// =============================================================================
+
+bitflags! {
+  #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+  pub struct TrigConditionFlags: u8 {
+    /// The condition is disabled.
+    const DISABLED = 0x02;
+    /// The unit type is used.
+    const UNIT_TYPE = 0x10;
+  }
+}
+
+serde_flags!(TrigConditionFlags);
+
+// =============================================================================
+// Trigger Action Flags
+// =============================================================================
+
+bitflags! {
+  #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+  pub struct TrigActionFlags: u8 {
+    /// Ignore a wait or transmission once.
+    const IGNORE_WAIT = 0x01;
+    /// The action is disabled.
+    const DISABLED = 0x02;
+    /// Always display text, even if subtitles are off.
+    const ALWAYS_DISPLAY = 0x04;
+    /// Unit properties are used (Create Unit with Properties).
+    const UNIT_PROPERTIES = 0x08;
+    /// The unit type is used.
+    const UNIT_TYPE = 0x10;
+  }
+}
+
+serde_flags!(TrigActionFlags);
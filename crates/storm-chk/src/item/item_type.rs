@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -39,3 +41,13 @@ impl ParseChunk for Type {
     })
   }
 }
+
+impl WriteChunk for Type {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u32_le(self.scenario)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Type { scenario });
@@ -1,9 +1,12 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
+use crate::id::UnitId;
 use crate::parse::BoxedSize;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -36,6 +39,18 @@ impl ParseChunk for Unit {
   }
 }
 
+impl WriteChunk for Unit {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.units.iter() {
+      item.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+serde_struct!(Unit { units });
+
 // =============================================================================
 // Unit Data
 // =============================================================================
@@ -78,6 +93,12 @@ pub struct UnitData {
 }
 
 impl UnitData {
+  /// Returns the unit type, if valid.
+  #[inline]
+  pub const fn unit(&self) -> Option<UnitId> {
+    UnitId::from_u16(self.unit_id)
+  }
+
   pub fn from_reader<R: ReadExt>(reader: &mut R) -> Result<Self> {
     Ok(Self {
       class: reader.read_u32_le()?,
@@ -98,4 +119,44 @@ impl UnitData {
       related: reader.read_u32_le()?,
     })
   }
+
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u32_le(self.class)?;
+    writer.write_u16_le(self.x)?;
+    writer.write_u16_le(self.y)?;
+    writer.write_u16_le(self.unit_id)?;
+    writer.write_u16_le(self.unit_ty)?;
+    writer.write_u16_le(self.props_applied)?;
+    writer.write_u16_le(self.props_changed)?;
+    writer.write_u8(self.owner)?;
+    writer.write_u8(self.hp)?;
+    writer.write_u8(self.sp)?;
+    writer.write_u8(self.ep)?;
+    writer.write_u32_le(self.resource)?;
+    writer.write_u16_le(self.hangar)?;
+    writer.write_u16_le(self.state)?;
+    writer.write_u32_le(self._padding)?;
+    writer.write_u32_le(self.related)?;
+
+    Ok(())
+  }
 }
+
+serde_struct!(UnitData {
+  class,
+  x,
+  y,
+  unit_id,
+  unit_ty,
+  props_applied,
+  props_changed,
+  owner,
+  hp,
+  sp,
+  ep,
+  resource,
+  hangar,
+  state,
+  _padding,
+  related,
+});
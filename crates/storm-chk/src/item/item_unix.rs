@@ -1,10 +1,12 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::id::UnitId;
 use crate::id::WeaponId;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -116,3 +118,33 @@ impl ParseChunk for Unix {
     })
   }
 }
+
+impl WriteChunk for Unix {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.defaults)?;
+    writer.write_slice_u32(&self.unit_hp)?;
+    writer.write_slice_u16(&self.unit_sp)?;
+    writer.write_bytes(&self.unit_ap)?;
+    writer.write_slice_u16(&self.build_time)?;
+    writer.write_slice_u16(&self.min_cost)?;
+    writer.write_slice_u16(&self.gas_cost)?;
+    writer.write_slice_u16(&self.string)?;
+    writer.write_slice_u16(&self.default_damage)?;
+    writer.write_slice_u16(&self.boosted_damage)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Unix {
+  defaults,
+  unit_hp,
+  unit_sp,
+  unit_ap,
+  build_time,
+  min_cost,
+  gas_cost,
+  string,
+  default_damage,
+  boosted_damage,
+});
@@ -1,9 +1,12 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
+use crate::id;
 use crate::id::UpgradeId;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 use crate::types::Player;
 
@@ -60,6 +63,53 @@ impl Upgr {
     debug_assert!(upgrade.is_classic());
     self.global_default[player.as_usize()][upgrade as usize] == 0x01
   }
+
+  /// Returns the starting and maximum levels of `upgrade` for `player`, using
+  /// the global settings if `player` uses global defaults.
+  #[inline]
+  pub const fn resolve(&self, player: Player, upgrade: UpgradeId) -> (u8, u8) {
+    if self.global_default(player, upgrade) {
+      (self.global_initial(upgrade), self.global_maximum(upgrade))
+    } else {
+      (
+        self.player_initial(player, upgrade),
+        self.player_maximum(player, upgrade),
+      )
+    }
+  }
+
+  /// Set the starting and maximum levels of `upgrade` for `player`, overriding
+  /// global defaults.
+  pub fn set_levels(&mut self, player: Player, upgrade: UpgradeId, initial: u8, maximum: u8) {
+    debug_assert!(upgrade.is_classic());
+    let index: usize = upgrade as usize;
+
+    self.player_initial[player.as_usize()][index] = initial;
+    self.player_maximum[player.as_usize()][index] = maximum;
+    self.global_default[player.as_usize()][index] = 0x00;
+  }
+
+  /// Set the global starting and maximum levels of `upgrade`.
+  pub fn set_global_levels(&mut self, upgrade: UpgradeId, initial: u8, maximum: u8) {
+    debug_assert!(upgrade.is_classic());
+    self.global_initial[upgrade as usize] = initial;
+    self.global_maximum[upgrade as usize] = maximum;
+  }
+}
+
+impl Default for Upgr {
+  /// Default levels, all players using global defaults.
+  fn default() -> Self {
+    let maximum: [u8; UpgradeId::CLASSIC] = core::array::from_fn(id::default_maximum);
+
+    Self {
+      player_maximum: [maximum; Player::TOTAL],
+      player_initial: [[0x00; UpgradeId::CLASSIC]; Player::TOTAL],
+      global_maximum: maximum,
+      global_initial: [0x00; UpgradeId::CLASSIC],
+      global_default: [[0x01; UpgradeId::CLASSIC]; Player::TOTAL],
+    }
+  }
 }
 
 impl From<Upgr> for Item {
@@ -82,3 +132,23 @@ impl ParseChunk for Upgr {
     })
   }
 }
+
+impl WriteChunk for Upgr {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice(&self.player_maximum, |writer, row| writer.write_bytes(row))?;
+    writer.write_slice(&self.player_initial, |writer, row| writer.write_bytes(row))?;
+    writer.write_bytes(&self.global_maximum)?;
+    writer.write_bytes(&self.global_initial)?;
+    writer.write_slice(&self.global_default, |writer, row| writer.write_bytes(row))?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Upgr {
+  player_maximum,
+  player_initial,
+  global_maximum,
+  global_initial,
+  global_default,
+});
@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::id::UpgradeId;
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -97,3 +99,27 @@ impl ParseChunk for Upgs {
     })
   }
 }
+
+impl WriteChunk for Upgs {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.defaults)?;
+    writer.write_slice_u16(&self.min_cost_initial)?;
+    writer.write_slice_u16(&self.min_cost_upgrade)?;
+    writer.write_slice_u16(&self.gas_cost_initial)?;
+    writer.write_slice_u16(&self.gas_cost_upgrade)?;
+    writer.write_slice_u16(&self.time_initial)?;
+    writer.write_slice_u16(&self.time_upgrade)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Upgs {
+  defaults,
+  min_cost_initial,
+  min_cost_upgrade,
+  gas_cost_initial,
+  gas_cost_upgrade,
+  time_initial,
+  time_upgrade,
+});
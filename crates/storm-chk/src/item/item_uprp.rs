@@ -1,9 +1,11 @@
 use storm_core::error::Result;
 use storm_utils::bitflags;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -37,6 +39,18 @@ impl ParseChunk for Uprp {
   }
 }
 
+impl WriteChunk for Uprp {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for item in self.units.iter() {
+      item.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+serde_struct!(Uprp { units });
+
 // =============================================================================
 // Uprp Data
 // =============================================================================
@@ -68,20 +82,48 @@ pub struct UprpData {
 impl UprpData {
   pub fn from_reader<R: ReadExt>(reader: &mut R) -> Result<Self> {
     Ok(Self {
-      props_applied: UprpAppliedFlags::from_value(reader.read_u16_le()?),
-      props_changed: UprpChangedFlags::from_value(reader.read_u16_le()?),
+      props_applied: UprpAppliedFlags::from_bits_retain(reader.read_u16_le()?),
+      props_changed: UprpChangedFlags::from_bits_retain(reader.read_u16_le()?),
       owner: reader.read_u8()?,
       health: reader.read_u8()?,
       shield: reader.read_u8()?,
       energy: reader.read_u8()?,
       resource: reader.read_u32_le()?,
       hangar: reader.read_u16_le()?,
-      flags: UprpUnitFlags::from_value(reader.read_u16_le()?),
+      flags: UprpUnitFlags::from_bits_retain(reader.read_u16_le()?),
       _padding: reader.read_u32_le()?,
     })
   }
+
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_u16_le(self.props_applied.bits())?;
+    writer.write_u16_le(self.props_changed.bits())?;
+    writer.write_u8(self.owner)?;
+    writer.write_u8(self.health)?;
+    writer.write_u8(self.shield)?;
+    writer.write_u8(self.energy)?;
+    writer.write_u32_le(self.resource)?;
+    writer.write_u16_le(self.hangar)?;
+    writer.write_u16_le(self.flags.bits())?;
+    writer.write_u32_le(self._padding)?;
+
+    Ok(())
+  }
 }
 
+serde_struct!(UprpData {
+  props_applied,
+  props_changed,
+  owner,
+  health,
+  shield,
+  energy,
+  resource,
+  hangar,
+  flags,
+  _padding,
+});
+
 // =============================================================================
 // Uprp Flags (Applied)
 // =============================================================================
@@ -102,6 +144,8 @@ bitflags! {
   }
 }
 
+serde_flags!(UprpAppliedFlags);
+
 // =============================================================================
 // Uprp Flags (Changed)
 // =============================================================================
@@ -124,6 +168,8 @@ bitflags! {
   }
 }
 
+serde_flags!(UprpChangedFlags);
+
 // =============================================================================
 // Uprp Flags (Unit)
 // =============================================================================
@@ -143,3 +189,5 @@ bitflags! {
     const INVINCIBLE = 0x0010;
   }
 }
+
+serde_flags!(UprpUnitFlags);
@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -33,3 +35,13 @@ impl ParseChunk for Upus {
     })
   }
 }
+
+impl WriteChunk for Upus {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_bytes(&self.used)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Upus { used });
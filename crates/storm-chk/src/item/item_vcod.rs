@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -36,3 +38,14 @@ impl ParseChunk for Vcod {
     })
   }
 }
+
+impl WriteChunk for Vcod {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice_u32(&self.seed)?;
+    writer.write_bytes(&self.code)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Vcod { seed, code });
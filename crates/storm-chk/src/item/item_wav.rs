@@ -1,8 +1,10 @@
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
+use crate::parse::WriteChunk;
 use crate::types::Item;
 
 // =============================================================================
@@ -32,3 +34,13 @@ impl ParseChunk for Wav {
     })
   }
 }
+
+impl WriteChunk for Wav {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    writer.write_slice_u32(&self.wav)?;
+
+    Ok(())
+  }
+}
+
+serde_struct!(Wav { wav });
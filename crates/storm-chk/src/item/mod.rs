@@ -74,8 +74,11 @@ pub use self::item_thg2::Thg2Data;
 pub use self::item_tile::Tile;
 pub use self::item_trig::Trig;
 pub use self::item_trig::TrigAction;
+pub use self::item_trig::TrigActionFlags;
 pub use self::item_trig::TrigCondition;
+pub use self::item_trig::TrigConditionFlags;
 pub use self::item_trig::TrigData;
+pub use self::item_trig::TrigFlags;
 pub use self::item_type::Type;
 pub use self::item_unis::Unis;
 pub use self::item_unit::Unit;
@@ -10,4 +10,9 @@ pub mod consts;
 pub mod id;
 pub mod item;
 pub mod parse;
+pub mod trigger;
 pub mod types;
+
+only_serde! {
+  mod serial;
+}
@@ -14,3 +14,151 @@ macro_rules! delegate_fmt {
     impl_fmt!($ty -> $func as Display);
   };
 }
+
+// Implement `Field` for types that are already `Serialize + Deserialize`
+macro_rules! serde_field {
+  ($($ty:ty),* $(,)?) => {
+    only_serde! {
+      $(
+        impl $crate::serial::Field for $ty {
+          #[inline]
+          fn serialize_field<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+          where
+            S: ::serde::Serializer,
+          {
+            ::serde::Serialize::serialize(self, serializer)
+          }
+
+          #[inline]
+          fn deserialize_field<'de, D>(deserializer: D) -> Result<Self, D::Error>
+          where
+            D: ::serde::Deserializer<'de>,
+          {
+            ::serde::Deserialize::deserialize(deserializer)
+          }
+        }
+      )*
+    }
+  };
+}
+
+// Implement `Serialize` and `Deserialize` for a struct, field by field
+macro_rules! serde_struct {
+  ($ty:ident { $($field:ident),* $(,)? }) => {
+    only_serde! {
+      impl ::serde::Serialize for $ty {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+          S: ::serde::Serializer,
+        {
+          use ::serde::ser::SerializeStruct;
+
+          let fields: &[&str] = &[$(stringify!($field)),*];
+          let mut state: S::SerializeStruct =
+            serializer.serialize_struct(stringify!($ty), fields.len())?;
+          $(state.serialize_field(stringify!($field), &$crate::serial::Ser(&self.$field))?;)*
+          state.end()
+        }
+      }
+
+      impl<'de> ::serde::Deserialize<'de> for $ty {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+          D: ::serde::Deserializer<'de>,
+        {
+          struct StructVisitor;
+
+          impl<'de> ::serde::de::Visitor<'de> for StructVisitor {
+            type Value = $ty;
+
+            fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+              f.write_str(concat!("struct ", stringify!($ty)))
+            }
+
+            #[allow(unused_assignments)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+              A: ::serde::de::SeqAccess<'de>,
+            {
+              let mut index: usize = 0;
+
+              $(
+                let Some($crate::serial::De($field)) = seq.next_element()? else {
+                  return Err(::serde::de::Error::invalid_length(index, &self));
+                };
+
+                index += 1;
+              )*
+
+              Ok($ty { $($field),* })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+              A: ::serde::de::MapAccess<'de>,
+            {
+              $(let mut $field = None;)*
+
+              while let Some(key) = map.next_key::<::std::borrow::Cow<'de, str>>()? {
+                match &*key {
+                  $(
+                    stringify!($field) => {
+                      $field = Some(map.next_value::<$crate::serial::De<_>>()?.0);
+                    }
+                  )*
+                  _ => {
+                    map.next_value::<::serde::de::IgnoredAny>()?;
+                  }
+                }
+              }
+
+              Ok($ty {
+                $(
+                  $field: $field.ok_or_else(|| {
+                    <A::Error as ::serde::de::Error>::missing_field(stringify!($field))
+                  })?,
+                )*
+              })
+            }
+          }
+
+          let fields: &'static [&'static str] = &[$(stringify!($field)),*];
+
+          deserializer.deserialize_struct(stringify!($ty), fields, StructVisitor)
+        }
+      }
+    }
+
+    serde_field!($ty);
+  };
+}
+
+// Implement `Serialize` and `Deserialize` for bitflags, using flag names
+macro_rules! serde_flags {
+  ($($ty:ident),* $(,)?) => {
+    only_serde! {
+      $(
+        impl ::serde::Serialize for $ty {
+          #[inline]
+          fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+          where
+            S: ::serde::Serializer,
+          {
+            serializer.collect_str(self)
+          }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+          fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+          where
+            D: ::serde::Deserializer<'de>,
+          {
+            $crate::serial::deserialize_flags(deserializer)
+          }
+        }
+      )*
+    }
+
+    serde_field!($($ty),*);
+  };
+}
@@ -5,3 +5,4 @@ pub use self::parser::Parser;
 pub use self::traits::BoxedSize;
 pub use self::traits::ChunkType;
 pub use self::traits::ParseChunk;
+pub use self::traits::WriteChunk;
@@ -33,13 +33,50 @@ impl Parser {
     self.name = unsafe { Magic::new_unchecked(name) };
     self.size = self.reader.read_u32_le()?;
 
+    let start: u64 = self.reader.position();
+    let item: Item = Item::parse(self)?;
+    let trailing: Box<[u8]> = self.read_trailing(start)?;
+
     Ok(Chunk {
       name: self.name,
       size: self.size,
-      item: Item::parse(self)?,
+      item,
+      trailing,
     })
   }
 
+  /// Parse the content of a single section with the given `name`.
+  pub fn read_section(name: Magic, data: Vec<u8>) -> Result<Chunk> {
+    let mut parser: Self = Self::new(data);
+
+    parser.name = name;
+    parser.size = parser.reader.get_ref().len() as u32;
+
+    let item: Item = Item::parse(&mut parser)?;
+    let trailing: Box<[u8]> = parser.read_trailing(0)?;
+
+    Ok(Chunk {
+      name,
+      size: parser.size,
+      item,
+      trailing,
+    })
+  }
+
+  // Read any data of the section (starting at `start`) the item did not consume
+  fn read_trailing(&mut self, start: u64) -> Result<Box<[u8]>> {
+    let length: u64 = self.reader.get_ref().len() as u64;
+    let end: u64 = length.min(start + u64::from(self.size));
+    let position: u64 = self.reader.position();
+
+    if position >= end {
+      self.reader.set_position(start + u64::from(self.size));
+      return Ok(Box::default());
+    }
+
+    Ok(self.reader.read_boxed_u8((end - position) as usize)?)
+  }
+
   #[inline]
   pub fn read_all<T>(self) -> Result<T>
   where
@@ -63,7 +100,7 @@ impl Iterator for Parser {
 
   #[inline]
   fn next(&mut self) -> Option<Self::Item> {
-    if self.reader.get_ref().is_empty() {
+    if self.reader.position() >= self.reader.get_ref().len() as u64 {
       return None;
     }
 
@@ -2,6 +2,7 @@ use core::any::type_name;
 use storm_core::error::Error;
 use storm_core::error::Result;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::Parser;
 use crate::types::Item;
@@ -109,3 +110,17 @@ pub trait ParseChunk: Sized + Into<Item> {
     Ok(tiles)
   }
 }
+
+pub trait WriteChunk {
+  /// Write the content of the section (without the name and size header).
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()>;
+
+  /// Returns the content of the section as bytes.
+  fn to_bytes(&self) -> Result<Vec<u8>> {
+    let mut output: Vec<u8> = Vec::new();
+
+    self.to_writer(&mut output)?;
+
+    Ok(output)
+  }
+}
@@ -0,0 +1,225 @@
+//! Serde support for section content.
+//!
+//! Section fields are (de)serialized through [`Field`], which covers arrays of
+//! any length and boxed slices as plain sequences.
+
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::marker::PhantomData;
+use core::ops::RangeInclusive;
+use core::str::from_utf8;
+use serde::de::Error;
+use serde::de::SeqAccess;
+use serde::de::Unexpected;
+use serde::de::Visitor;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use storm_core::types::Magic;
+use storm_utils::bitflags::parser;
+use storm_utils::bitflags::Flags;
+
+// =============================================================================
+// Field
+// =============================================================================
+
+/// A value stored in a section field.
+pub(crate) trait Field: Sized {
+  fn serialize_field<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer;
+
+  fn deserialize_field<'de, D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>;
+}
+
+/// Serialize a [`Field`].
+pub(crate) struct Ser<'a, T>(pub(crate) &'a T);
+
+impl<T: Field> Serialize for Ser<'_, T> {
+  #[inline]
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.0.serialize_field(serializer)
+  }
+}
+
+/// Deserialize a [`Field`].
+pub(crate) struct De<T>(pub(crate) T);
+
+impl<'de, T: Field> Deserialize<'de> for De<T> {
+  #[inline]
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    T::deserialize_field(deserializer).map(Self)
+  }
+}
+
+serde_field!(u8, u16, u32);
+
+impl<T: Field, const N: usize> Field for [T; N] {
+  fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(self.iter().map(Ser))
+  }
+
+  fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let items: Vec<T> = deserializer.deserialize_seq(SeqVisitor(PhantomData))?;
+    let count: usize = items.len();
+
+    items
+      .try_into()
+      .map_err(|_| D::Error::invalid_length(count, &format!("{N} elements").as_str()))
+  }
+}
+
+impl<T: Field> Field for Box<[T]> {
+  fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(self.iter().map(Ser))
+  }
+
+  fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer
+      .deserialize_seq(SeqVisitor(PhantomData))
+      .map(Vec::into_boxed_slice)
+  }
+}
+
+/// Section names are written as text if valid UTF-8, bytes otherwise.
+impl Field for Magic {
+  fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match from_utf8(&self[..]) {
+      Ok(name) => serializer.serialize_str(name),
+      Err(_) => self[..].serialize(serializer),
+    }
+  }
+
+  fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(MagicVisitor)
+  }
+}
+
+struct SeqVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Field> Visitor<'de> for SeqVisitor<T> {
+  type Value = Vec<T>;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str("a sequence")
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let mut output: Vec<T> = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(0x1000));
+
+    while let Some(De(item)) = seq.next_element()? {
+      output.push(item);
+    }
+
+    Ok(output)
+  }
+}
+
+struct MagicVisitor;
+
+impl<'de> Visitor<'de> for MagicVisitor {
+  type Value = Magic;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str("a 4-byte section name")
+  }
+
+  fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+    let Ok(name) = <[u8; 4]>::try_from(value.as_bytes()) else {
+      return Err(E::invalid_value(Unexpected::Str(value), &self));
+    };
+
+    // SAFETY: `name` comes from a `str`
+    Ok(unsafe { Magic::new_unchecked(name) })
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+    let name: Vec<u8> = SeqVisitor(PhantomData).visit_seq(seq)?;
+
+    let Ok(name) = <[u8; 4]>::try_from(name.as_slice()) else {
+      return Err(A::Error::invalid_length(name.len(), &self));
+    };
+
+    // SAFETY: Section names are not required to be UTF-8, see `Parser`
+    Ok(unsafe { Magic::new_unchecked(name) })
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+/// Deserialize bitflags from their text representation.
+pub(crate) fn deserialize_flags<'de, D, F>(deserializer: D) -> Result<F, D::Error>
+where
+  D: Deserializer<'de>,
+  F: Flags,
+  F::Bits: parser::ParseHex,
+{
+  let text: String = String::deserialize(deserializer)?;
+
+  if text == "<<EMPTY>>" {
+    return Ok(F::empty());
+  }
+
+  parser::from_str(&text).map_err(D::Error::custom)
+}
+
+/// Serialize a value known by name, or by number if unknown.
+pub(crate) fn serialize_named<S>(
+  serializer: S,
+  name: Option<&str>,
+  value: u16,
+) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  match name {
+    Some(name) => serializer.serialize_str(name),
+    None => serializer.serialize_u16(value),
+  }
+}
+
+/// Deserialize a value known by name (within `range`), or by number.
+pub(crate) fn deserialize_named<'de, D, F>(
+  deserializer: D,
+  range: RangeInclusive<u16>,
+  name: F,
+) -> Result<u16, D::Error>
+where
+  D: Deserializer<'de>,
+  F: Fn(u16) -> &'static str,
+{
+  struct NamedVisitor<F> {
+    range: RangeInclusive<u16>,
+    name: F,
+  }
+
+  impl<'de, F> Visitor<'de> for NamedVisitor<F>
+  where
+    F: Fn(u16) -> &'static str,
+  {
+    type Value = u16;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+      f.write_str("a name or a number")
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+      u16::try_from(value).map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+      self
+        .range
+        .clone()
+        .find(|index| (self.name)(*index) == value)
+        .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+    }
+  }
+
+  deserializer.deserialize_any(NamedVisitor { range, name })
+}
@@ -0,0 +1,1114 @@
+use crate::item::TrigAction;
+use crate::item::TrigActionFlags;
+use crate::trigger::Alliance;
+use crate::trigger::Group;
+use crate::trigger::Modifier;
+use crate::trigger::Order;
+use crate::trigger::Resource;
+use crate::trigger::Score;
+use crate::trigger::StateAction;
+use crate::trigger::SwitchAction;
+
+// =============================================================================
+// Action
+// =============================================================================
+
+/// A trigger action with typed arguments.
+///
+/// Strings and locations are 1-based, switches are 0-based. Unit counts of
+/// zero mean all units. Unit types are kept as raw values since triggers also
+/// accept unit groups ("Any unit", "Men", ...).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Action {
+  /// No action, ends the list of actions.
+  None,
+  Victory,
+  Defeat,
+  PreserveTrigger,
+  Wait {
+    /// Milliseconds.
+    time: u32,
+  },
+  PauseGame,
+  UnpauseGame,
+  Transmission {
+    text: u32,
+    unit: u16,
+    location: u32,
+    modifier: Modifier,
+    /// Change to the display time, in milliseconds.
+    amount: u32,
+    wav: u32,
+    /// Duration of the WAV, in milliseconds.
+    duration: u32,
+  },
+  PlayWav {
+    wav: u32,
+    /// Duration of the WAV, in milliseconds.
+    duration: u32,
+  },
+  DisplayText {
+    text: u32,
+  },
+  CenterView {
+    location: u32,
+  },
+  CreateUnitWithProperties {
+    group: Group,
+    unit: u16,
+    count: u8,
+    location: u32,
+    /// Unit properties (`UPRP`, 1-based).
+    properties: u32,
+  },
+  SetMissionObjectives {
+    text: u32,
+  },
+  SetSwitch {
+    switch: u32,
+    action: SwitchAction,
+  },
+  SetCountdownTimer {
+    modifier: Modifier,
+    /// Seconds.
+    time: u32,
+  },
+  RunAiScript {
+    /// Script identifier (4 characters).
+    script: u32,
+  },
+  RunAiScriptAt {
+    /// Script identifier (4 characters).
+    script: u32,
+    location: u32,
+  },
+  LeaderboardControl {
+    text: u32,
+    unit: u16,
+  },
+  LeaderboardControlAt {
+    text: u32,
+    unit: u16,
+    location: u32,
+  },
+  LeaderboardResources {
+    text: u32,
+    resource: Resource,
+  },
+  LeaderboardKills {
+    text: u32,
+    unit: u16,
+  },
+  LeaderboardPoints {
+    text: u32,
+    score: Score,
+  },
+  KillUnit {
+    group: Group,
+    unit: u16,
+  },
+  KillUnitAt {
+    group: Group,
+    unit: u16,
+    count: u8,
+    location: u32,
+  },
+  RemoveUnit {
+    group: Group,
+    unit: u16,
+  },
+  RemoveUnitAt {
+    group: Group,
+    unit: u16,
+    count: u8,
+    location: u32,
+  },
+  SetResources {
+    group: Group,
+    modifier: Modifier,
+    amount: u32,
+    resource: Resource,
+  },
+  SetScore {
+    group: Group,
+    modifier: Modifier,
+    amount: u32,
+    score: Score,
+  },
+  MinimapPing {
+    location: u32,
+  },
+  TalkingPortrait {
+    unit: u16,
+    /// Milliseconds.
+    time: u32,
+  },
+  MuteUnitSpeech,
+  UnmuteUnitSpeech,
+  LeaderboardComputerPlayers {
+    state: StateAction,
+  },
+  LeaderboardGoalControl {
+    text: u32,
+    unit: u16,
+    goal: u32,
+  },
+  LeaderboardGoalControlAt {
+    text: u32,
+    unit: u16,
+    goal: u32,
+    location: u32,
+  },
+  LeaderboardGoalResources {
+    text: u32,
+    resource: Resource,
+    goal: u32,
+  },
+  LeaderboardGoalKills {
+    text: u32,
+    unit: u16,
+    goal: u32,
+  },
+  LeaderboardGoalPoints {
+    text: u32,
+    score: Score,
+    goal: u32,
+  },
+  MoveLocation {
+    group: Group,
+    unit: u16,
+    /// The location the unit is in.
+    source: u32,
+    /// The location to center on the unit.
+    destination: u32,
+  },
+  MoveUnit {
+    group: Group,
+    unit: u16,
+    count: u8,
+    source: u32,
+    destination: u32,
+  },
+  LeaderboardGreed {
+    goal: u32,
+  },
+  SetNextScenario {
+    text: u32,
+  },
+  SetDoodadState {
+    group: Group,
+    unit: u16,
+    location: u32,
+    state: StateAction,
+  },
+  SetInvincibility {
+    group: Group,
+    unit: u16,
+    location: u32,
+    state: StateAction,
+  },
+  CreateUnit {
+    group: Group,
+    unit: u16,
+    count: u8,
+    location: u32,
+  },
+  SetDeaths {
+    group: Group,
+    unit: u16,
+    modifier: Modifier,
+    amount: u32,
+  },
+  Order {
+    group: Group,
+    unit: u16,
+    source: u32,
+    destination: u32,
+    order: Order,
+  },
+  Comment {
+    text: u32,
+  },
+  GiveUnits {
+    group: Group,
+    target: Group,
+    unit: u16,
+    count: u8,
+    location: u32,
+  },
+  ModifyHitPoints {
+    group: Group,
+    unit: u16,
+    percent: u32,
+    count: u8,
+    location: u32,
+  },
+  ModifyEnergy {
+    group: Group,
+    unit: u16,
+    percent: u32,
+    count: u8,
+    location: u32,
+  },
+  ModifyShields {
+    group: Group,
+    unit: u16,
+    percent: u32,
+    count: u8,
+    location: u32,
+  },
+  ModifyResources {
+    group: Group,
+    amount: u32,
+    count: u8,
+    location: u32,
+  },
+  ModifyHangar {
+    group: Group,
+    unit: u16,
+    amount: u32,
+    count: u8,
+    location: u32,
+  },
+  PauseTimer,
+  UnpauseTimer,
+  Draw,
+  SetAllianceStatus {
+    group: Group,
+    status: Alliance,
+  },
+  DisableDebugMode,
+  EnableDebugMode,
+  /// An action type unknown to the game, kept as-is.
+  Unknown(TrigAction),
+}
+
+impl Action {
+  /// Decode the typed action from `raw` data.
+  pub fn decode(raw: &TrigAction) -> Self {
+    let group: Group = Group::from_u32(raw.group);
+    let unit: u16 = raw.kind;
+    let count: u8 = raw.units;
+    let location: u32 = raw.location;
+    let text: u32 = raw.txt_index;
+    let modifier: Modifier = Modifier::from_u8(raw.units);
+    let state: StateAction = StateAction::from_u8(raw.units);
+
+    match raw.action {
+      0x00 => Self::None,
+      0x01 => Self::Victory,
+      0x02 => Self::Defeat,
+      0x03 => Self::PreserveTrigger,
+      0x04 => Self::Wait { time: raw.time },
+      0x05 => Self::PauseGame,
+      0x06 => Self::UnpauseGame,
+      0x07 => Self::Transmission {
+        text,
+        unit,
+        location,
+        modifier,
+        amount: raw.state,
+        wav: raw.wav_index,
+        duration: raw.time,
+      },
+      0x08 => Self::PlayWav {
+        wav: raw.wav_index,
+        duration: raw.time,
+      },
+      0x09 => Self::DisplayText { text },
+      0x0A => Self::CenterView { location },
+      0x0B => Self::CreateUnitWithProperties {
+        group,
+        unit,
+        count,
+        location,
+        properties: raw.state,
+      },
+      0x0C => Self::SetMissionObjectives { text },
+      0x0D => Self::SetSwitch {
+        switch: raw.state,
+        action: SwitchAction::from_u8(raw.units),
+      },
+      0x0E => Self::SetCountdownTimer {
+        modifier,
+        time: raw.time,
+      },
+      0x0F => Self::RunAiScript { script: raw.state },
+      0x10 => Self::RunAiScriptAt {
+        script: raw.state,
+        location,
+      },
+      0x11 => Self::LeaderboardControl { text, unit },
+      0x12 => Self::LeaderboardControlAt {
+        text,
+        unit,
+        location,
+      },
+      0x13 => Self::LeaderboardResources {
+        text,
+        resource: Resource::from_u16(raw.kind),
+      },
+      0x14 => Self::LeaderboardKills { text, unit },
+      0x15 => Self::LeaderboardPoints {
+        text,
+        score: Score::from_u16(raw.kind),
+      },
+      0x16 => Self::KillUnit { group, unit },
+      0x17 => Self::KillUnitAt {
+        group,
+        unit,
+        count,
+        location,
+      },
+      0x18 => Self::RemoveUnit { group, unit },
+      0x19 => Self::RemoveUnitAt {
+        group,
+        unit,
+        count,
+        location,
+      },
+      0x1A => Self::SetResources {
+        group,
+        modifier,
+        amount: raw.state,
+        resource: Resource::from_u16(raw.kind),
+      },
+      0x1B => Self::SetScore {
+        group,
+        modifier,
+        amount: raw.state,
+        score: Score::from_u16(raw.kind),
+      },
+      0x1C => Self::MinimapPing { location },
+      0x1D => Self::TalkingPortrait {
+        unit,
+        time: raw.time,
+      },
+      0x1E => Self::MuteUnitSpeech,
+      0x1F => Self::UnmuteUnitSpeech,
+      0x20 => Self::LeaderboardComputerPlayers { state },
+      0x21 => Self::LeaderboardGoalControl {
+        text,
+        unit,
+        goal: raw.state,
+      },
+      0x22 => Self::LeaderboardGoalControlAt {
+        text,
+        unit,
+        goal: raw.state,
+        location,
+      },
+      0x23 => Self::LeaderboardGoalResources {
+        text,
+        resource: Resource::from_u16(raw.kind),
+        goal: raw.state,
+      },
+      0x24 => Self::LeaderboardGoalKills {
+        text,
+        unit,
+        goal: raw.state,
+      },
+      0x25 => Self::LeaderboardGoalPoints {
+        text,
+        score: Score::from_u16(raw.kind),
+        goal: raw.state,
+      },
+      0x26 => Self::MoveLocation {
+        group,
+        unit,
+        source: location,
+        destination: raw.state,
+      },
+      0x27 => Self::MoveUnit {
+        group,
+        unit,
+        count,
+        source: location,
+        destination: raw.state,
+      },
+      0x28 => Self::LeaderboardGreed { goal: raw.state },
+      0x29 => Self::SetNextScenario { text },
+      0x2A => Self::SetDoodadState {
+        group,
+        unit,
+        location,
+        state,
+      },
+      0x2B => Self::SetInvincibility {
+        group,
+        unit,
+        location,
+        state,
+      },
+      0x2C => Self::CreateUnit {
+        group,
+        unit,
+        count,
+        location,
+      },
+      0x2D => Self::SetDeaths {
+        group,
+        unit,
+        modifier,
+        amount: raw.state,
+      },
+      0x2E => Self::Order {
+        group,
+        unit,
+        source: location,
+        destination: raw.state,
+        order: Order::from_u8(raw.units),
+      },
+      0x2F => Self::Comment { text },
+      0x30 => Self::GiveUnits {
+        group,
+        target: Group::from_u32(raw.state),
+        unit,
+        count,
+        location,
+      },
+      0x31 => Self::ModifyHitPoints {
+        group,
+        unit,
+        percent: raw.state,
+        count,
+        location,
+      },
+      0x32 => Self::ModifyEnergy {
+        group,
+        unit,
+        percent: raw.state,
+        count,
+        location,
+      },
+      0x33 => Self::ModifyShields {
+        group,
+        unit,
+        percent: raw.state,
+        count,
+        location,
+      },
+      0x34 => Self::ModifyResources {
+        group,
+        amount: raw.state,
+        count,
+        location,
+      },
+      0x35 => Self::ModifyHangar {
+        group,
+        unit,
+        amount: raw.state,
+        count,
+        location,
+      },
+      0x36 => Self::PauseTimer,
+      0x37 => Self::UnpauseTimer,
+      0x38 => Self::Draw,
+      0x39 => Self::SetAllianceStatus {
+        group,
+        status: Alliance::from_u16(raw.kind),
+      },
+      0x3A => Self::DisableDebugMode,
+      0x3B => Self::EnableDebugMode,
+      _ => Self::Unknown(*raw),
+    }
+  }
+
+  /// Encode the action as raw data.
+  ///
+  /// Unused fields are zero. Actions using a unit type are flagged with
+  /// [`TrigActionFlags::UNIT_TYPE`], like StarEdit does.
+  pub fn encode(&self) -> TrigAction {
+    let mut raw: TrigAction = TrigAction {
+      action: self.opcode(),
+      ..TrigAction::default()
+    };
+
+    match *self {
+      Self::None
+      | Self::Victory
+      | Self::Defeat
+      | Self::PreserveTrigger
+      | Self::PauseGame
+      | Self::UnpauseGame
+      | Self::MuteUnitSpeech
+      | Self::UnmuteUnitSpeech
+      | Self::PauseTimer
+      | Self::UnpauseTimer
+      | Self::Draw
+      | Self::DisableDebugMode
+      | Self::EnableDebugMode => {}
+      Self::Wait { time } => {
+        raw.time = time;
+      }
+      Self::Transmission {
+        text,
+        unit,
+        location,
+        modifier,
+        amount,
+        wav,
+        duration,
+      } => {
+        raw.txt_index = text;
+        raw.kind = unit;
+        raw.location = location;
+        raw.units = modifier.as_u8();
+        raw.state = amount;
+        raw.wav_index = wav;
+        raw.time = duration;
+      }
+      Self::PlayWav { wav, duration } => {
+        raw.wav_index = wav;
+        raw.time = duration;
+      }
+      Self::DisplayText { text }
+      | Self::SetMissionObjectives { text }
+      | Self::SetNextScenario { text }
+      | Self::Comment { text } => {
+        raw.txt_index = text;
+      }
+      Self::CenterView { location } | Self::MinimapPing { location } => {
+        raw.location = location;
+      }
+      Self::CreateUnitWithProperties {
+        group,
+        unit,
+        count,
+        location,
+        properties,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.units = count;
+        raw.location = location;
+        raw.state = properties;
+        raw.flags |= TrigActionFlags::UNIT_PROPERTIES;
+      }
+      Self::SetSwitch { switch, action } => {
+        raw.state = switch;
+        raw.units = action.as_u8();
+      }
+      Self::SetCountdownTimer { modifier, time } => {
+        raw.units = modifier.as_u8();
+        raw.time = time;
+      }
+      Self::RunAiScript { script } => {
+        raw.state = script;
+      }
+      Self::RunAiScriptAt { script, location } => {
+        raw.state = script;
+        raw.location = location;
+      }
+      Self::LeaderboardControl { text, unit } | Self::LeaderboardKills { text, unit } => {
+        raw.txt_index = text;
+        raw.kind = unit;
+      }
+      Self::LeaderboardControlAt {
+        text,
+        unit,
+        location,
+      } => {
+        raw.txt_index = text;
+        raw.kind = unit;
+        raw.location = location;
+      }
+      Self::LeaderboardResources { text, resource } => {
+        raw.txt_index = text;
+        raw.kind = resource.as_u16();
+      }
+      Self::LeaderboardPoints { text, score } => {
+        raw.txt_index = text;
+        raw.kind = score.as_u16();
+      }
+      Self::KillUnit { group, unit } | Self::RemoveUnit { group, unit } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+      }
+      Self::KillUnitAt {
+        group,
+        unit,
+        count,
+        location,
+      }
+      | Self::RemoveUnitAt {
+        group,
+        unit,
+        count,
+        location,
+      }
+      | Self::CreateUnit {
+        group,
+        unit,
+        count,
+        location,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.units = count;
+        raw.location = location;
+      }
+      Self::SetResources {
+        group,
+        modifier,
+        amount,
+        resource,
+      } => {
+        raw.group = group.as_u32();
+        raw.units = modifier.as_u8();
+        raw.state = amount;
+        raw.kind = resource.as_u16();
+      }
+      Self::SetScore {
+        group,
+        modifier,
+        amount,
+        score,
+      } => {
+        raw.group = group.as_u32();
+        raw.units = modifier.as_u8();
+        raw.state = amount;
+        raw.kind = score.as_u16();
+      }
+      Self::TalkingPortrait { unit, time } => {
+        raw.kind = unit;
+        raw.time = time;
+      }
+      Self::LeaderboardComputerPlayers { state } => {
+        raw.units = state.as_u8();
+      }
+      Self::LeaderboardGoalControl { text, unit, goal }
+      | Self::LeaderboardGoalKills { text, unit, goal } => {
+        raw.txt_index = text;
+        raw.kind = unit;
+        raw.state = goal;
+      }
+      Self::LeaderboardGoalControlAt {
+        text,
+        unit,
+        goal,
+        location,
+      } => {
+        raw.txt_index = text;
+        raw.kind = unit;
+        raw.state = goal;
+        raw.location = location;
+      }
+      Self::LeaderboardGoalResources {
+        text,
+        resource,
+        goal,
+      } => {
+        raw.txt_index = text;
+        raw.kind = resource.as_u16();
+        raw.state = goal;
+      }
+      Self::LeaderboardGoalPoints { text, score, goal } => {
+        raw.txt_index = text;
+        raw.kind = score.as_u16();
+        raw.state = goal;
+      }
+      Self::MoveLocation {
+        group,
+        unit,
+        source,
+        destination,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.location = source;
+        raw.state = destination;
+      }
+      Self::MoveUnit {
+        group,
+        unit,
+        count,
+        source,
+        destination,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.units = count;
+        raw.location = source;
+        raw.state = destination;
+      }
+      Self::LeaderboardGreed { goal } => {
+        raw.state = goal;
+      }
+      Self::SetDoodadState {
+        group,
+        unit,
+        location,
+        state,
+      }
+      | Self::SetInvincibility {
+        group,
+        unit,
+        location,
+        state,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.location = location;
+        raw.units = state.as_u8();
+      }
+      Self::SetDeaths {
+        group,
+        unit,
+        modifier,
+        amount,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.units = modifier.as_u8();
+        raw.state = amount;
+      }
+      Self::Order {
+        group,
+        unit,
+        source,
+        destination,
+        order,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.location = source;
+        raw.state = destination;
+        raw.units = order.as_u8();
+      }
+      Self::GiveUnits {
+        group,
+        target,
+        unit,
+        count,
+        location,
+      } => {
+        raw.group = group.as_u32();
+        raw.state = target.as_u32();
+        raw.kind = unit;
+        raw.units = count;
+        raw.location = location;
+      }
+      Self::ModifyHitPoints {
+        group,
+        unit,
+        percent: amount,
+        count,
+        location,
+      }
+      | Self::ModifyEnergy {
+        group,
+        unit,
+        percent: amount,
+        count,
+        location,
+      }
+      | Self::ModifyShields {
+        group,
+        unit,
+        percent: amount,
+        count,
+        location,
+      }
+      | Self::ModifyHangar {
+        group,
+        unit,
+        amount,
+        count,
+        location,
+      } => {
+        raw.group = group.as_u32();
+        raw.kind = unit;
+        raw.state = amount;
+        raw.units = count;
+        raw.location = location;
+      }
+      Self::ModifyResources {
+        group,
+        amount,
+        count,
+        location,
+      } => {
+        raw.group = group.as_u32();
+        raw.state = amount;
+        raw.units = count;
+        raw.location = location;
+      }
+      Self::SetAllianceStatus { group, status } => {
+        raw.group = group.as_u32();
+        raw.kind = status.as_u16();
+      }
+      Self::Unknown(raw) => return raw,
+    }
+
+    if self.unit().is_some() {
+      raw.flags |= TrigActionFlags::UNIT_TYPE;
+    }
+
+    raw
+  }
+
+  /// Returns the action type.
+  pub const fn opcode(&self) -> u8 {
+    match self {
+      Self::None => 0x00,
+      Self::Victory => 0x01,
+      Self::Defeat => 0x02,
+      Self::PreserveTrigger => 0x03,
+      Self::Wait { .. } => 0x04,
+      Self::PauseGame => 0x05,
+      Self::UnpauseGame => 0x06,
+      Self::Transmission { .. } => 0x07,
+      Self::PlayWav { .. } => 0x08,
+      Self::DisplayText { .. } => 0x09,
+      Self::CenterView { .. } => 0x0A,
+      Self::CreateUnitWithProperties { .. } => 0x0B,
+      Self::SetMissionObjectives { .. } => 0x0C,
+      Self::SetSwitch { .. } => 0x0D,
+      Self::SetCountdownTimer { .. } => 0x0E,
+      Self::RunAiScript { .. } => 0x0F,
+      Self::RunAiScriptAt { .. } => 0x10,
+      Self::LeaderboardControl { .. } => 0x11,
+      Self::LeaderboardControlAt { .. } => 0x12,
+      Self::LeaderboardResources { .. } => 0x13,
+      Self::LeaderboardKills { .. } => 0x14,
+      Self::LeaderboardPoints { .. } => 0x15,
+      Self::KillUnit { .. } => 0x16,
+      Self::KillUnitAt { .. } => 0x17,
+      Self::RemoveUnit { .. } => 0x18,
+      Self::RemoveUnitAt { .. } => 0x19,
+      Self::SetResources { .. } => 0x1A,
+      Self::SetScore { .. } => 0x1B,
+      Self::MinimapPing { .. } => 0x1C,
+      Self::TalkingPortrait { .. } => 0x1D,
+      Self::MuteUnitSpeech => 0x1E,
+      Self::UnmuteUnitSpeech => 0x1F,
+      Self::LeaderboardComputerPlayers { .. } => 0x20,
+      Self::LeaderboardGoalControl { .. } => 0x21,
+      Self::LeaderboardGoalControlAt { .. } => 0x22,
+      Self::LeaderboardGoalResources { .. } => 0x23,
+      Self::LeaderboardGoalKills { .. } => 0x24,
+      Self::LeaderboardGoalPoints { .. } => 0x25,
+      Self::MoveLocation { .. } => 0x26,
+      Self::MoveUnit { .. } => 0x27,
+      Self::LeaderboardGreed { .. } => 0x28,
+      Self::SetNextScenario { .. } => 0x29,
+      Self::SetDoodadState { .. } => 0x2A,
+      Self::SetInvincibility { .. } => 0x2B,
+      Self::CreateUnit { .. } => 0x2C,
+      Self::SetDeaths { .. } => 0x2D,
+      Self::Order { .. } => 0x2E,
+      Self::Comment { .. } => 0x2F,
+      Self::GiveUnits { .. } => 0x30,
+      Self::ModifyHitPoints { .. } => 0x31,
+      Self::ModifyEnergy { .. } => 0x32,
+      Self::ModifyShields { .. } => 0x33,
+      Self::ModifyResources { .. } => 0x34,
+      Self::ModifyHangar { .. } => 0x35,
+      Self::PauseTimer => 0x36,
+      Self::UnpauseTimer => 0x37,
+      Self::Draw => 0x38,
+      Self::SetAllianceStatus { .. } => 0x39,
+      Self::DisableDebugMode => 0x3A,
+      Self::EnableDebugMode => 0x3B,
+      Self::Unknown(raw) => raw.action,
+    }
+  }
+
+  /// Returns the name of the action type.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match Self::name_of(self.opcode()) {
+      Some(name) => name,
+      None => "Unknown",
+    }
+  }
+
+  /// Returns the name of the action type `opcode`, if known.
+  pub const fn name_of(opcode: u8) -> Option<&'static str> {
+    match opcode {
+      0x00 => Some("No Action"),
+      0x01 => Some("Victory"),
+      0x02 => Some("Defeat"),
+      0x03 => Some("Preserve Trigger"),
+      0x04 => Some("Wait"),
+      0x05 => Some("Pause Game"),
+      0x06 => Some("Unpause Game"),
+      0x07 => Some("Transmission"),
+      0x08 => Some("Play WAV"),
+      0x09 => Some("Display Text Message"),
+      0x0A => Some("Center View"),
+      0x0B => Some("Create Unit with Properties"),
+      0x0C => Some("Set Mission Objectives"),
+      0x0D => Some("Set Switch"),
+      0x0E => Some("Set Countdown Timer"),
+      0x0F => Some("Run AI Script"),
+      0x10 => Some("Run AI Script At Location"),
+      0x11 => Some("Leader Board Control"),
+      0x12 => Some("Leader Board Control At Location"),
+      0x13 => Some("Leader Board Resources"),
+      0x14 => Some("Leader Board Kills"),
+      0x15 => Some("Leader Board Points"),
+      0x16 => Some("Kill Unit"),
+      0x17 => Some("Kill Unit At Location"),
+      0x18 => Some("Remove Unit"),
+      0x19 => Some("Remove Unit At Location"),
+      0x1A => Some("Set Resources"),
+      0x1B => Some("Set Score"),
+      0x1C => Some("Minimap Ping"),
+      0x1D => Some("Talking Portrait"),
+      0x1E => Some("Mute Unit Speech"),
+      0x1F => Some("Unmute Unit Speech"),
+      0x20 => Some("Leaderboard Computer Players"),
+      0x21 => Some("Leaderboard Goal Control"),
+      0x22 => Some("Leaderboard Goal Control At Location"),
+      0x23 => Some("Leaderboard Goal Resources"),
+      0x24 => Some("Leaderboard Goal Kills"),
+      0x25 => Some("Leaderboard Goal Points"),
+      0x26 => Some("Move Location"),
+      0x27 => Some("Move Unit"),
+      0x28 => Some("Leaderboard Greed"),
+      0x29 => Some("Set Next Scenario"),
+      0x2A => Some("Set Doodad State"),
+      0x2B => Some("Set Invincibility"),
+      0x2C => Some("Create Unit"),
+      0x2D => Some("Set Deaths"),
+      0x2E => Some("Order"),
+      0x2F => Some("Comment"),
+      0x30 => Some("Give Units to Player"),
+      0x31 => Some("Modify Unit Hit Points"),
+      0x32 => Some("Modify Unit Energy"),
+      0x33 => Some("Modify Unit Shield Points"),
+      0x34 => Some("Modify Unit Resource Amount"),
+      0x35 => Some("Modify Unit Hanger Count"),
+      0x36 => Some("Pause Timer"),
+      0x37 => Some("Unpause Timer"),
+      0x38 => Some("Draw"),
+      0x39 => Some("Set Alliance Status"),
+      0x3A => Some("Disable Debug Mode"),
+      0x3B => Some("Enable Debug Mode"),
+      _ => None,
+    }
+  }
+
+  /// Returns the unit type the action applies to, if any.
+  pub const fn unit(&self) -> Option<u16> {
+    match *self {
+      Self::Transmission { unit, .. }
+      | Self::CreateUnitWithProperties { unit, .. }
+      | Self::LeaderboardControl { unit, .. }
+      | Self::LeaderboardControlAt { unit, .. }
+      | Self::LeaderboardKills { unit, .. }
+      | Self::KillUnit { unit, .. }
+      | Self::KillUnitAt { unit, .. }
+      | Self::RemoveUnit { unit, .. }
+      | Self::RemoveUnitAt { unit, .. }
+      | Self::TalkingPortrait { unit, .. }
+      | Self::LeaderboardGoalControl { unit, .. }
+      | Self::LeaderboardGoalControlAt { unit, .. }
+      | Self::LeaderboardGoalKills { unit, .. }
+      | Self::MoveLocation { unit, .. }
+      | Self::MoveUnit { unit, .. }
+      | Self::SetDoodadState { unit, .. }
+      | Self::SetInvincibility { unit, .. }
+      | Self::CreateUnit { unit, .. }
+      | Self::SetDeaths { unit, .. }
+      | Self::Order { unit, .. }
+      | Self::GiveUnits { unit, .. }
+      | Self::ModifyHitPoints { unit, .. }
+      | Self::ModifyEnergy { unit, .. }
+      | Self::ModifyShields { unit, .. }
+      | Self::ModifyHangar { unit, .. } => Some(unit),
+      _ => None,
+    }
+  }
+
+  /// Returns the (1-based) locations the action uses.
+  pub fn locations(&self) -> Vec<u32> {
+    match *self {
+      Self::Transmission { location, .. }
+      | Self::CenterView { location }
+      | Self::CreateUnitWithProperties { location, .. }
+      | Self::RunAiScriptAt { location, .. }
+      | Self::LeaderboardControlAt { location, .. }
+      | Self::KillUnitAt { location, .. }
+      | Self::RemoveUnitAt { location, .. }
+      | Self::MinimapPing { location }
+      | Self::LeaderboardGoalControlAt { location, .. }
+      | Self::SetDoodadState { location, .. }
+      | Self::SetInvincibility { location, .. }
+      | Self::CreateUnit { location, .. }
+      | Self::GiveUnits { location, .. }
+      | Self::ModifyHitPoints { location, .. }
+      | Self::ModifyEnergy { location, .. }
+      | Self::ModifyShields { location, .. }
+      | Self::ModifyResources { location, .. }
+      | Self::ModifyHangar { location, .. } => vec![location],
+      Self::MoveLocation {
+        source,
+        destination,
+        ..
+      }
+      | Self::MoveUnit {
+        source,
+        destination,
+        ..
+      }
+      | Self::Order {
+        source,
+        destination,
+        ..
+      } => vec![source, destination],
+      _ => Vec::new(),
+    }
+  }
+
+  /// Returns the (1-based) text string the action uses, if any.
+  pub const fn text(&self) -> Option<u32> {
+    match *self {
+      Self::Transmission { text, .. }
+      | Self::DisplayText { text }
+      | Self::SetMissionObjectives { text }
+      | Self::LeaderboardControl { text, .. }
+      | Self::LeaderboardControlAt { text, .. }
+      | Self::LeaderboardResources { text, .. }
+      | Self::LeaderboardKills { text, .. }
+      | Self::LeaderboardPoints { text, .. }
+      | Self::LeaderboardGoalControl { text, .. }
+      | Self::LeaderboardGoalControlAt { text, .. }
+      | Self::LeaderboardGoalResources { text, .. }
+      | Self::LeaderboardGoalKills { text, .. }
+      | Self::LeaderboardGoalPoints { text, .. }
+      | Self::SetNextScenario { text }
+      | Self::Comment { text } => Some(text),
+      _ => None,
+    }
+  }
+
+  /// Returns the (1-based) WAV file name string the action uses, if any.
+  pub const fn wav(&self) -> Option<u32> {
+    match *self {
+      Self::Transmission { wav, .. } | Self::PlayWav { wav, .. } => Some(wav),
+      _ => None,
+    }
+  }
+
+  /// Returns the (1-based) strings the action uses (text and WAV).
+  pub fn strings(&self) -> Vec<u32> {
+    self.text().into_iter().chain(self.wav()).collect()
+  }
+}
+
+impl TrigAction {
+  /// Returns the action with typed arguments.
+  #[inline]
+  pub fn decode(&self) -> Action {
+    Action::decode(self)
+  }
+
+  /// Returns `true` if the action is disabled.
+  #[inline]
+  pub const fn is_disabled(&self) -> bool {
+    self.flags.contains(TrigActionFlags::DISABLED)
+  }
+}
@@ -0,0 +1,422 @@
+// =============================================================================
+// Group
+// =============================================================================
+
+/// A player or group of players.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Group(u32);
+
+impl Group {
+  pub const NONE: Self = Self(0x0C);
+  pub const CURRENT_PLAYER: Self = Self(0x0D);
+  pub const FOES: Self = Self(0x0E);
+  pub const ALLIES: Self = Self(0x0F);
+  pub const NEUTRAL_PLAYERS: Self = Self(0x10);
+  pub const ALL_PLAYERS: Self = Self(0x11);
+  pub const NON_AV_PLAYERS: Self = Self(0x1A);
+
+  #[inline]
+  pub const fn from_u32(value: u32) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u32(self) -> u32 {
+    self.0
+  }
+
+  /// Returns the group of the (0-based) `player`.
+  #[inline]
+  pub const fn player(player: u8) -> Self {
+    Self(player as u32)
+  }
+
+  /// Returns the group of the (0-based) `force`.
+  #[inline]
+  pub const fn force(force: u8) -> Self {
+    Self(0x12 + force as u32)
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x00 => "Player 1",
+      0x01 => "Player 2",
+      0x02 => "Player 3",
+      0x03 => "Player 4",
+      0x04 => "Player 5",
+      0x05 => "Player 6",
+      0x06 => "Player 7",
+      0x07 => "Player 8",
+      0x08 => "Player 9",
+      0x09 => "Player 10",
+      0x0A => "Player 11",
+      0x0B => "Player 12",
+      0x0C => "None",
+      0x0D => "Current Player",
+      0x0E => "Foes",
+      0x0F => "Allies",
+      0x10 => "Neutral Players",
+      0x11 => "All players",
+      0x12 => "Force 1",
+      0x13 => "Force 2",
+      0x14 => "Force 3",
+      0x15 => "Force 4",
+      0x16 => "Unused 1",
+      0x17 => "Unused 2",
+      0x18 => "Unused 3",
+      0x19 => "Unused 4",
+      0x1A => "Non Allied Victory Players",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(Group -> as_str);
+
+// =============================================================================
+// Comparison
+// =============================================================================
+
+/// A numeric comparison.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Comparison(u8);
+
+impl Comparison {
+  pub const AT_LEAST: Self = Self(0x00);
+  pub const AT_MOST: Self = Self(0x01);
+  pub const EXACTLY: Self = Self(0x0A);
+
+  #[inline]
+  pub const fn from_u8(value: u8) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u8(self) -> u8 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x00 => "At least",
+      0x01 => "At most",
+      0x0A => "Exactly",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(Comparison -> as_str);
+
+// =============================================================================
+// Modifier
+// =============================================================================
+
+/// A numeric modifier.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Modifier(u8);
+
+impl Modifier {
+  pub const SET_TO: Self = Self(0x07);
+  pub const ADD: Self = Self(0x08);
+  pub const SUBTRACT: Self = Self(0x09);
+
+  #[inline]
+  pub const fn from_u8(value: u8) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u8(self) -> u8 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x07 => "Set To",
+      0x08 => "Add",
+      0x09 => "Subtract",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(Modifier -> as_str);
+
+// =============================================================================
+// Switch State
+// =============================================================================
+
+/// The state of a switch, checked by the Switch condition.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct SwitchState(u8);
+
+impl SwitchState {
+  pub const SET: Self = Self(0x02);
+  pub const NOT_SET: Self = Self(0x03);
+
+  #[inline]
+  pub const fn from_u8(value: u8) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u8(self) -> u8 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x02 => "set",
+      0x03 => "not set",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(SwitchState -> as_str);
+
+// =============================================================================
+// Switch Action
+// =============================================================================
+
+/// A change to a switch, applied by the Set Switch action.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct SwitchAction(u8);
+
+impl SwitchAction {
+  pub const SET: Self = Self(0x04);
+  pub const CLEAR: Self = Self(0x05);
+  pub const TOGGLE: Self = Self(0x06);
+  pub const RANDOMIZE: Self = Self(0x0B);
+
+  #[inline]
+  pub const fn from_u8(value: u8) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u8(self) -> u8 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x04 => "set",
+      0x05 => "clear",
+      0x06 => "toggle",
+      0x0B => "randomize",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(SwitchAction -> as_str);
+
+// =============================================================================
+// State Action
+// =============================================================================
+
+/// A change to an on/off state (doodads, invincibility, computer players on
+/// the leaderboard).
+///
+/// Uses the same values as [`SwitchAction`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct StateAction(u8);
+
+impl StateAction {
+  pub const ENABLE: Self = Self(0x04);
+  pub const DISABLE: Self = Self(0x05);
+  pub const TOGGLE: Self = Self(0x06);
+
+  #[inline]
+  pub const fn from_u8(value: u8) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u8(self) -> u8 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x04 => "enabled",
+      0x05 => "disabled",
+      0x06 => "toggle",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(StateAction -> as_str);
+
+// =============================================================================
+// Resource
+// =============================================================================
+
+/// A resource type.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Resource(u16);
+
+impl Resource {
+  pub const ORE: Self = Self(0x00);
+  pub const GAS: Self = Self(0x01);
+  pub const ORE_AND_GAS: Self = Self(0x02);
+
+  #[inline]
+  pub const fn from_u16(value: u16) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u16(self) -> u16 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x00 => "ore",
+      0x01 => "gas",
+      0x02 => "ore and gas",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(Resource -> as_str);
+
+// =============================================================================
+// Score
+// =============================================================================
+
+/// A score type.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Score(u16);
+
+impl Score {
+  pub const TOTAL: Self = Self(0x00);
+  pub const UNITS: Self = Self(0x01);
+  pub const BUILDINGS: Self = Self(0x02);
+  pub const UNITS_AND_BUILDINGS: Self = Self(0x03);
+  pub const KILLS: Self = Self(0x04);
+  pub const RAZINGS: Self = Self(0x05);
+  pub const KILLS_AND_RAZINGS: Self = Self(0x06);
+  pub const CUSTOM: Self = Self(0x07);
+
+  #[inline]
+  pub const fn from_u16(value: u16) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u16(self) -> u16 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x00 => "Total",
+      0x01 => "Units",
+      0x02 => "Buildings",
+      0x03 => "Units and buildings",
+      0x04 => "Kills",
+      0x05 => "Razings",
+      0x06 => "Kills and razings",
+      0x07 => "Custom",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(Score -> as_str);
+
+// =============================================================================
+// Alliance
+// =============================================================================
+
+/// An alliance status.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Alliance(u16);
+
+impl Alliance {
+  pub const ENEMY: Self = Self(0x00);
+  pub const ALLY: Self = Self(0x01);
+  pub const ALLIED_VICTORY: Self = Self(0x02);
+
+  #[inline]
+  pub const fn from_u16(value: u16) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u16(self) -> u16 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x00 => "Enemy",
+      0x01 => "Ally",
+      0x02 => "Allied Victory",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(Alliance -> as_str);
+
+// =============================================================================
+// Order
+// =============================================================================
+
+/// A unit order, issued by the Order action.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Order(u8);
+
+impl Order {
+  pub const MOVE: Self = Self(0x00);
+  pub const PATROL: Self = Self(0x01);
+  pub const ATTACK: Self = Self(0x02);
+
+  #[inline]
+  pub const fn from_u8(value: u8) -> Self {
+    Self(value)
+  }
+
+  #[inline]
+  pub const fn as_u8(self) -> u8 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self.0 {
+      0x00 => "move",
+      0x01 => "patrol",
+      0x02 => "attack",
+      _ => "Unknown",
+    }
+  }
+}
+
+delegate_fmt!(Order -> as_str);
@@ -0,0 +1,257 @@
+use crate::item::TrigAction;
+use crate::item::TrigActionFlags;
+use crate::trigger::Modifier;
+
+// =============================================================================
+// Briefing Action
+// =============================================================================
+
+/// A mission briefing action with typed arguments.
+///
+/// Briefings (`MBRF`) share the layout of triggers but use their own action
+/// types. Briefing conditions are regular [`Condition`]s, usually
+/// [`Condition::MissionBriefing`].
+///
+/// Strings are 1-based, portrait slots are 0-based.
+///
+/// [`Condition`]: crate::trigger::Condition
+/// [`Condition::MissionBriefing`]: crate::trigger::Condition::MissionBriefing
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum BriefingAction {
+  /// No action, ends the list of actions.
+  None,
+  Wait {
+    /// Milliseconds.
+    time: u32,
+  },
+  PlayWav {
+    wav: u32,
+    /// Duration of the WAV, in milliseconds.
+    duration: u32,
+  },
+  TextMessage {
+    text: u32,
+    /// Milliseconds.
+    time: u32,
+  },
+  MissionObjectives {
+    text: u32,
+  },
+  ShowPortrait {
+    unit: u16,
+    slot: u32,
+  },
+  HidePortrait {
+    slot: u32,
+  },
+  SpeakingPortrait {
+    slot: u32,
+    /// Milliseconds.
+    time: u32,
+  },
+  Transmission {
+    text: u32,
+    slot: u32,
+    modifier: Modifier,
+    /// Change to the display time, in milliseconds.
+    amount: u32,
+    wav: u32,
+    /// Duration of the WAV, in milliseconds.
+    duration: u32,
+  },
+  SkipTutorialEnabled,
+  /// An action type unknown to the game, kept as-is.
+  Unknown(TrigAction),
+}
+
+impl BriefingAction {
+  /// Decode the typed briefing action from `raw` data.
+  pub fn decode(raw: &TrigAction) -> Self {
+    match raw.action {
+      0x00 => Self::None,
+      0x01 => Self::Wait { time: raw.time },
+      0x02 => Self::PlayWav {
+        wav: raw.wav_index,
+        duration: raw.time,
+      },
+      0x03 => Self::TextMessage {
+        text: raw.txt_index,
+        time: raw.time,
+      },
+      0x04 => Self::MissionObjectives {
+        text: raw.txt_index,
+      },
+      0x05 => Self::ShowPortrait {
+        unit: raw.kind,
+        slot: raw.group,
+      },
+      0x06 => Self::HidePortrait { slot: raw.group },
+      0x07 => Self::SpeakingPortrait {
+        slot: raw.group,
+        time: raw.time,
+      },
+      0x08 => Self::Transmission {
+        text: raw.txt_index,
+        slot: raw.group,
+        modifier: Modifier::from_u8(raw.units),
+        amount: raw.state,
+        wav: raw.wav_index,
+        duration: raw.time,
+      },
+      0x09 => Self::SkipTutorialEnabled,
+      _ => Self::Unknown(*raw),
+    }
+  }
+
+  /// Encode the briefing action as raw data.
+  ///
+  /// Unused fields are zero. Actions using a unit type are flagged with
+  /// [`TrigActionFlags::UNIT_TYPE`].
+  pub fn encode(&self) -> TrigAction {
+    let mut raw: TrigAction = TrigAction {
+      action: self.opcode(),
+      ..TrigAction::default()
+    };
+
+    match *self {
+      Self::None | Self::SkipTutorialEnabled => {}
+      Self::Wait { time } => {
+        raw.time = time;
+      }
+      Self::PlayWav { wav, duration } => {
+        raw.wav_index = wav;
+        raw.time = duration;
+      }
+      Self::TextMessage { text, time } => {
+        raw.txt_index = text;
+        raw.time = time;
+      }
+      Self::MissionObjectives { text } => {
+        raw.txt_index = text;
+      }
+      Self::ShowPortrait { unit, slot } => {
+        raw.kind = unit;
+        raw.group = slot;
+        raw.flags |= TrigActionFlags::UNIT_TYPE;
+      }
+      Self::HidePortrait { slot } => {
+        raw.group = slot;
+      }
+      Self::SpeakingPortrait { slot, time } => {
+        raw.group = slot;
+        raw.time = time;
+      }
+      Self::Transmission {
+        text,
+        slot,
+        modifier,
+        amount,
+        wav,
+        duration,
+      } => {
+        raw.txt_index = text;
+        raw.group = slot;
+        raw.units = modifier.as_u8();
+        raw.state = amount;
+        raw.wav_index = wav;
+        raw.time = duration;
+      }
+      Self::Unknown(raw) => return raw,
+    }
+
+    raw
+  }
+
+  /// Returns the action type.
+  pub const fn opcode(&self) -> u8 {
+    match self {
+      Self::None => 0x00,
+      Self::Wait { .. } => 0x01,
+      Self::PlayWav { .. } => 0x02,
+      Self::TextMessage { .. } => 0x03,
+      Self::MissionObjectives { .. } => 0x04,
+      Self::ShowPortrait { .. } => 0x05,
+      Self::HidePortrait { .. } => 0x06,
+      Self::SpeakingPortrait { .. } => 0x07,
+      Self::Transmission { .. } => 0x08,
+      Self::SkipTutorialEnabled => 0x09,
+      Self::Unknown(raw) => raw.action,
+    }
+  }
+
+  /// Returns the name of the action type.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match Self::name_of(self.opcode()) {
+      Some(name) => name,
+      None => "Unknown",
+    }
+  }
+
+  /// Returns the name of the briefing action type `opcode`, if known.
+  pub const fn name_of(opcode: u8) -> Option<&'static str> {
+    match opcode {
+      0x00 => Some("No Action"),
+      0x01 => Some("Wait"),
+      0x02 => Some("Play WAV"),
+      0x03 => Some("Text Message"),
+      0x04 => Some("Mission Objectives"),
+      0x05 => Some("Show Portrait"),
+      0x06 => Some("Hide Portrait"),
+      0x07 => Some("Display Speaking Portrait"),
+      0x08 => Some("Transmission"),
+      0x09 => Some("Skip Tutorial Enabled"),
+      _ => None,
+    }
+  }
+
+  /// Returns the unit type the action applies to, if any.
+  pub const fn unit(&self) -> Option<u16> {
+    match *self {
+      Self::ShowPortrait { unit, .. } => Some(unit),
+      _ => None,
+    }
+  }
+
+  /// Returns the portrait slot the action applies to, if any.
+  pub const fn slot(&self) -> Option<u32> {
+    match *self {
+      Self::ShowPortrait { slot, .. }
+      | Self::HidePortrait { slot }
+      | Self::SpeakingPortrait { slot, .. }
+      | Self::Transmission { slot, .. } => Some(slot),
+      _ => None,
+    }
+  }
+
+  /// Returns the (1-based) text string the action uses, if any.
+  pub const fn text(&self) -> Option<u32> {
+    match *self {
+      Self::Transmission { text, .. }
+      | Self::TextMessage { text, .. }
+      | Self::MissionObjectives { text } => Some(text),
+      _ => None,
+    }
+  }
+
+  /// Returns the (1-based) WAV file name string the action uses, if any.
+  pub const fn wav(&self) -> Option<u32> {
+    match *self {
+      Self::Transmission { wav, .. } | Self::PlayWav { wav, .. } => Some(wav),
+      _ => None,
+    }
+  }
+
+  /// Returns the (1-based) strings the action uses (text and WAV).
+  pub fn strings(&self) -> Vec<u32> {
+    self.text().into_iter().chain(self.wav()).collect()
+  }
+}
+
+impl TrigAction {
+  /// Returns the action with typed arguments, as a briefing action.
+  #[inline]
+  pub fn decode_briefing(&self) -> BriefingAction {
+    BriefingAction::decode(self)
+  }
+}
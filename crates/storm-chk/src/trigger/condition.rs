@@ -0,0 +1,432 @@
+use crate::item::TrigCondition;
+use crate::item::TrigConditionFlags;
+use crate::trigger::Comparison;
+use crate::trigger::Group;
+use crate::trigger::Resource;
+use crate::trigger::Score;
+use crate::trigger::SwitchState;
+
+// =============================================================================
+// Condition
+// =============================================================================
+
+/// A trigger condition with typed arguments.
+///
+/// Locations are 1-based, switches are 0-based. Unit types are kept as raw
+/// values since triggers also accept unit groups ("Any unit", "Men", ...).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Condition {
+  /// No condition, ends the list of conditions.
+  None,
+  CountdownTimer {
+    comparison: Comparison,
+    time: u32,
+  },
+  Command {
+    group: Group,
+    comparison: Comparison,
+    count: u32,
+    unit: u16,
+  },
+  Bring {
+    group: Group,
+    comparison: Comparison,
+    count: u32,
+    unit: u16,
+    location: u32,
+  },
+  Accumulate {
+    group: Group,
+    comparison: Comparison,
+    amount: u32,
+    resource: Resource,
+  },
+  Kill {
+    group: Group,
+    comparison: Comparison,
+    count: u32,
+    unit: u16,
+  },
+  CommandTheMost {
+    unit: u16,
+  },
+  CommandTheMostAt {
+    unit: u16,
+    location: u32,
+  },
+  MostKills {
+    unit: u16,
+  },
+  HighestScore {
+    score: Score,
+  },
+  MostResources {
+    resource: Resource,
+  },
+  Switch {
+    switch: u32,
+    state: SwitchState,
+  },
+  ElapsedTime {
+    comparison: Comparison,
+    time: u32,
+  },
+  /// Only used by mission briefings.
+  MissionBriefing,
+  Opponents {
+    group: Group,
+    comparison: Comparison,
+    count: u32,
+  },
+  Deaths {
+    group: Group,
+    comparison: Comparison,
+    count: u32,
+    unit: u16,
+  },
+  CommandTheLeast {
+    unit: u16,
+  },
+  CommandTheLeastAt {
+    unit: u16,
+    location: u32,
+  },
+  LeastKills {
+    unit: u16,
+  },
+  LowestScore {
+    score: Score,
+  },
+  LeastResources {
+    resource: Resource,
+  },
+  Score {
+    group: Group,
+    comparison: Comparison,
+    score: Score,
+    amount: u32,
+  },
+  Always,
+  Never,
+  /// A condition type unknown to the game, kept as-is.
+  Unknown(TrigCondition),
+}
+
+impl Condition {
+  /// Decode the typed condition from `raw` data.
+  pub fn decode(raw: &TrigCondition) -> Self {
+    let group: Group = Group::from_u32(raw.group);
+    let comparison: Comparison = Comparison::from_u8(raw.state);
+
+    match raw.condition {
+      0x00 => Self::None,
+      0x01 => Self::CountdownTimer {
+        comparison,
+        time: raw.count,
+      },
+      0x02 => Self::Command {
+        group,
+        comparison,
+        count: raw.count,
+        unit: raw.unit_id,
+      },
+      0x03 => Self::Bring {
+        group,
+        comparison,
+        count: raw.count,
+        unit: raw.unit_id,
+        location: raw.location,
+      },
+      0x04 => Self::Accumulate {
+        group,
+        comparison,
+        amount: raw.count,
+        resource: Resource::from_u16(raw.kind.into()),
+      },
+      0x05 => Self::Kill {
+        group,
+        comparison,
+        count: raw.count,
+        unit: raw.unit_id,
+      },
+      0x06 => Self::CommandTheMost { unit: raw.unit_id },
+      0x07 => Self::CommandTheMostAt {
+        unit: raw.unit_id,
+        location: raw.location,
+      },
+      0x08 => Self::MostKills { unit: raw.unit_id },
+      0x09 => Self::HighestScore {
+        score: Score::from_u16(raw.kind.into()),
+      },
+      0x0A => Self::MostResources {
+        resource: Resource::from_u16(raw.kind.into()),
+      },
+      0x0B => Self::Switch {
+        switch: raw.kind.into(),
+        state: SwitchState::from_u8(raw.state),
+      },
+      0x0C => Self::ElapsedTime {
+        comparison,
+        time: raw.count,
+      },
+      0x0D => Self::MissionBriefing,
+      0x0E => Self::Opponents {
+        group,
+        comparison,
+        count: raw.count,
+      },
+      0x0F => Self::Deaths {
+        group,
+        comparison,
+        count: raw.count,
+        unit: raw.unit_id,
+      },
+      0x10 => Self::CommandTheLeast { unit: raw.unit_id },
+      0x11 => Self::CommandTheLeastAt {
+        unit: raw.unit_id,
+        location: raw.location,
+      },
+      0x12 => Self::LeastKills { unit: raw.unit_id },
+      0x13 => Self::LowestScore {
+        score: Score::from_u16(raw.kind.into()),
+      },
+      0x14 => Self::LeastResources {
+        resource: Resource::from_u16(raw.kind.into()),
+      },
+      0x15 => Self::Score {
+        group,
+        comparison,
+        score: Score::from_u16(raw.kind.into()),
+        amount: raw.count,
+      },
+      0x16 => Self::Always,
+      0x17 => Self::Never,
+      _ => Self::Unknown(*raw),
+    }
+  }
+
+  /// Encode the condition as raw data.
+  ///
+  /// Unused fields are zero. Conditions using a unit type are flagged with
+  /// [`TrigConditionFlags::UNIT_TYPE`], like StarEdit does.
+  pub fn encode(&self) -> TrigCondition {
+    let mut raw: TrigCondition = TrigCondition {
+      condition: self.opcode(),
+      ..TrigCondition::default()
+    };
+
+    match *self {
+      Self::None | Self::MissionBriefing | Self::Always | Self::Never => {}
+      Self::CountdownTimer { comparison, time } | Self::ElapsedTime { comparison, time } => {
+        raw.state = comparison.as_u8();
+        raw.count = time;
+      }
+      Self::Command {
+        group,
+        comparison,
+        count,
+        unit,
+      }
+      | Self::Kill {
+        group,
+        comparison,
+        count,
+        unit,
+      }
+      | Self::Deaths {
+        group,
+        comparison,
+        count,
+        unit,
+      } => {
+        raw.group = group.as_u32();
+        raw.state = comparison.as_u8();
+        raw.count = count;
+        raw.unit_id = unit;
+      }
+      Self::Bring {
+        group,
+        comparison,
+        count,
+        unit,
+        location,
+      } => {
+        raw.group = group.as_u32();
+        raw.state = comparison.as_u8();
+        raw.count = count;
+        raw.unit_id = unit;
+        raw.location = location;
+      }
+      Self::Accumulate {
+        group,
+        comparison,
+        amount,
+        resource,
+      } => {
+        raw.group = group.as_u32();
+        raw.state = comparison.as_u8();
+        raw.count = amount;
+        raw.kind = resource.as_u16() as u8;
+      }
+      Self::CommandTheMost { unit }
+      | Self::MostKills { unit }
+      | Self::CommandTheLeast { unit }
+      | Self::LeastKills { unit } => {
+        raw.unit_id = unit;
+      }
+      Self::CommandTheMostAt { unit, location } | Self::CommandTheLeastAt { unit, location } => {
+        raw.unit_id = unit;
+        raw.location = location;
+      }
+      Self::HighestScore { score } | Self::LowestScore { score } => {
+        raw.kind = score.as_u16() as u8;
+      }
+      Self::MostResources { resource } | Self::LeastResources { resource } => {
+        raw.kind = resource.as_u16() as u8;
+      }
+      Self::Switch { switch, state } => {
+        raw.kind = switch as u8;
+        raw.state = state.as_u8();
+      }
+      Self::Opponents {
+        group,
+        comparison,
+        count,
+      } => {
+        raw.group = group.as_u32();
+        raw.state = comparison.as_u8();
+        raw.count = count;
+      }
+      Self::Score {
+        group,
+        comparison,
+        score,
+        amount,
+      } => {
+        raw.group = group.as_u32();
+        raw.state = comparison.as_u8();
+        raw.kind = score.as_u16() as u8;
+        raw.count = amount;
+      }
+      Self::Unknown(raw) => return raw,
+    }
+
+    if self.unit().is_some() {
+      raw.flags |= TrigConditionFlags::UNIT_TYPE;
+    }
+
+    raw
+  }
+
+  /// Returns the condition type.
+  pub const fn opcode(&self) -> u8 {
+    match self {
+      Self::None => 0x00,
+      Self::CountdownTimer { .. } => 0x01,
+      Self::Command { .. } => 0x02,
+      Self::Bring { .. } => 0x03,
+      Self::Accumulate { .. } => 0x04,
+      Self::Kill { .. } => 0x05,
+      Self::CommandTheMost { .. } => 0x06,
+      Self::CommandTheMostAt { .. } => 0x07,
+      Self::MostKills { .. } => 0x08,
+      Self::HighestScore { .. } => 0x09,
+      Self::MostResources { .. } => 0x0A,
+      Self::Switch { .. } => 0x0B,
+      Self::ElapsedTime { .. } => 0x0C,
+      Self::MissionBriefing => 0x0D,
+      Self::Opponents { .. } => 0x0E,
+      Self::Deaths { .. } => 0x0F,
+      Self::CommandTheLeast { .. } => 0x10,
+      Self::CommandTheLeastAt { .. } => 0x11,
+      Self::LeastKills { .. } => 0x12,
+      Self::LowestScore { .. } => 0x13,
+      Self::LeastResources { .. } => 0x14,
+      Self::Score { .. } => 0x15,
+      Self::Always => 0x16,
+      Self::Never => 0x17,
+      Self::Unknown(raw) => raw.condition,
+    }
+  }
+
+  /// Returns the name of the condition type.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match Self::name_of(self.opcode()) {
+      Some(name) => name,
+      None => "Unknown",
+    }
+  }
+
+  /// Returns the name of the condition type `opcode`, if known.
+  pub const fn name_of(opcode: u8) -> Option<&'static str> {
+    match opcode {
+      0x00 => Some("No Condition"),
+      0x01 => Some("Countdown Timer"),
+      0x02 => Some("Command"),
+      0x03 => Some("Bring"),
+      0x04 => Some("Accumulate"),
+      0x05 => Some("Kill"),
+      0x06 => Some("Command the Most"),
+      0x07 => Some("Commands the Most At"),
+      0x08 => Some("Most Kills"),
+      0x09 => Some("Highest Score"),
+      0x0A => Some("Most Resources"),
+      0x0B => Some("Switch"),
+      0x0C => Some("Elapsed Time"),
+      0x0D => Some("Mission Briefing"),
+      0x0E => Some("Opponents"),
+      0x0F => Some("Deaths"),
+      0x10 => Some("Command the Least"),
+      0x11 => Some("Command the Least At"),
+      0x12 => Some("Least Kills"),
+      0x13 => Some("Lowest Score"),
+      0x14 => Some("Least Resources"),
+      0x15 => Some("Score"),
+      0x16 => Some("Always"),
+      0x17 => Some("Never"),
+      _ => None,
+    }
+  }
+
+  /// Returns the unit type the condition applies to, if any.
+  pub const fn unit(&self) -> Option<u16> {
+    match *self {
+      Self::Command { unit, .. }
+      | Self::Bring { unit, .. }
+      | Self::Kill { unit, .. }
+      | Self::CommandTheMost { unit }
+      | Self::CommandTheMostAt { unit, .. }
+      | Self::MostKills { unit }
+      | Self::Deaths { unit, .. }
+      | Self::CommandTheLeast { unit }
+      | Self::CommandTheLeastAt { unit, .. }
+      | Self::LeastKills { unit } => Some(unit),
+      _ => None,
+    }
+  }
+
+  /// Returns the (1-based) location the condition applies to, if any.
+  pub const fn location(&self) -> Option<u32> {
+    match *self {
+      Self::Bring { location, .. }
+      | Self::CommandTheMostAt { location, .. }
+      | Self::CommandTheLeastAt { location, .. } => Some(location),
+      _ => None,
+    }
+  }
+}
+
+impl TrigCondition {
+  /// Returns the condition with typed arguments.
+  #[inline]
+  pub fn decode(&self) -> Condition {
+    Condition::decode(self)
+  }
+
+  /// Returns `true` if the condition is disabled.
+  #[inline]
+  pub const fn is_disabled(&self) -> bool {
+    self.flags.contains(TrigConditionFlags::DISABLED)
+  }
+}
@@ -0,0 +1,38 @@
+//! Typed trigger conditions and actions.
+//!
+//! Triggers are stored as raw [`TrigCondition`] and [`TrigAction`] entries,
+//! where the meaning of each field depends on the condition or action type.
+//! [`Condition`] and [`Action`] decode those fields into typed arguments.
+//! Mission briefings share the layout but not the action types, see
+//! [`BriefingAction`].
+//!
+//! Triggers and briefings can also be printed and compiled as text, see
+//! [`print_triggers`] and [`compile_triggers`].
+//!
+//! [`TrigCondition`]: crate::item::TrigCondition
+//! [`TrigAction`]: crate::item::TrigAction
+
+mod action;
+mod args;
+mod briefing;
+mod condition;
+mod text;
+
+pub use self::action::Action;
+pub use self::args::Alliance;
+pub use self::args::Comparison;
+pub use self::args::Group;
+pub use self::args::Modifier;
+pub use self::args::Order;
+pub use self::args::Resource;
+pub use self::args::Score;
+pub use self::args::StateAction;
+pub use self::args::SwitchAction;
+pub use self::args::SwitchState;
+pub use self::briefing::BriefingAction;
+pub use self::condition::Condition;
+pub use self::text::compile_briefing;
+pub use self::text::compile_triggers;
+pub use self::text::print_briefing;
+pub use self::text::print_triggers;
+pub use self::text::TriggerNames;
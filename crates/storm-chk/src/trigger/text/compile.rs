@@ -0,0 +1,452 @@
+use core::fmt::Display;
+use storm_core::error::Error;
+use storm_core::error::Result;
+
+use crate::item::TrigData;
+use crate::item::TrigFlags;
+use crate::trigger::text::print::find_unit;
+use crate::trigger::text::print::is_escape;
+use crate::trigger::text::syntax::Arg;
+use crate::trigger::text::syntax::Field;
+use crate::trigger::text::syntax::Record;
+use crate::trigger::text::syntax::Signature;
+use crate::trigger::text::syntax::Syntax;
+use crate::trigger::text::syntax::FLAG_ALWAYS_DISPLAY;
+use crate::trigger::text::TriggerNames;
+
+// =============================================================================
+// Compiler
+// =============================================================================
+
+pub(crate) fn compile(
+  syntax: &Syntax,
+  text: &str,
+  names: &mut TriggerNames,
+) -> Result<Vec<TrigData>> {
+  let mut parser: Parser<'_> = Parser::new(text);
+  let mut output: Vec<TrigData> = Vec::new();
+
+  while !parser.is_end() {
+    output.push(compile_trigger(syntax, &mut parser, names)?);
+  }
+
+  Ok(output)
+}
+
+fn compile_trigger(
+  syntax: &Syntax,
+  parser: &mut Parser<'_>,
+  names: &mut TriggerNames,
+) -> Result<TrigData> {
+  let mut trigger: TrigData = TrigData {
+    conditions: Default::default(),
+    actions: [Default::default(); 0x40],
+    execution: TrigFlags::empty(),
+    players: [0; 0x1B],
+    current: 0,
+  };
+
+  parser.expect(syntax.block)?;
+
+  for value in parser.args()? {
+    let index: usize = parser.player(&value)?;
+    trigger.players[index] = 1;
+  }
+
+  parser.expect("{")?;
+  parser.expect("Conditions:")?;
+  compile_records(syntax.conditions, parser, names, &mut trigger.conditions)?;
+
+  parser.expect("Actions:")?;
+  compile_records(syntax.actions, parser, names, &mut trigger.actions)?;
+
+  if parser.eat("Flags:") {
+    while !parser.at("}") {
+      let name: &str = parser.name()?;
+      let args: Vec<Value> = parser.args()?;
+
+      match (name, args.as_slice()) {
+        ("Execution", [value]) => {
+          trigger.execution = TrigFlags::from_bits_retain(parser.number(value)?);
+        }
+        ("Current", [value]) => {
+          trigger.current = parser.number(value)?;
+        }
+        ("Player", [player, value]) => {
+          let index: usize = parser.player(player)?;
+          trigger.players[index] = parser.number(value)?;
+        }
+        _ => return Err(parser.error(format_args!("invalid trigger flag: {name:?}"))),
+      }
+
+      parser.expect(";")?;
+    }
+  }
+
+  parser.expect("}")?;
+
+  Ok(trigger)
+}
+
+fn compile_records<T>(
+  table: &'static [Signature],
+  parser: &mut Parser<'_>,
+  names: &mut TriggerNames,
+  output: &mut [T],
+) -> Result<()>
+where
+  T: Record,
+{
+  let mut count: usize = 0;
+
+  while !parser.at("Actions:") && !parser.at("Flags:") && !parser.at("}") {
+    let record: T = compile_record(table, parser, names)?;
+
+    let Some(slot) = output.get_mut(count) else {
+      return Err(parser.error(format_args!("too many entries, at most {}", output.len())));
+    };
+
+    *slot = record;
+    count += 1;
+  }
+
+  Ok(())
+}
+
+fn compile_record<T>(
+  table: &'static [Signature],
+  parser: &mut Parser<'_>,
+  names: &mut TriggerNames,
+) -> Result<T>
+where
+  T: Record,
+{
+  let name: &str = parser.name()?;
+  let args: Vec<Value> = parser.args()?;
+
+  let flags: Option<u32> = if parser.eat("Flags") {
+    match parser.args()?.as_slice() {
+      [value] => Some(parser.number::<u8>(value)?.into()),
+      _ => return Err(parser.error("expected a single flags value")),
+    }
+  } else {
+    None
+  };
+
+  parser.expect(";")?;
+
+  let mut record: T = if name.eq_ignore_ascii_case("Custom") {
+    let raw: Vec<u32> = args
+      .iter()
+      .map(|value| parser.number(value))
+      .collect::<Result<_>>()?;
+
+    if raw.len() != T::FIELDS {
+      return Err(parser.error(format_args!("expected {} values", T::FIELDS)));
+    }
+
+    T::from_raw(&raw).ok_or_else(|| parser.error("value out of range"))?
+  } else {
+    let Some(signature) = Signature::find_name(table, name) else {
+      return Err(parser.error(format_args!("unknown statement: {name:?}")));
+    };
+
+    if args.len() != signature.args.len() {
+      return Err(parser.error(format_args!(
+        "{name:?} expects {} arguments",
+        signature.args.len()
+      )));
+    }
+
+    let mut record: T = T::default();
+    let mut flags: u8 = signature.flags();
+
+    record.set_opcode(signature.opcode);
+
+    for ((arg, field), value) in signature.args.iter().zip(args.iter()) {
+      if *arg == Arg::Display {
+        flags |= parser.display(value)?;
+        continue;
+      }
+
+      let data: u32 = compile_arg(parser, *arg, value, names)?;
+
+      if !record.set(*field, data) {
+        return Err(parser.error(format_args!("value out of range: {data}")));
+      }
+    }
+
+    record.set(Field::Flags, flags.into());
+    record
+  };
+
+  if let Some(flags) = flags {
+    record.set(Field::Flags, flags);
+  }
+
+  Ok(record)
+}
+
+fn compile_arg(
+  parser: &Parser<'_>,
+  arg: Arg,
+  value: &Value,
+  names: &mut TriggerNames,
+) -> Result<u32> {
+  if let Value::Word(word) = value {
+    if let Some(number) = parse_number(word) {
+      return Ok(number);
+    }
+  }
+
+  let text: &str = value.as_str();
+
+  let data: Option<u32> = match (arg, value) {
+    (Arg::Group, _) => arg.find_keyword(text),
+    (Arg::Unit, _) => find_unit(text),
+    (Arg::Location, _) => names.find_location(text),
+    (Arg::String, Value::Text(text)) => Some(names.find_string(text)?),
+    (Arg::String, Value::Word(_)) => None,
+    (Arg::Switch, _) => names.find_switch(text),
+    (Arg::Count, _) => text.eq_ignore_ascii_case("All").then_some(0),
+    (Arg::Script, _) => <[u8; 4]>::try_from(text.as_bytes())
+      .ok()
+      .map(u32::from_le_bytes),
+    (Arg::Number | Arg::Properties | Arg::Display, _) => None,
+    _ => arg.find_keyword(text),
+  };
+
+  data.ok_or_else(|| parser.error(format_args!("invalid {} argument: {text:?}", arg_name(arg))))
+}
+
+fn arg_name(arg: Arg) -> &'static str {
+  match arg {
+    Arg::Group => "player",
+    Arg::Unit => "unit",
+    Arg::Location => "location",
+    Arg::String => "string",
+    Arg::Switch => "switch",
+    Arg::Number | Arg::Properties => "number",
+    Arg::Count => "unit count",
+    Arg::Script => "AI script",
+    Arg::Display => "display",
+    Arg::Slot => "portrait slot",
+    Arg::Comparison => "comparison",
+    Arg::Modifier => "modifier",
+    Arg::SwitchState => "switch state",
+    Arg::SwitchAction | Arg::StateAction => "state",
+    Arg::Resource => "resource",
+    Arg::Score => "score",
+    Arg::Alliance => "alliance",
+    Arg::Order => "order",
+  }
+}
+
+fn parse_number(text: &str) -> Option<u32> {
+  match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+    None => text.parse().ok(),
+  }
+}
+
+// =============================================================================
+// Parser
+// =============================================================================
+
+/// A quoted string or a bare word (numbers, keywords).
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Value<'a> {
+  Text(String),
+  Word(&'a str),
+}
+
+impl Value<'_> {
+  fn as_str(&self) -> &str {
+    match self {
+      Self::Text(text) => text,
+      Self::Word(word) => word,
+    }
+  }
+}
+
+struct Parser<'a> {
+  text: &'a str,
+  offset: usize,
+}
+
+impl<'a> Parser<'a> {
+  const fn new(text: &'a str) -> Self {
+    Self { text, offset: 0 }
+  }
+
+  fn error(&self, message: impl Display) -> Error {
+    let line: usize = self.text[..self.offset].matches('\n').count() + 1;
+    Error::message(format_args!("line {line}: {message}"))
+  }
+
+  fn rest(&self) -> &'a str {
+    &self.text[self.offset..]
+  }
+
+  // Skip whitespace and comments
+  fn skip(&mut self) {
+    loop {
+      let rest: &str = self.rest();
+      let trimmed: &str = rest.trim_start();
+
+      self.offset += rest.len() - trimmed.len();
+
+      if !trimmed.starts_with("//") {
+        break;
+      }
+
+      self.offset += trimmed.find('\n').unwrap_or(trimmed.len());
+    }
+  }
+
+  fn is_end(&mut self) -> bool {
+    self.skip();
+    self.rest().is_empty()
+  }
+
+  fn at(&mut self, token: &str) -> bool {
+    self.skip();
+    self.rest().starts_with(token)
+  }
+
+  fn eat(&mut self, token: &str) -> bool {
+    let found: bool = self.at(token);
+
+    if found {
+      self.offset += token.len();
+    }
+
+    found
+  }
+
+  fn expect(&mut self, token: &str) -> Result<()> {
+    if self.eat(token) {
+      Ok(())
+    } else {
+      Err(self.error(format_args!("expected {token:?}")))
+    }
+  }
+
+  // A statement name, up to the argument list
+  fn name(&mut self) -> Result<&'a str> {
+    self.skip();
+
+    let rest: &'a str = self.rest();
+    let end: usize = rest.find(['(', ';', '{', '}', '\n']).unwrap_or(rest.len());
+    let name: &'a str = rest[..end].trim();
+
+    if name.is_empty() || !rest[end..].starts_with('(') {
+      return Err(self.error("expected a statement"));
+    }
+
+    self.offset += end;
+
+    Ok(name)
+  }
+
+  fn args(&mut self) -> Result<Vec<Value<'a>>> {
+    let mut output: Vec<Value<'a>> = Vec::new();
+
+    self.expect("(")?;
+
+    if self.eat(")") {
+      return Ok(output);
+    }
+
+    loop {
+      output.push(self.value()?);
+
+      if self.eat(")") {
+        return Ok(output);
+      }
+
+      self.expect(",")?;
+    }
+  }
+
+  fn value(&mut self) -> Result<Value<'a>> {
+    if self.eat("\"") {
+      return self.quoted().map(Value::Text);
+    }
+
+    let rest: &'a str = self.rest();
+    let end: usize = rest.find([',', ')', ';', '\n']).unwrap_or(rest.len());
+    let word: &'a str = rest[..end].trim();
+
+    if word.is_empty() {
+      return Err(self.error("expected a value"));
+    }
+
+    self.offset += end;
+
+    Ok(Value::Word(word))
+  }
+
+  // The rest of a quoted string, see `print::quote`
+  fn quoted(&mut self) -> Result<String> {
+    let mut output: String = String::new();
+    let mut chars = self.rest().char_indices();
+
+    while let Some((index, char)) = chars.next() {
+      match char {
+        '"' => {
+          self.offset += index + 1;
+          return Ok(output);
+        }
+        '\\' => match chars.next().map(|(_, char)| char) {
+          Some('n') => output.push('\n'),
+          Some('r') => output.push('\r'),
+          Some('t') => output.push('\t'),
+          Some(char) => output.push(char),
+          None => break,
+        },
+        '<' if is_escape(chars.clone().map(|(_, char)| char)) => {
+          let code: String = chars.by_ref().take(3).map(|(_, char)| char).collect();
+
+          if let Ok(code) = u8::from_str_radix(&code[..2], 16) {
+            output.push(char::from(code));
+          }
+        }
+        char => output.push(char),
+      }
+    }
+
+    Err(self.error("unterminated string"))
+  }
+
+  fn number<T: TryFrom<u32>>(&self, value: &Value<'_>) -> Result<T> {
+    let number: Option<u32> = match value {
+      Value::Word(word) => parse_number(word),
+      Value::Text(_) => None,
+    };
+
+    number
+      .and_then(|number| T::try_from(number).ok())
+      .ok_or_else(|| self.error(format_args!("invalid number: {:?}", value.as_str())))
+  }
+
+  fn player(&self, value: &Value<'_>) -> Result<usize> {
+    let index: Option<u32> = match value {
+      Value::Word(word) => parse_number(word),
+      Value::Text(text) => Arg::Group.find_keyword(text),
+    };
+
+    index
+      .map(|index| index as usize)
+      .filter(|index| *index < 0x1B)
+      .ok_or_else(|| self.error(format_args!("invalid player: {:?}", value.as_str())))
+  }
+
+  fn display(&self, value: &Value<'_>) -> Result<u8> {
+    match value.as_str() {
+      "Always Display" => Ok(FLAG_ALWAYS_DISPLAY),
+      "Don't Always Display" => Ok(0),
+      text => Err(self.error(format_args!("invalid display argument: {text:?}"))),
+    }
+  }
+}
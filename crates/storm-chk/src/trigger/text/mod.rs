@@ -0,0 +1,134 @@
+//! Triggers as text, in the TrigEdit style.
+//!
+//! ```text
+//! Trigger("Player 1"){
+//! Conditions:
+//!     Bring("Player 1", "Terran Marine", "Beacon", At least, 1);
+//!
+//! Actions:
+//!     Display Text Message(Always Display, "Welcome!");
+//!     Preserve Trigger();
+//! }
+//! ```
+//!
+//! Strings, locations and switches are written by name when the name maps
+//! back to the same index, and by number otherwise. Data that can not be
+//! written with the usual arguments is kept:
+//!
+//! - unusual condition or action flags are written as `Flags(0x12)` after the
+//!   arguments
+//! - unknown types and unused fields with data are written as `Custom(...)`,
+//!   with every raw field in storage order
+//! - execution flags, the current action and player values other than `1` are
+//!   written in a `Flags:` block
+//!
+//! Mission briefings use the same format, with `Briefing` blocks and the
+//! briefing actions:
+//!
+//! ```text
+//! Briefing("Player 1"){
+//! Conditions:
+//!     Mission Briefing();
+//!
+//! Actions:
+//!     Show Portrait("Terran Marine", Slot 1);
+//!     Transmission("Hello!", Slot 1, Add, 0, "", 0);
+//! }
+//! ```
+
+mod compile;
+mod names;
+mod print;
+mod syntax;
+
+use storm_core::error::Result;
+
+use crate::consts;
+use crate::item::Mbrf;
+use crate::item::Trig;
+use crate::item::TrigData;
+use crate::types::Scenario;
+use crate::types::StringTable;
+
+pub use self::names::TriggerNames;
+
+/// Print `triggers` as text.
+#[inline]
+pub fn print_triggers(triggers: &[TrigData], names: &TriggerNames) -> String {
+  print::print(&syntax::TRIGGERS, triggers, names)
+}
+
+/// Compile triggers from `text`.
+///
+/// Strings not found in `names` are added to its string table.
+#[inline]
+pub fn compile_triggers(text: &str, names: &mut TriggerNames) -> Result<Vec<TrigData>> {
+  compile::compile(&syntax::TRIGGERS, text, names)
+}
+
+/// Print the mission briefing `triggers` as text.
+#[inline]
+pub fn print_briefing(triggers: &[TrigData], names: &TriggerNames) -> String {
+  print::print(&syntax::BRIEFING, triggers, names)
+}
+
+/// Compile mission briefing triggers from `text`.
+///
+/// Strings not found in `names` are added to its string table.
+#[inline]
+pub fn compile_briefing(text: &str, names: &mut TriggerNames) -> Result<Vec<TrigData>> {
+  compile::compile(&syntax::BRIEFING, text, names)
+}
+
+impl Scenario {
+  /// Returns the triggers (`TRIG`) as text.
+  pub fn triggers_to_text(&self) -> String {
+    print_triggers(self.triggers(), &TriggerNames::new(self))
+  }
+
+  /// Replace the triggers (`TRIG`) with triggers compiled from `text`.
+  ///
+  /// New strings are added to the string table.
+  pub fn triggers_from_text(&mut self, text: &str) -> Result<()> {
+    let mut names: TriggerNames = TriggerNames::new(self);
+    let triggers: Vec<TrigData> = compile_triggers(text, &mut names)?;
+
+    self.write_names(names)?;
+
+    let item: Trig = Trig {
+      triggers: triggers.into_boxed_slice(),
+    };
+
+    self.insert(consts::MAGIC_TRIG, item.into())
+  }
+
+  /// Returns the mission briefing (`MBRF`) as text.
+  pub fn briefing_to_text(&self) -> String {
+    print_briefing(self.briefing(), &TriggerNames::new(self))
+  }
+
+  /// Replace the mission briefing (`MBRF`) with triggers compiled from `text`.
+  ///
+  /// New strings are added to the string table.
+  pub fn briefing_from_text(&mut self, text: &str) -> Result<()> {
+    let mut names: TriggerNames = TriggerNames::new(self);
+    let triggers: Vec<TrigData> = compile_briefing(text, &mut names)?;
+
+    self.write_names(names)?;
+
+    let item: Mbrf = Mbrf {
+      triggers: triggers.into_boxed_slice(),
+    };
+
+    self.insert(consts::MAGIC_MBRF, item.into())
+  }
+
+  // Write the string table, if strings were added while compiling
+  fn write_names(&mut self, names: TriggerNames) -> Result<()> {
+    if *names.strings() != StringTable::from_scenario(self) {
+      names.into_strings().write(self)?;
+    }
+
+    Ok(())
+  }
+}
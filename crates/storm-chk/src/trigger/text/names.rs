@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use storm_core::error::Result;
+
+use crate::consts;
+use crate::types::Item;
+use crate::types::Scenario;
+use crate::types::StringTable;
+
+// =============================================================================
+// Trigger Names
+// =============================================================================
+
+/// Resolves strings, locations and switches of a [`Scenario`] by name, for
+/// printing and compiling triggers as text.
+///
+/// Strings not found while compiling are added to the string table, see
+/// [`TriggerNames::into_strings`].
+#[derive(Clone, Debug)]
+pub struct TriggerNames {
+  strings: StringTable,
+  texts: HashMap<String, u32>,
+  locations: Vec<String>,
+  switches: Vec<String>,
+}
+
+impl TriggerNames {
+  /// Create a new `TriggerNames` from the strings, locations (`MRGN`) and
+  /// switch names (`SWNM`) of a [`Scenario`].
+  pub fn new(scenario: &Scenario) -> Self {
+    let strings: StringTable = StringTable::from_scenario(scenario);
+    let mut texts: HashMap<String, u32> = HashMap::new();
+
+    for index in 1..=strings.len() {
+      if let Some(text) = strings.get(index) {
+        texts.entry(text.into_string()).or_insert(index as u32);
+      }
+    }
+
+    let text = |index: u32| -> String {
+      strings
+        .get(index as usize)
+        .map(|text| text.into_string())
+        .unwrap_or_default()
+    };
+
+    let locations: Vec<String> = scenario
+      .locations()
+      .iter()
+      .map(|location| text(location.index.into()))
+      .collect();
+
+    let switches: Vec<String> = match scenario.get(consts::MAGIC_SWNM) {
+      Some(Item::Swnm(item)) => item.switches.iter().map(|index| text(*index)).collect(),
+      _ => Vec::new(),
+    };
+
+    Self {
+      strings,
+      texts,
+      locations,
+      switches,
+    }
+  }
+
+  /// Returns the string table, including the strings added while compiling.
+  #[inline]
+  pub const fn strings(&self) -> &StringTable {
+    &self.strings
+  }
+
+  /// Returns the string table, including the strings added while compiling.
+  #[inline]
+  pub fn into_strings(self) -> StringTable {
+    self.strings
+  }
+
+  // ===========================================================================
+  // Printing
+  // ===========================================================================
+
+  pub(crate) fn string_name(&self, index: u32) -> Option<String> {
+    if index == 0 {
+      return Some(String::new());
+    }
+
+    let text: String = self.strings.get(index as usize)?.into_string();
+
+    (!text.is_empty() && self.texts.get(&text) == Some(&index)).then_some(text)
+  }
+
+  pub(crate) fn location_name(&self, index: u32) -> Option<&str> {
+    let name: &str = self.locations.get((index as usize).checked_sub(1)?)?;
+
+    (self.find_location(name) == Some(index)).then_some(name)
+  }
+
+  pub(crate) fn switch_name(&self, index: u32) -> Option<String> {
+    let name: String = match self.switches.get(index as usize) {
+      Some(name) if !name.is_empty() => name.clone(),
+      _ => format!("Switch{}", index + 1),
+    };
+
+    (self.find_switch(&name) == Some(index)).then_some(name)
+  }
+
+  // ===========================================================================
+  // Compiling
+  // ===========================================================================
+
+  pub(crate) fn find_string(&mut self, text: &str) -> Result<u32> {
+    if text.is_empty() {
+      return Ok(0);
+    }
+
+    if let Some(index) = self.texts.get(text) {
+      return Ok(*index);
+    }
+
+    let index: u32 = self.strings.add(text)? as u32;
+
+    self.texts.insert(text.to_owned(), index);
+
+    Ok(index)
+  }
+
+  pub(crate) fn find_location(&self, name: &str) -> Option<u32> {
+    if name.is_empty() {
+      return None;
+    }
+
+    self
+      .locations
+      .iter()
+      .position(|location| location == name)
+      .map(|index| index as u32 + 1)
+  }
+
+  pub(crate) fn find_switch(&self, name: &str) -> Option<u32> {
+    if name.is_empty() {
+      return None;
+    }
+
+    if let Some(index) = self.switches.iter().position(|switch| switch == name) {
+      return Some(index as u32);
+    }
+
+    name
+      .strip_prefix("Switch")?
+      .parse::<u32>()
+      .ok()
+      .filter(|index| (1..=0x100).contains(index))
+      .map(|index| index - 1)
+  }
+}
+
+// =============================================================================
+// Unit Groups
+// =============================================================================
+
+/// Unit groups only used by triggers, following the unit types.
+pub(crate) const UNIT_GROUPS: [&str; 5] = ["None", "Any unit", "Men", "Buildings", "Factories"];
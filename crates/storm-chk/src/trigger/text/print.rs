@@ -0,0 +1,241 @@
+use core::fmt::Write;
+
+use crate::id::UnitId;
+use crate::item::TrigData;
+use crate::trigger::text::names::UNIT_GROUPS;
+use crate::trigger::text::syntax::Arg;
+use crate::trigger::text::syntax::Field;
+use crate::trigger::text::syntax::Record;
+use crate::trigger::text::syntax::Signature;
+use crate::trigger::text::syntax::Syntax;
+use crate::trigger::text::syntax::FLAG_ALWAYS_DISPLAY;
+use crate::trigger::text::TriggerNames;
+use crate::trigger::Group;
+
+pub(crate) const SEPARATOR: &str =
+  "//-----------------------------------------------------------------//";
+
+// =============================================================================
+// Printer
+// =============================================================================
+
+pub(crate) fn print(syntax: &Syntax, triggers: &[TrigData], names: &TriggerNames) -> String {
+  let mut output: String = String::new();
+
+  for trigger in triggers {
+    print_trigger(syntax, trigger, names, &mut output);
+  }
+
+  output
+}
+
+fn print_trigger(syntax: &Syntax, trigger: &TrigData, names: &TriggerNames, output: &mut String) {
+  let players: Vec<String> = (0..trigger.players.len() as u32)
+    .filter(|index| trigger.players[*index as usize] != 0)
+    .map(|index| quote(Group::from_u32(index).as_str()))
+    .collect();
+
+  output.push_str(syntax.block);
+  output.push('(');
+  output.push_str(&players.join(", "));
+  output.push_str("){\n");
+
+  output.push_str("Conditions:\n");
+  print_records(syntax.conditions, &trigger.conditions, names, output);
+
+  output.push_str("\nActions:\n");
+  print_records(syntax.actions, &trigger.actions, names, output);
+
+  let mut flags: Vec<String> = Vec::new();
+
+  if !trigger.execution.is_empty() {
+    flags.push(format!("Execution(0x{:08X})", trigger.execution.bits()));
+  }
+
+  if trigger.current != 0 {
+    flags.push(format!("Current({})", trigger.current));
+  }
+
+  for (index, value) in trigger.players.iter().enumerate() {
+    if *value > 1 {
+      let group: &str = Group::from_u32(index as u32).as_str();
+      flags.push(format!("Player({}, {value})", quote(group)));
+    }
+  }
+
+  if !flags.is_empty() {
+    output.push_str("\nFlags:\n");
+
+    for flag in flags {
+      let _ = writeln!(output, "\t{flag};");
+    }
+  }
+
+  output.push_str("}\n\n");
+  output.push_str(SEPARATOR);
+  output.push_str("\n\n");
+}
+
+// Print every record up to the last non-empty one
+fn print_records<T>(
+  table: &'static [Signature],
+  records: &[T],
+  names: &TriggerNames,
+  output: &mut String,
+) where
+  T: Record,
+{
+  let count: usize = records
+    .iter()
+    .rposition(|record| *record != T::default())
+    .map_or(0, |index| index + 1);
+
+  for record in &records[..count] {
+    output.push('\t');
+    output.push_str(&print_record(table, record, names));
+    output.push_str(";\n");
+  }
+}
+
+pub(crate) fn print_record<T>(
+  table: &'static [Signature],
+  record: &T,
+  names: &TriggerNames,
+) -> String
+where
+  T: Record,
+{
+  let Some(signature) = Signature::find(table, record.opcode()) else {
+    return print_custom(record);
+  };
+
+  // Rebuild the record from the arguments, anything else must be unused
+  let mut expected: T = T::default();
+  let mut flags: u8 = signature.flags();
+  let mut args: Vec<String> = Vec::with_capacity(signature.args.len());
+
+  expected.set_opcode(signature.opcode);
+
+  for (arg, field) in signature.args {
+    let value: u32 = record.get(*field);
+
+    if *arg == Arg::Display {
+      flags |= value as u8 & FLAG_ALWAYS_DISPLAY;
+    } else {
+      expected.set(*field, value);
+    }
+
+    args.push(print_arg(*arg, value, names));
+  }
+
+  expected.set(Field::Flags, record.get(Field::Flags));
+
+  if expected != *record {
+    return print_custom(record);
+  }
+
+  let mut output: String = format!("{}({})", signature.name, args.join(", "));
+
+  if record.get(Field::Flags) != u32::from(flags) {
+    let _ = write!(output, " Flags(0x{:02X})", record.get(Field::Flags));
+  }
+
+  output
+}
+
+fn print_custom<T: Record>(record: &T) -> String {
+  let fields: Vec<String> = record.to_raw().iter().map(ToString::to_string).collect();
+
+  format!("Custom({})", fields.join(", "))
+}
+
+fn print_arg(arg: Arg, value: u32, names: &TriggerNames) -> String {
+  let name: Option<String> = match arg {
+    Arg::Group => arg.keyword(value).map(quote),
+    Arg::Unit => unit_name(value).map(quote),
+    Arg::Location => names.location_name(value).map(quote),
+    Arg::String => names.string_name(value).map(|text| quote(&text)),
+    Arg::Switch => names.switch_name(value).map(|name| quote(&name)),
+    Arg::Number | Arg::Properties => None,
+    Arg::Count => (value == 0).then(|| "All".to_owned()),
+    Arg::Script => script_name(value).map(|name| quote(&name)),
+    Arg::Display if value as u8 & FLAG_ALWAYS_DISPLAY != 0 => Some("Always Display".to_owned()),
+    Arg::Display => Some("Don't Always Display".to_owned()),
+    _ => arg.keyword(value).map(ToOwned::to_owned),
+  };
+
+  name.unwrap_or_else(|| value.to_string())
+}
+
+fn unit_name(value: u32) -> Option<&'static str> {
+  let name: &'static str = match UnitId::from_u16(u16::try_from(value).ok()?) {
+    Some(unit) => unit.as_str(),
+    None => UNIT_GROUPS.get(value as usize - UnitId::TOTAL)?,
+  };
+
+  (find_unit(name) == Some(value)).then_some(name)
+}
+
+pub(crate) fn find_unit(name: &str) -> Option<u32> {
+  UnitId::ALL
+    .iter()
+    .map(UnitId::as_str)
+    .chain(UNIT_GROUPS)
+    .position(|unit| unit.eq_ignore_ascii_case(name))
+    .map(|index| index as u32)
+}
+
+// Scripts are written as text if printable and free of escapes
+fn script_name(value: u32) -> Option<String> {
+  let bytes: [u8; 4] = value.to_le_bytes();
+
+  let printable: bool = bytes
+    .iter()
+    .all(|byte| byte.is_ascii_graphic() && !matches!(byte, b'"' | b'\\' | b'<'));
+
+  printable.then(|| bytes.iter().map(|byte| char::from(*byte)).collect())
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+/// Quote and escape `text`.
+///
+/// Control characters (text colors, ...) are written as `<XX>`, like TrigEdit
+/// does.
+pub(crate) fn quote(text: &str) -> String {
+  let mut output: String = String::with_capacity(text.len() + 2);
+  let mut chars = text.chars();
+
+  output.push('"');
+
+  while let Some(char) = chars.next() {
+    match char {
+      '"' => output.push_str("\\\""),
+      '\\' => output.push_str("\\\\"),
+      '\n' => output.push_str("\\n"),
+      '\r' => output.push_str("\\r"),
+      '\t' => output.push_str("\\t"),
+      '<' if is_escape(chars.clone()) => output.push_str("\\<"),
+      char if char.is_ascii_control() => {
+        let _ = write!(output, "<{:02X}>", char as u32);
+      }
+      char => output.push(char),
+    }
+  }
+
+  output.push('"');
+  output
+}
+
+// Returns `true` if `chars` (after a `<`) look like a `XX>` escape
+pub(crate) fn is_escape<I>(mut chars: I) -> bool
+where
+  I: Iterator<Item = char>,
+{
+  matches!(
+    (chars.next(), chars.next(), chars.next()),
+    (Some(a), Some(b), Some('>')) if a.is_ascii_hexdigit() && b.is_ascii_hexdigit()
+  )
+}
@@ -0,0 +1,560 @@
+use crate::item::TrigAction;
+use crate::item::TrigActionFlags;
+use crate::item::TrigCondition;
+use crate::item::TrigConditionFlags;
+use crate::trigger::Alliance;
+use crate::trigger::Comparison;
+use crate::trigger::Group;
+use crate::trigger::Modifier;
+use crate::trigger::Order;
+use crate::trigger::Resource;
+use crate::trigger::Score;
+use crate::trigger::StateAction;
+use crate::trigger::SwitchAction;
+use crate::trigger::SwitchState;
+
+// =============================================================================
+// Syntax
+// =============================================================================
+
+/// The statements known by a text format.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Syntax {
+  /// Name of the trigger block (`Trigger`, `Briefing`).
+  pub(crate) block: &'static str,
+  pub(crate) conditions: &'static [Signature],
+  pub(crate) actions: &'static [Signature],
+}
+
+/// The name and arguments of a condition or action type.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Signature {
+  pub(crate) opcode: u8,
+  pub(crate) name: &'static str,
+  pub(crate) args: &'static [(Arg, Field)],
+}
+
+impl Signature {
+  pub(crate) fn find(table: &'static [Self], opcode: u8) -> Option<&'static Self> {
+    table.iter().find(|signature| signature.opcode == opcode)
+  }
+
+  pub(crate) fn find_name(table: &'static [Self], name: &str) -> Option<&'static Self> {
+    table
+      .iter()
+      .find(|signature| signature.name.eq_ignore_ascii_case(name))
+  }
+
+  /// Returns the flags set by StarEdit for this signature.
+  pub(crate) fn flags(&self) -> u8 {
+    let mut flags: u8 = 0;
+
+    for (arg, _) in self.args {
+      match arg {
+        Arg::Unit => flags |= FLAG_UNIT_TYPE,
+        Arg::Properties => flags |= FLAG_UNIT_PROPERTIES,
+        _ => {}
+      }
+    }
+
+    flags
+  }
+}
+
+// Shared by conditions and actions
+const FLAG_UNIT_TYPE: u8 = TrigActionFlags::UNIT_TYPE.bits();
+const FLAG_UNIT_PROPERTIES: u8 = TrigActionFlags::UNIT_PROPERTIES.bits();
+
+pub(crate) const FLAG_ALWAYS_DISPLAY: u8 = TrigActionFlags::ALWAYS_DISPLAY.bits();
+
+/// The kind of an argument, deciding how it is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Arg {
+  Group,
+  Unit,
+  Location,
+  String,
+  Switch,
+  Number,
+  /// Unit count, `All` if zero.
+  Count,
+  /// Unit properties (`UPRP`) index.
+  Properties,
+  /// AI script identifier (4 characters).
+  Script,
+  /// Always display flag.
+  Display,
+  /// Briefing portrait slot.
+  Slot,
+  Comparison,
+  Modifier,
+  SwitchState,
+  SwitchAction,
+  StateAction,
+  Resource,
+  Score,
+  Alliance,
+  Order,
+}
+
+/// The raw field an argument is stored in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Field {
+  Location,
+  Group,
+  Count,
+  Unit,
+  State,
+  Kind,
+  Text,
+  Wav,
+  Time,
+  Units,
+  Flags,
+}
+
+// =============================================================================
+// Record
+// =============================================================================
+
+/// A raw condition or action.
+pub(crate) trait Record: Copy + Default + PartialEq {
+  /// Number of raw fields, see [`Record::to_raw`].
+  const FIELDS: usize;
+
+  fn opcode(&self) -> u8;
+
+  fn set_opcode(&mut self, opcode: u8);
+
+  fn get(&self, field: Field) -> u32;
+
+  /// Returns `false` if `value` does not fit in `field`.
+  fn set(&mut self, field: Field, value: u32) -> bool;
+
+  /// Returns every field, in storage order.
+  fn to_raw(&self) -> Vec<u32>;
+
+  /// Create a record from every field, in storage order.
+  fn from_raw(raw: &[u32]) -> Option<Self>;
+}
+
+impl Record for TrigCondition {
+  const FIELDS: usize = 9;
+
+  #[inline]
+  fn opcode(&self) -> u8 {
+    self.condition
+  }
+
+  #[inline]
+  fn set_opcode(&mut self, opcode: u8) {
+    self.condition = opcode;
+  }
+
+  fn get(&self, field: Field) -> u32 {
+    match field {
+      Field::Location => self.location,
+      Field::Group => self.group,
+      Field::Count => self.count,
+      Field::Unit => self.unit_id.into(),
+      Field::State => self.state.into(),
+      Field::Kind => self.kind.into(),
+      Field::Flags => self.flags.bits().into(),
+      Field::Text | Field::Wav | Field::Time | Field::Units => 0,
+    }
+  }
+
+  fn set(&mut self, field: Field, value: u32) -> bool {
+    match field {
+      Field::Location => self.location = value,
+      Field::Group => self.group = value,
+      Field::Count => self.count = value,
+      Field::Unit => return set_u16(&mut self.unit_id, value),
+      Field::State => return set_u8(&mut self.state, value),
+      Field::Kind => return set_u8(&mut self.kind, value),
+      Field::Flags => {
+        let Ok(value) = u8::try_from(value) else {
+          return false;
+        };
+
+        self.flags = TrigConditionFlags::from_bits_retain(value);
+      }
+      Field::Text | Field::Wav | Field::Time | Field::Units => return false,
+    }
+
+    true
+  }
+
+  fn to_raw(&self) -> Vec<u32> {
+    vec![
+      self.location,
+      self.group,
+      self.count,
+      self.unit_id.into(),
+      self.state.into(),
+      self.condition.into(),
+      self.kind.into(),
+      self.flags.bits().into(),
+      self.mask.into(),
+    ]
+  }
+
+  fn from_raw(raw: &[u32]) -> Option<Self> {
+    let [location, group, count, unit_id, state, condition, kind, flags, mask] = *raw else {
+      return None;
+    };
+
+    Some(Self {
+      location,
+      group,
+      count,
+      unit_id: unit_id.try_into().ok()?,
+      state: state.try_into().ok()?,
+      condition: condition.try_into().ok()?,
+      kind: kind.try_into().ok()?,
+      flags: TrigConditionFlags::from_bits_retain(flags.try_into().ok()?),
+      mask: mask.try_into().ok()?,
+    })
+  }
+}
+
+impl Record for TrigAction {
+  const FIELDS: usize = 12;
+
+  #[inline]
+  fn opcode(&self) -> u8 {
+    self.action
+  }
+
+  #[inline]
+  fn set_opcode(&mut self, opcode: u8) {
+    self.action = opcode;
+  }
+
+  fn get(&self, field: Field) -> u32 {
+    match field {
+      Field::Location => self.location,
+      Field::Group => self.group,
+      Field::State => self.state,
+      Field::Kind => self.kind.into(),
+      Field::Text => self.txt_index,
+      Field::Wav => self.wav_index,
+      Field::Time => self.time,
+      Field::Units => self.units.into(),
+      Field::Flags => self.flags.bits().into(),
+      Field::Count | Field::Unit => 0,
+    }
+  }
+
+  fn set(&mut self, field: Field, value: u32) -> bool {
+    match field {
+      Field::Location => self.location = value,
+      Field::Group => self.group = value,
+      Field::State => self.state = value,
+      Field::Kind => return set_u16(&mut self.kind, value),
+      Field::Text => self.txt_index = value,
+      Field::Wav => self.wav_index = value,
+      Field::Time => self.time = value,
+      Field::Units => return set_u8(&mut self.units, value),
+      Field::Flags => {
+        let Ok(value) = u8::try_from(value) else {
+          return false;
+        };
+
+        self.flags = TrigActionFlags::from_bits_retain(value);
+      }
+      Field::Count | Field::Unit => return false,
+    }
+
+    true
+  }
+
+  fn to_raw(&self) -> Vec<u32> {
+    vec![
+      self.location,
+      self.txt_index,
+      self.wav_index,
+      self.time,
+      self.group,
+      self.state,
+      self.kind.into(),
+      self.action.into(),
+      self.units.into(),
+      self.flags.bits().into(),
+      self._padding.into(),
+      self.mask.into(),
+    ]
+  }
+
+  fn from_raw(raw: &[u32]) -> Option<Self> {
+    let [location, txt_index, wav_index, time, group, state, kind, action, units, flags, padding, mask] =
+      *raw
+    else {
+      return None;
+    };
+
+    Some(Self {
+      location,
+      txt_index,
+      wav_index,
+      time,
+      group,
+      state,
+      kind: kind.try_into().ok()?,
+      action: action.try_into().ok()?,
+      units: units.try_into().ok()?,
+      flags: TrigActionFlags::from_bits_retain(flags.try_into().ok()?),
+      _padding: padding.try_into().ok()?,
+      mask: mask.try_into().ok()?,
+    })
+  }
+}
+
+fn set_u8(field: &mut u8, value: u32) -> bool {
+  u8::try_from(value).map(|value| *field = value).is_ok()
+}
+
+fn set_u16(field: &mut u16, value: u32) -> bool {
+  u16::try_from(value).map(|value| *field = value).is_ok()
+}
+
+// =============================================================================
+// Triggers
+// =============================================================================
+
+macro_rules! signatures {
+  ($($opcode:literal $name:literal ($($arg:ident: $field:ident),* $(,)?)),* $(,)?) => {
+    &[$(
+      Signature {
+        opcode: $opcode,
+        name: $name,
+        args: &[$((Arg::$arg, Field::$field)),*],
+      }
+    ),*]
+  };
+}
+
+/// Trigger statements, in the TrigEdit style.
+pub(crate) const TRIGGERS: Syntax = Syntax {
+  block: "Trigger",
+  conditions: CONDITIONS,
+  actions: ACTIONS,
+};
+
+const CONDITIONS: &[Signature] = signatures![
+  0x00 "No Condition" (),
+  0x01 "Countdown Timer" (Comparison: State, Number: Count),
+  0x02 "Command" (Group: Group, Unit: Unit, Comparison: State, Number: Count),
+  0x03 "Bring" (Group: Group, Unit: Unit, Location: Location, Comparison: State, Number: Count),
+  0x04 "Accumulate" (Group: Group, Comparison: State, Number: Count, Resource: Kind),
+  0x05 "Kill" (Group: Group, Unit: Unit, Comparison: State, Number: Count),
+  0x06 "Command the Most" (Unit: Unit),
+  0x07 "Commands the Most At" (Unit: Unit, Location: Location),
+  0x08 "Most Kills" (Unit: Unit),
+  0x09 "Highest Score" (Score: Kind),
+  0x0A "Most Resources" (Resource: Kind),
+  0x0B "Switch" (Switch: Kind, SwitchState: State),
+  0x0C "Elapsed Time" (Comparison: State, Number: Count),
+  0x0D "Mission Briefing" (),
+  0x0E "Opponents" (Group: Group, Comparison: State, Number: Count),
+  0x0F "Deaths" (Group: Group, Unit: Unit, Comparison: State, Number: Count),
+  0x10 "Command the Least" (Unit: Unit),
+  0x11 "Command the Least At" (Unit: Unit, Location: Location),
+  0x12 "Least Kills" (Unit: Unit),
+  0x13 "Lowest Score" (Score: Kind),
+  0x14 "Least Resources" (Resource: Kind),
+  0x15 "Score" (Group: Group, Score: Kind, Comparison: State, Number: Count),
+  0x16 "Always" (),
+  0x17 "Never" (),
+];
+
+const ACTIONS: &[Signature] = signatures![
+  0x00 "No Action" (),
+  0x01 "Victory" (),
+  0x02 "Defeat" (),
+  0x03 "Preserve Trigger" (),
+  0x04 "Wait" (Number: Time),
+  0x05 "Pause Game" (),
+  0x06 "Unpause Game" (),
+  0x07 "Transmission" (
+    Display: Flags,
+    String: Text,
+    Unit: Kind,
+    Location: Location,
+    Modifier: Units,
+    Number: State,
+    String: Wav,
+    Number: Time,
+  ),
+  0x08 "Play WAV" (String: Wav, Number: Time),
+  0x09 "Display Text Message" (Display: Flags, String: Text),
+  0x0A "Center View" (Location: Location),
+  0x0B "Create Unit with Properties" (
+    Group: Group,
+    Unit: Kind,
+    Count: Units,
+    Location: Location,
+    Properties: State,
+  ),
+  0x0C "Set Mission Objectives" (String: Text),
+  0x0D "Set Switch" (Switch: State, SwitchAction: Units),
+  0x0E "Set Countdown Timer" (Modifier: Units, Number: Time),
+  0x0F "Run AI Script" (Script: State),
+  0x10 "Run AI Script At Location" (Script: State, Location: Location),
+  0x11 "Leader Board Control" (String: Text, Unit: Kind),
+  0x12 "Leader Board Control At Location" (String: Text, Unit: Kind, Location: Location),
+  0x13 "Leader Board Resources" (String: Text, Resource: Kind),
+  0x14 "Leader Board Kills" (String: Text, Unit: Kind),
+  0x15 "Leader Board Points" (String: Text, Score: Kind),
+  0x16 "Kill Unit" (Group: Group, Unit: Kind),
+  0x17 "Kill Unit At Location" (Group: Group, Unit: Kind, Count: Units, Location: Location),
+  0x18 "Remove Unit" (Group: Group, Unit: Kind),
+  0x19 "Remove Unit At Location" (Group: Group, Unit: Kind, Count: Units, Location: Location),
+  0x1A "Set Resources" (Group: Group, Modifier: Units, Number: State, Resource: Kind),
+  0x1B "Set Score" (Group: Group, Modifier: Units, Number: State, Score: Kind),
+  0x1C "Minimap Ping" (Location: Location),
+  0x1D "Talking Portrait" (Unit: Kind, Number: Time),
+  0x1E "Mute Unit Speech" (),
+  0x1F "Unmute Unit Speech" (),
+  0x20 "Leaderboard Computer Players" (StateAction: Units),
+  0x21 "Leaderboard Goal Control" (String: Text, Unit: Kind, Number: State),
+  0x22 "Leaderboard Goal Control At Location" (
+    String: Text,
+    Unit: Kind,
+    Number: State,
+    Location: Location,
+  ),
+  0x23 "Leaderboard Goal Resources" (String: Text, Number: State, Resource: Kind),
+  0x24 "Leaderboard Goal Kills" (String: Text, Unit: Kind, Number: State),
+  0x25 "Leaderboard Goal Points" (String: Text, Score: Kind, Number: State),
+  0x26 "Move Location" (Group: Group, Unit: Kind, Location: Location, Location: State),
+  0x27 "Move Unit" (
+    Group: Group,
+    Unit: Kind,
+    Count: Units,
+    Location: Location,
+    Location: State,
+  ),
+  0x28 "Leaderboard Greed" (Number: State),
+  0x29 "Set Next Scenario" (String: Text),
+  0x2A "Set Doodad State" (Group: Group, Unit: Kind, Location: Location, StateAction: Units),
+  0x2B "Set Invincibility" (Group: Group, Unit: Kind, Location: Location, StateAction: Units),
+  0x2C "Create Unit" (Group: Group, Unit: Kind, Count: Units, Location: Location),
+  0x2D "Set Deaths" (Group: Group, Unit: Kind, Modifier: Units, Number: State),
+  0x2E "Order" (Group: Group, Unit: Kind, Location: Location, Location: State, Order: Units),
+  0x2F "Comment" (String: Text),
+  0x30 "Give Units to Player" (
+    Group: Group,
+    Group: State,
+    Unit: Kind,
+    Count: Units,
+    Location: Location,
+  ),
+  0x31 "Modify Unit Hit Points" (
+    Group: Group,
+    Unit: Kind,
+    Number: State,
+    Count: Units,
+    Location: Location,
+  ),
+  0x32 "Modify Unit Energy" (
+    Group: Group,
+    Unit: Kind,
+    Number: State,
+    Count: Units,
+    Location: Location,
+  ),
+  0x33 "Modify Unit Shield Points" (
+    Group: Group,
+    Unit: Kind,
+    Number: State,
+    Count: Units,
+    Location: Location,
+  ),
+  0x34 "Modify Unit Resource Amount" (Group: Group, Number: State, Count: Units, Location: Location),
+  0x35 "Modify Unit Hanger Count" (
+    Group: Group,
+    Unit: Kind,
+    Number: State,
+    Count: Units,
+    Location: Location,
+  ),
+  0x36 "Pause Timer" (),
+  0x37 "Unpause Timer" (),
+  0x38 "Draw" (),
+  0x39 "Set Alliance Status" (Group: Group, Alliance: Kind),
+  0x3A "Disable Debug Mode" (),
+  0x3B "Enable Debug Mode" (),
+];
+
+// =============================================================================
+// Briefings
+// =============================================================================
+
+/// Briefing statements, sharing the trigger conditions.
+pub(crate) const BRIEFING: Syntax = Syntax {
+  block: "Briefing",
+  conditions: CONDITIONS,
+  actions: BRIEFING_ACTIONS,
+};
+
+const BRIEFING_ACTIONS: &[Signature] = signatures![
+  0x00 "No Action" (),
+  0x01 "Wait" (Number: Time),
+  0x02 "Play WAV" (String: Wav, Number: Time),
+  0x03 "Text Message" (String: Text, Number: Time),
+  0x04 "Mission Objectives" (String: Text),
+  0x05 "Show Portrait" (Unit: Kind, Slot: Group),
+  0x06 "Hide Portrait" (Slot: Group),
+  0x07 "Display Speaking Portrait" (Slot: Group, Number: Time),
+  0x08 "Transmission" (
+    String: Text,
+    Slot: Group,
+    Modifier: Units,
+    Number: State,
+    String: Wav,
+    Number: Time,
+  ),
+  0x09 "Skip Tutorial Enabled" (),
+];
+
+const SLOTS: [&str; 4] = ["Slot 1", "Slot 2", "Slot 3", "Slot 4"];
+
+// =============================================================================
+// Keywords
+// =============================================================================
+
+impl Arg {
+  /// Returns the name of a keyword (or group) `value`, if known.
+  pub(crate) fn keyword(&self, value: u32) -> Option<&'static str> {
+    let Ok(byte) = u8::try_from(value) else {
+      return None;
+    };
+
+    let name: &'static str = match self {
+      Self::Group => Group::from_u32(value).as_str(),
+      Self::Comparison => Comparison::from_u8(byte).as_str(),
+      Self::Modifier => Modifier::from_u8(byte).as_str(),
+      Self::SwitchState => SwitchState::from_u8(byte).as_str(),
+      Self::SwitchAction => SwitchAction::from_u8(byte).as_str(),
+      Self::StateAction => StateAction::from_u8(byte).as_str(),
+      Self::Resource => Resource::from_u16(byte.into()).as_str(),
+      Self::Score => Score::from_u16(byte.into()).as_str(),
+      Self::Alliance => Alliance::from_u16(byte.into()).as_str(),
+      Self::Order => Order::from_u8(byte).as_str(),
+      Self::Slot => return SLOTS.get(byte as usize).copied(),
+      _ => return None,
+    };
+
+    (name != "Unknown").then_some(name)
+  }
+
+  /// Returns the value of a keyword (or group) `name`, if known.
+  pub(crate) fn find_keyword(&self, name: &str) -> Option<u32> {
+    (0..=u8::MAX as u32).find(|value| {
+      self
+        .keyword(*value)
+        .is_some_and(|keyword| keyword.eq_ignore_ascii_case(name))
+    })
+  }
+}
@@ -1,8 +1,11 @@
 use core::fmt::Debug;
 use core::fmt::Formatter;
-use core::fmt::Result;
+use core::fmt::Result as FmtResult;
+use storm_core::error::Result;
 use storm_core::types::Magic;
+use storm_utils::traits::WriteExt;
 
+use crate::parse::WriteChunk;
 use crate::types::AnyString;
 use crate::types::Item;
 
@@ -11,14 +14,69 @@ pub struct Chunk {
   pub name: Magic,
   pub size: u32,
   pub item: Item,
+  /// Data of the section after the content of `item`, kept as-is.
+  pub trailing: Box<[u8]>,
+}
+
+impl Chunk {
+  /// The `size` of a section written with the size of its content.
+  ///
+  /// Never the size of a section found in a file, as sizes are signed and
+  /// negative sizes only move the cursor (see [`Scenario`]).
+  ///
+  /// [`Scenario`]: crate::types::Scenario
+  pub const UNSIZED: u32 = u32::MAX;
+
+  /// Create a new `Chunk` with the size of the given `item`.
+  pub fn new(name: Magic, item: Item) -> Result<Self> {
+    let size: u32 = item.to_bytes()?.len() as u32;
+
+    Ok(Self {
+      name,
+      size,
+      item,
+      trailing: Box::default(),
+    })
+  }
+
+  /// Write the section header and content.
+  ///
+  /// The content is the item followed by the trailing data. The header uses
+  /// `size` as-is; the content is truncated to match sections that were
+  /// shorter than their type (padded with zeros when read, or misaligned
+  /// `MTXM` sections with an odd size). Sections with a size of
+  /// [`Chunk::UNSIZED`] use the size of the content.
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    let mut content: Vec<u8> = self.item.to_bytes()?;
+
+    content.extend_from_slice(&self.trailing);
+
+    if self.size != Self::UNSIZED {
+      content.resize(self.size as usize, 0);
+    }
+
+    writer.write_bytes(&self.name[..])?;
+    writer.write_u32_le(content.len() as u32)?;
+    writer.write_bytes(&content)?;
+
+    Ok(())
+  }
 }
 
 impl Debug for Chunk {
-  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     f.debug_struct("Chunk")
       .field("name", &AnyString::read(&self.name[..]))
       .field("size", &self.size)
       .field("item", &self.item)
+      .field("trailing", &self.trailing.len())
       .finish()
   }
 }
+
+serde_struct!(Chunk {
+  name,
+  size,
+  item,
+  trailing,
+});
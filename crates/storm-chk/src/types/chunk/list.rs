@@ -3,6 +3,7 @@ use storm_core::error::Error;
 use storm_core::error::Result;
 use storm_core::extract::FilePtr;
 use storm_core::types::File;
+use storm_utils::traits::WriteExt;
 
 use crate::parse::Parser;
 use crate::types::Chunk;
@@ -20,6 +21,24 @@ impl ChunkList {
     })
   }
 
+  /// Write every chunk, reproducing the original data of unmodified chunks.
+  pub fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    for chunk in self.inner.iter() {
+      chunk.to_writer(writer)?;
+    }
+
+    Ok(())
+  }
+
+  /// Returns every chunk as bytes.
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    let mut output: Vec<u8> = Vec::new();
+
+    self.to_writer(&mut output)?;
+
+    Ok(output)
+  }
+
   #[inline]
   pub const fn len(&self) -> usize {
     self.inner.len()
@@ -40,6 +59,15 @@ impl Deref for ChunkList {
   }
 }
 
+impl FromIterator<Chunk> for ChunkList {
+  #[inline]
+  fn from_iter<T: IntoIterator<Item = Chunk>>(iter: T) -> Self {
+    Self {
+      inner: iter.into_iter().collect(),
+    }
+  }
+}
+
 impl TryFrom<FilePtr<'_>> for ChunkList {
   type Error = Error;
 
@@ -48,3 +76,26 @@ impl TryFrom<FilePtr<'_>> for ChunkList {
     other.read().and_then(Self::new)
   }
 }
+
+only_serde! {
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+
+  use crate::serial::Field;
+
+  impl Serialize for ChunkList {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      self.inner.serialize_field(serializer)
+    }
+  }
+
+  impl<'de> Deserialize<'de> for ChunkList {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      Field::deserialize_field(deserializer).map(|inner| Self { inner })
+    }
+  }
+}
@@ -1,7 +1,5 @@
 mod core;
-mod file;
 mod list;
 
 pub use self::core::Chunk;
-pub use self::file::ChunkFile;
 pub use self::list::ChunkList;
@@ -2,12 +2,16 @@ use core::fmt::Debug;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 use storm_core::error::Result;
+use storm_core::types::Magic;
 use storm_utils::traits::ReadExt;
+use storm_utils::traits::WriteExt;
 
 use crate::consts;
 use crate::item::*;
+use crate::parse::ChunkType;
 use crate::parse::ParseChunk;
 use crate::parse::Parser;
+use crate::parse::WriteChunk;
 
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub enum Item {
@@ -111,6 +115,55 @@ impl Item {
     }
   }
 
+  /// Returns the expected layout of the section with the given `name`.
+  #[rustfmt::skip]
+  pub fn chunk_type(name: Magic) -> Option<ChunkType> {
+    match name {
+      consts::MAGIC_COLR => Some(Colr::TYPE),
+      consts::MAGIC_CRGB => Some(Crgb::TYPE),
+      consts::MAGIC_DD2  => Some(Dd2::TYPE),
+      consts::MAGIC_DIM  => Some(Dim::TYPE),
+      consts::MAGIC_ERA  => Some(Era::TYPE),
+      consts::MAGIC_FORC => Some(Forc::TYPE),
+      consts::MAGIC_IOWN => Some(Iown::TYPE),
+      consts::MAGIC_ISOM => Some(Isom::TYPE),
+      consts::MAGIC_IVE2 => Some(Ive2::TYPE),
+      consts::MAGIC_IVER => Some(Iver::TYPE),
+      consts::MAGIC_MASK => Some(Mask::TYPE),
+      consts::MAGIC_MBRF => Some(Mbrf::TYPE),
+      consts::MAGIC_MRGN => Some(Mrgn::TYPE),
+      consts::MAGIC_MTXM => Some(Mtxm::TYPE),
+      consts::MAGIC_OWNR => Some(Ownr::TYPE),
+      consts::MAGIC_PTEC => Some(Ptec::TYPE),
+      consts::MAGIC_PTEX => Some(Ptex::TYPE),
+      consts::MAGIC_PUNI => Some(Puni::TYPE),
+      consts::MAGIC_PUPX => Some(Pupx::TYPE),
+      consts::MAGIC_SIDE => Some(Side::TYPE),
+      consts::MAGIC_SPRP => Some(Sprp::TYPE),
+      consts::MAGIC_STR  => Some(Str::TYPE),
+      consts::MAGIC_STRX => Some(Strx::TYPE),
+      consts::MAGIC_SWNM => Some(Swnm::TYPE),
+      consts::MAGIC_TECS => Some(Tecs::TYPE),
+      consts::MAGIC_TECX => Some(Tecx::TYPE),
+      consts::MAGIC_THG2 => Some(Thg2::TYPE),
+      consts::MAGIC_TILE => Some(Tile::TYPE),
+      consts::MAGIC_TRIG => Some(Trig::TYPE),
+      consts::MAGIC_TYPE => Some(Type::TYPE),
+      consts::MAGIC_UNIS => Some(Unis::TYPE),
+      consts::MAGIC_UNIT => Some(Unit::TYPE),
+      consts::MAGIC_UNIX => Some(Unix::TYPE),
+      consts::MAGIC_UPGR => Some(Upgr::TYPE),
+      consts::MAGIC_UPGS => Some(Upgs::TYPE),
+      consts::MAGIC_UPGX => Some(Upgx::TYPE),
+      consts::MAGIC_UPRP => Some(Uprp::TYPE),
+      consts::MAGIC_UPUS => Some(Upus::TYPE),
+      consts::MAGIC_VCOD => Some(Vcod::TYPE),
+      consts::MAGIC_VER  => Some(Ver::TYPE),
+      consts::MAGIC_WAV  => Some(Wav::TYPE),
+      _ => None,
+    }
+  }
+
   fn badarg(parser: &mut Parser) -> Result<Self> {
     let size: usize = parser.size as usize;
     let data: Box<[u8]> = parser.reader.read_boxed_u8(size)?;
@@ -168,3 +221,231 @@ impl Debug for Item {
     }
   }
 }
+
+impl WriteChunk for Item {
+  fn to_writer<W: WriteExt>(&self, writer: &mut W) -> Result<()> {
+    match self {
+      Self::None => Ok(()),
+      Self::Barg(data) => writer.write_bytes(data).map_err(Into::into),
+      Self::Colr(item) => item.to_writer(writer),
+      Self::Crgb(item) => item.to_writer(writer),
+      Self::Dd2(item) => item.to_writer(writer),
+      Self::Dim(item) => item.to_writer(writer),
+      Self::Era(item) => item.to_writer(writer),
+      Self::Forc(item) => item.to_writer(writer),
+      Self::Iown(item) => item.to_writer(writer),
+      Self::Isom(item) => item.to_writer(writer),
+      Self::Ive2(item) => item.to_writer(writer),
+      Self::Iver(item) => item.to_writer(writer),
+      Self::Mask(item) => item.to_writer(writer),
+      Self::Mbrf(item) => item.to_writer(writer),
+      Self::Mrgn(item) => item.to_writer(writer),
+      Self::Mtxm(item) => item.to_writer(writer),
+      Self::Ownr(item) => item.to_writer(writer),
+      Self::Ptec(item) => item.to_writer(writer),
+      Self::Ptex(item) => item.to_writer(writer),
+      Self::Puni(item) => item.to_writer(writer),
+      Self::Pupx(item) => item.to_writer(writer),
+      Self::Side(item) => item.to_writer(writer),
+      Self::Sprp(item) => item.to_writer(writer),
+      Self::Str(item) => item.to_writer(writer),
+      Self::Strx(item) => item.to_writer(writer),
+      Self::Swnm(item) => item.to_writer(writer),
+      Self::Tecs(item) => item.to_writer(writer),
+      Self::Tecx(item) => item.to_writer(writer),
+      Self::Thg2(item) => item.to_writer(writer),
+      Self::Tile(item) => item.to_writer(writer),
+      Self::Trig(item) => item.to_writer(writer),
+      Self::Type(item) => item.to_writer(writer),
+      Self::Unis(item) => item.to_writer(writer),
+      Self::Unit(item) => item.to_writer(writer),
+      Self::Unix(item) => item.to_writer(writer),
+      Self::Upgr(item) => item.to_writer(writer),
+      Self::Upgs(item) => item.to_writer(writer),
+      Self::Upgx(item) => item.to_writer(writer),
+      Self::Uprp(item) => item.to_writer(writer),
+      Self::Upus(item) => item.to_writer(writer),
+      Self::Vcod(item) => item.to_writer(writer),
+      Self::Ver(item) => item.to_writer(writer),
+      Self::Wav(item) => item.to_writer(writer),
+    }
+  }
+}
+
+only_serde! {
+  use serde::de::EnumAccess;
+  use serde::de::Error;
+  use serde::de::VariantAccess;
+  use serde::de::Visitor;
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+  use std::borrow::Cow;
+
+  const VARIANTS: &[&str] = &[
+    "None",
+    "Barg",
+    "Colr",
+    "Crgb",
+    "Dd2",
+    "Dim",
+    "Era",
+    "Forc",
+    "Iown",
+    "Isom",
+    "Ive2",
+    "Iver",
+    "Mask",
+    "Mbrf",
+    "Mrgn",
+    "Mtxm",
+    "Ownr",
+    "Ptec",
+    "Ptex",
+    "Puni",
+    "Pupx",
+    "Side",
+    "Sprp",
+    "Str",
+    "Strx",
+    "Swnm",
+    "Tecs",
+    "Tecx",
+    "Thg2",
+    "Tile",
+    "Trig",
+    "Type",
+    "Unis",
+    "Unit",
+    "Unix",
+    "Upgr",
+    "Upgs",
+    "Upgx",
+    "Uprp",
+    "Upus",
+    "Vcod",
+    "Ver",
+    "Wav",
+  ];
+
+  impl Serialize for Item {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      match self {
+        Self::None => serializer.serialize_unit_variant("Item", 0, "None"),
+        Self::Barg(data) => serializer.serialize_newtype_variant("Item", 1, "Barg", data),
+        Self::Colr(item) => serializer.serialize_newtype_variant("Item", 2, "Colr", item),
+        Self::Crgb(item) => serializer.serialize_newtype_variant("Item", 3, "Crgb", item),
+        Self::Dd2(item) => serializer.serialize_newtype_variant("Item", 4, "Dd2", item),
+        Self::Dim(item) => serializer.serialize_newtype_variant("Item", 5, "Dim", item),
+        Self::Era(item) => serializer.serialize_newtype_variant("Item", 6, "Era", item),
+        Self::Forc(item) => serializer.serialize_newtype_variant("Item", 7, "Forc", item),
+        Self::Iown(item) => serializer.serialize_newtype_variant("Item", 8, "Iown", item),
+        Self::Isom(item) => serializer.serialize_newtype_variant("Item", 9, "Isom", item),
+        Self::Ive2(item) => serializer.serialize_newtype_variant("Item", 10, "Ive2", item),
+        Self::Iver(item) => serializer.serialize_newtype_variant("Item", 11, "Iver", item),
+        Self::Mask(item) => serializer.serialize_newtype_variant("Item", 12, "Mask", item),
+        Self::Mbrf(item) => serializer.serialize_newtype_variant("Item", 13, "Mbrf", item),
+        Self::Mrgn(item) => serializer.serialize_newtype_variant("Item", 14, "Mrgn", item),
+        Self::Mtxm(item) => serializer.serialize_newtype_variant("Item", 15, "Mtxm", item),
+        Self::Ownr(item) => serializer.serialize_newtype_variant("Item", 16, "Ownr", item),
+        Self::Ptec(item) => serializer.serialize_newtype_variant("Item", 17, "Ptec", item),
+        Self::Ptex(item) => serializer.serialize_newtype_variant("Item", 18, "Ptex", item),
+        Self::Puni(item) => serializer.serialize_newtype_variant("Item", 19, "Puni", item),
+        Self::Pupx(item) => serializer.serialize_newtype_variant("Item", 20, "Pupx", item),
+        Self::Side(item) => serializer.serialize_newtype_variant("Item", 21, "Side", item),
+        Self::Sprp(item) => serializer.serialize_newtype_variant("Item", 22, "Sprp", item),
+        Self::Str(item) => serializer.serialize_newtype_variant("Item", 23, "Str", item),
+        Self::Strx(item) => serializer.serialize_newtype_variant("Item", 24, "Strx", item),
+        Self::Swnm(item) => serializer.serialize_newtype_variant("Item", 25, "Swnm", item),
+        Self::Tecs(item) => serializer.serialize_newtype_variant("Item", 26, "Tecs", item),
+        Self::Tecx(item) => serializer.serialize_newtype_variant("Item", 27, "Tecx", item),
+        Self::Thg2(item) => serializer.serialize_newtype_variant("Item", 28, "Thg2", item),
+        Self::Tile(item) => serializer.serialize_newtype_variant("Item", 29, "Tile", item),
+        Self::Trig(item) => serializer.serialize_newtype_variant("Item", 30, "Trig", item),
+        Self::Type(item) => serializer.serialize_newtype_variant("Item", 31, "Type", item),
+        Self::Unis(item) => serializer.serialize_newtype_variant("Item", 32, "Unis", item),
+        Self::Unit(item) => serializer.serialize_newtype_variant("Item", 33, "Unit", item),
+        Self::Unix(item) => serializer.serialize_newtype_variant("Item", 34, "Unix", item),
+        Self::Upgr(item) => serializer.serialize_newtype_variant("Item", 35, "Upgr", item),
+        Self::Upgs(item) => serializer.serialize_newtype_variant("Item", 36, "Upgs", item),
+        Self::Upgx(item) => serializer.serialize_newtype_variant("Item", 37, "Upgx", item),
+        Self::Uprp(item) => serializer.serialize_newtype_variant("Item", 38, "Uprp", item),
+        Self::Upus(item) => serializer.serialize_newtype_variant("Item", 39, "Upus", item),
+        Self::Vcod(item) => serializer.serialize_newtype_variant("Item", 40, "Vcod", item),
+        Self::Ver(item) => serializer.serialize_newtype_variant("Item", 41, "Ver", item),
+        Self::Wav(item) => serializer.serialize_newtype_variant("Item", 42, "Wav", item),
+      }
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Item {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      deserializer.deserialize_enum("Item", VARIANTS, ItemVisitor)
+    }
+  }
+
+  struct ItemVisitor;
+
+  impl<'de> Visitor<'de> for ItemVisitor {
+    type Value = Item;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+      f.write_str("a section item")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+      let (name, variant): (Cow<'de, str>, A::Variant) = data.variant()?;
+
+      match &*name {
+        "None" => variant.unit_variant().map(|()| Item::None),
+        "Barg" => variant.newtype_variant().map(Item::Barg),
+        "Colr" => variant.newtype_variant().map(Item::Colr),
+        "Crgb" => variant.newtype_variant().map(Item::Crgb),
+        "Dd2" => variant.newtype_variant().map(Item::Dd2),
+        "Dim" => variant.newtype_variant().map(Item::Dim),
+        "Era" => variant.newtype_variant().map(Item::Era),
+        "Forc" => variant.newtype_variant().map(Item::Forc),
+        "Iown" => variant.newtype_variant().map(Item::Iown),
+        "Isom" => variant.newtype_variant().map(Item::Isom),
+        "Ive2" => variant.newtype_variant().map(Item::Ive2),
+        "Iver" => variant.newtype_variant().map(Item::Iver),
+        "Mask" => variant.newtype_variant().map(Item::Mask),
+        "Mbrf" => variant.newtype_variant().map(Item::Mbrf),
+        "Mrgn" => variant.newtype_variant().map(Item::Mrgn),
+        "Mtxm" => variant.newtype_variant().map(Item::Mtxm),
+        "Ownr" => variant.newtype_variant().map(Item::Ownr),
+        "Ptec" => variant.newtype_variant().map(Item::Ptec),
+        "Ptex" => variant.newtype_variant().map(Item::Ptex),
+        "Puni" => variant.newtype_variant().map(Item::Puni),
+        "Pupx" => variant.newtype_variant().map(Item::Pupx),
+        "Side" => variant.newtype_variant().map(Item::Side),
+        "Sprp" => variant.newtype_variant().map(Item::Sprp),
+        "Str" => variant.newtype_variant().map(Item::Str),
+        "Strx" => variant.newtype_variant().map(Item::Strx),
+        "Swnm" => variant.newtype_variant().map(Item::Swnm),
+        "Tecs" => variant.newtype_variant().map(Item::Tecs),
+        "Tecx" => variant.newtype_variant().map(Item::Tecx),
+        "Thg2" => variant.newtype_variant().map(Item::Thg2),
+        "Tile" => variant.newtype_variant().map(Item::Tile),
+        "Trig" => variant.newtype_variant().map(Item::Trig),
+        "Type" => variant.newtype_variant().map(Item::Type),
+        "Unis" => variant.newtype_variant().map(Item::Unis),
+        "Unit" => variant.newtype_variant().map(Item::Unit),
+        "Unix" => variant.newtype_variant().map(Item::Unix),
+        "Upgr" => variant.newtype_variant().map(Item::Upgr),
+        "Upgs" => variant.newtype_variant().map(Item::Upgs),
+        "Upgx" => variant.newtype_variant().map(Item::Upgx),
+        "Uprp" => variant.newtype_variant().map(Item::Uprp),
+        "Upus" => variant.newtype_variant().map(Item::Upus),
+        "Vcod" => variant.newtype_variant().map(Item::Vcod),
+        "Ver" => variant.newtype_variant().map(Item::Ver),
+        "Wav" => variant.newtype_variant().map(Item::Wav),
+        name => Err(A::Error::unknown_variant(name, VARIANTS)),
+      }
+    }
+  }
+}
+
+serde_field!(Item);
@@ -3,17 +3,23 @@ mod item;
 mod owner;
 mod player;
 mod race;
+mod scenario;
 mod string;
+mod table;
 mod tileset;
 
 pub use self::chunk::Chunk;
-pub use self::chunk::ChunkFile;
 pub use self::chunk::ChunkList;
 pub use self::item::Item;
 pub use self::owner::Owner;
 pub use self::player::Player;
 pub use self::race::Race;
+pub use self::scenario::GameMode;
+pub use self::scenario::Scenario;
+pub use self::scenario::Strings;
 pub use self::string::AnyString;
 pub use self::string::ChkString;
 pub use self::string::Encoding;
+pub use self::table::StringRef;
+pub use self::table::StringTable;
 pub use self::tileset::Tileset;
@@ -31,3 +31,35 @@ impl Owner {
 }
 
 delegate_fmt!(Owner -> as_str);
+
+only_serde! {
+  use serde::de::Error;
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+
+  use crate::serial;
+
+  impl Serialize for Owner {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let name: Option<&str> = (self.0 <= 0x08).then(|| self.as_str());
+      serial::serialize_named(serializer, name, self.0.into())
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Owner {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      let value: u16 = serial::deserialize_named(deserializer, 0x00..=0x08, |value| {
+        Self(value as u8).as_str()
+      })?;
+
+      u8::try_from(value)
+        .map(Self)
+        .map_err(|_| D::Error::custom(format_args!("invalid owner: {value}")))
+    }
+  }
+}
+
+serde_field!(Owner);
@@ -30,3 +30,31 @@ impl Player {
     self.0 as usize
   }
 }
+
+only_serde! {
+  use serde::de::Error;
+  use serde::de::Unexpected;
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+
+  impl Serialize for Player {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      serializer.serialize_u8(self.0)
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Player {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      let value: u8 = u8::deserialize(deserializer)?;
+
+      Self::from_u8(value).ok_or_else(|| {
+        D::Error::invalid_value(Unexpected::Unsigned(value.into()), &"a player number (0-11)")
+      })
+    }
+  }
+}
+
+serde_field!(Player);
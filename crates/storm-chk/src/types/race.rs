@@ -30,3 +30,35 @@ impl Race {
 }
 
 delegate_fmt!(Race -> as_str);
+
+only_serde! {
+  use serde::de::Error;
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+
+  use crate::serial;
+
+  impl Serialize for Race {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let name: Option<&str> = (self.0 <= 0x07).then(|| self.as_str());
+      serial::serialize_named(serializer, name, self.0.into())
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Race {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      let value: u16 = serial::deserialize_named(deserializer, 0x00..=0x07, |value| {
+        Self(value as u8).as_str()
+      })?;
+
+      u8::try_from(value)
+        .map(Self)
+        .map_err(|_| D::Error::custom(format_args!("invalid race: {value}")))
+    }
+  }
+}
+
+serde_field!(Race);
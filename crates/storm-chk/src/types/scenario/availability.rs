@@ -0,0 +1,227 @@
+use storm_core::error::Error;
+use storm_core::error::Result;
+
+use crate::consts;
+use crate::id::TechId;
+use crate::id::UnitId;
+use crate::id::UpgradeId;
+use crate::item::Ptec;
+use crate::item::Ptex;
+use crate::item::Puni;
+use crate::item::Pupx;
+use crate::item::Upgr;
+use crate::item::Ver;
+use crate::types::Item;
+use crate::types::Player;
+use crate::types::Scenario;
+
+// Returns the section for writing, inserting a default one if missing
+macro_rules! section_mut {
+  ($scenario:expr, $name:expr, $variant:ident) => {{
+    if !matches!($scenario.get($name), Some(Item::$variant(_))) {
+      $scenario.insert($name, $variant::default().into())?;
+    }
+
+    match $scenario.get_mut($name) {
+      Some(Item::$variant(item)) => item,
+      _ => {
+        return Err(Error::message(format_args!(
+          "missing section: {:?}",
+          $name.as_str()
+        )))
+      }
+    }
+  }};
+}
+
+// =============================================================================
+// Availability
+// =============================================================================
+
+/// Player settings for units (`PUNI`), technologies (`PTEC`/`PTEx`) and
+/// upgrades (`UPGR`/`PUPx`).
+///
+/// Each section stores a global layer, a player layer and a flag per player
+/// choosing between them. Missing sections use the game defaults. Original
+/// (`VER` 59) scenarios only read the original sections, which only store the
+/// original technologies and upgrades; other scenarios only read the Brood War
+/// sections.
+///
+/// Setters write the player layer and clear the flag, or write the global
+/// layer, of every section used by the format version. Missing sections are
+/// added with default settings.
+impl Scenario {
+  /// Returns `true` if `unit` is available for production by `player`.
+  pub fn unit_available(&self, player: Player, unit: UnitId) -> bool {
+    match self.get(consts::MAGIC_PUNI) {
+      Some(Item::Puni(item)) => item.resolve(player, unit),
+      _ => true,
+    }
+  }
+
+  /// Set whether `unit` is available for production by `player`.
+  pub fn set_unit_available(
+    &mut self,
+    player: Player,
+    unit: UnitId,
+    available: bool,
+  ) -> Result<()> {
+    section_mut!(self, consts::MAGIC_PUNI, Puni).set_allowed(player, unit, available);
+    Ok(())
+  }
+
+  /// Set whether `unit` is available for production by players using global
+  /// defaults.
+  pub fn set_global_unit_available(&mut self, unit: UnitId, available: bool) -> Result<()> {
+    section_mut!(self, consts::MAGIC_PUNI, Puni).set_global_allowed(unit, available);
+    Ok(())
+  }
+
+  /// Returns whether `technology` is available and researched for `player`.
+  pub fn tech_state(&self, player: Player, technology: TechId) -> (bool, bool) {
+    match (self.get(consts::MAGIC_PTEX), self.get(consts::MAGIC_PTEC)) {
+      (Some(Item::Ptex(item)), _) if self.is_expansion() => item.resolve(player, technology),
+      (_, Some(Item::Ptec(item))) if !self.is_expansion() && technology.is_classic() => {
+        item.resolve(player, technology)
+      }
+      _ => (true, technology.is_default_researched()),
+    }
+  }
+
+  /// Set whether `technology` is available and researched for `player`.
+  pub fn set_tech_state(
+    &mut self,
+    player: Player,
+    technology: TechId,
+    available: bool,
+    researched: bool,
+  ) -> Result<()> {
+    let (original, expansion): (bool, bool) = self.layers();
+
+    if expansion {
+      section_mut!(self, consts::MAGIC_PTEX, Ptex)
+        .set_state(player, technology, available, researched);
+    }
+
+    if original && technology.is_classic() {
+      section_mut!(self, consts::MAGIC_PTEC, Ptec)
+        .set_state(player, technology, available, researched);
+    }
+
+    Ok(())
+  }
+
+  /// Set whether `technology` is available and researched for players using
+  /// global defaults.
+  pub fn set_global_tech_state(
+    &mut self,
+    technology: TechId,
+    available: bool,
+    researched: bool,
+  ) -> Result<()> {
+    let (original, expansion): (bool, bool) = self.layers();
+
+    if expansion {
+      section_mut!(self, consts::MAGIC_PTEX, Ptex)
+        .set_global_state(technology, available, researched);
+    }
+
+    if original && technology.is_classic() {
+      section_mut!(self, consts::MAGIC_PTEC, Ptec)
+        .set_global_state(technology, available, researched);
+    }
+
+    Ok(())
+  }
+
+  /// Returns the starting and maximum levels of `upgrade` for `player`.
+  pub fn upgrade_levels(&self, player: Player, upgrade: UpgradeId) -> (u8, u8) {
+    match (self.get(consts::MAGIC_PUPX), self.get(consts::MAGIC_UPGR)) {
+      (Some(Item::Pupx(item)), _) if self.is_expansion() => item.resolve(player, upgrade),
+      (_, Some(Item::Upgr(item))) if !self.is_expansion() && upgrade.is_classic() => {
+        item.resolve(player, upgrade)
+      }
+      _ => (0, upgrade.default_maximum()),
+    }
+  }
+
+  /// Set the starting and maximum levels of `upgrade` for `player`.
+  pub fn set_upgrade_levels(
+    &mut self,
+    player: Player,
+    upgrade: UpgradeId,
+    start: u8,
+    max: u8,
+  ) -> Result<()> {
+    check_levels(start, max)?;
+
+    let (original, expansion): (bool, bool) = self.layers();
+
+    if expansion {
+      section_mut!(self, consts::MAGIC_PUPX, Pupx).set_levels(player, upgrade, start, max);
+    }
+
+    if original && upgrade.is_classic() {
+      section_mut!(self, consts::MAGIC_UPGR, Upgr).set_levels(player, upgrade, start, max);
+    }
+
+    Ok(())
+  }
+
+  /// Set the starting and maximum levels of `upgrade` for players using global
+  /// defaults.
+  pub fn set_global_upgrade_levels(
+    &mut self,
+    upgrade: UpgradeId,
+    start: u8,
+    max: u8,
+  ) -> Result<()> {
+    check_levels(start, max)?;
+
+    let (original, expansion): (bool, bool) = self.layers();
+
+    if expansion {
+      section_mut!(self, consts::MAGIC_PUPX, Pupx).set_global_levels(upgrade, start, max);
+    }
+
+    if original && upgrade.is_classic() {
+      section_mut!(self, consts::MAGIC_UPGR, Upgr).set_global_levels(upgrade, start, max);
+    }
+
+    Ok(())
+  }
+
+  // Returns `false` for original scenarios, ignoring the expansion sections
+  fn is_expansion(&self) -> bool {
+    self.version() != Some(Ver::V1)
+  }
+
+  // Returns whether the original and expansion sections are used, by version
+  // or by presence
+  fn layers(&self) -> (bool, bool) {
+    let (original, expansion): (bool, bool) = match self.version() {
+      Some(Ver::V1) => (true, false),
+      Some(Ver::V2 | Ver::V3) => (true, true),
+      _ => (false, true),
+    };
+
+    (
+      original || self.get(consts::MAGIC_PTEC).is_some() || self.get(consts::MAGIC_UPGR).is_some(),
+      expansion || self.get(consts::MAGIC_PTEX).is_some() || self.get(consts::MAGIC_PUPX).is_some(),
+    )
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+fn check_levels(start: u8, max: u8) -> Result<()> {
+  if start > max {
+    return Err(Error::message(format_args!(
+      "starting level above maximum level: {start} > {max}"
+    )));
+  }
+
+  Ok(())
+}
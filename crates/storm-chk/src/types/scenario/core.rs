@@ -0,0 +1,359 @@
+use core::mem::take;
+use core::str::from_utf8;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use storm_core::error::Error;
+use storm_core::error::Result;
+use storm_core::extract::FilePtr;
+use storm_core::types::File;
+use storm_core::types::Magic;
+
+use crate::consts;
+use crate::item::Dim;
+use crate::item::Era;
+use crate::item::MrgnData;
+use crate::item::Str;
+use crate::item::Strx;
+use crate::item::TrigData;
+use crate::item::UnitData;
+use crate::parse::BoxedSize;
+use crate::parse::ChunkType;
+use crate::parse::Parser;
+use crate::types::ChkString;
+use crate::types::Chunk;
+use crate::types::Item;
+use crate::types::Tileset;
+
+// =============================================================================
+// Scenario
+// =============================================================================
+
+/// A scenario (`.chk`) file, resolved the way StarCraft loads it.
+///
+/// ## Loading Rules
+///
+/// - later sections with the same name override earlier ones
+/// - later `MTXM` sections only overwrite the start of earlier ones; the rest
+///   of the terrain is kept
+/// - fixed-size sections that are too short are padded with zeros; extra data
+///   is ignored, but kept in [`Chunk::trailing`]
+/// - sections of fixed-size entries only read whole entries; the partial entry
+///   is kept in [`Chunk::trailing`]
+/// - sections running past the end of the file are truncated
+/// - section sizes are signed; a negative size jumps back that many bytes
+///   from the end of the section header and adds no content
+///
+/// ## Serialization
+///
+/// With the `serde` feature, a scenario (de)serializes as its resolved
+/// sections. The `json` feature adds [`ExportJson`] for writing a whole
+/// scenario to a JSON file.
+///
+/// [`ExportJson`]: storm_utils::traits::ExportJson
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Scenario {
+  chunks: Box<[Chunk]>,
+}
+
+impl Scenario {
+  /// Sections that are partially overwritten by later duplicates.
+  pub const OVERLAY: &'static [Magic] = &[consts::MAGIC_MTXM];
+
+  /// Parse a new `Scenario` from the given `data`.
+  pub fn new(data: File) -> Result<Self> {
+    let mut order: Vec<Magic> = Vec::new();
+    let mut sections: BTreeMap<Magic, Vec<u8>> = BTreeMap::new();
+
+    for (name, content) in split(&data) {
+      let Some(section) = sections.get_mut(&name) else {
+        order.push(name);
+        sections.insert(name, content.to_vec());
+        continue;
+      };
+
+      if Self::OVERLAY.contains(&name) && section.len() > content.len() {
+        section[..content.len()].copy_from_slice(content);
+      } else {
+        *section = content.to_vec();
+      }
+    }
+
+    let mut chunks: Vec<Chunk> = Vec::with_capacity(order.len());
+
+    for name in order {
+      let mut content: Vec<u8> = sections.remove(&name).unwrap_or_default();
+      let size: u32 = content.len() as u32;
+
+      // Split off the data past the type, which is written back as-is
+      let trailing: Vec<u8> = match Item::chunk_type(name) {
+        Some(ChunkType::Sized(size)) if content.len() > size as usize => {
+          content.split_off(size as usize)
+        }
+        Some(ChunkType::Sized(size)) => {
+          content.resize(size as usize, 0);
+          Vec::new()
+        }
+        Some(ChunkType::Boxed(BoxedSize::Int(size))) => {
+          content.split_off(content.len() - content.len() % size as usize)
+        }
+        Some(ChunkType::Boxed(BoxedSize::Dyn)) | None => Vec::new(),
+      };
+
+      let mut chunk: Chunk = Parser::read_section(name, content).map_err(|error| {
+        Error::message(format_args!("invalid section {:?}: {error}", name.as_str()))
+      })?;
+
+      // Keep the size found in the file, see `Scenario::validate`
+      chunk.size = size;
+
+      if !trailing.is_empty() {
+        chunk.trailing = trailing.into_boxed_slice();
+      }
+
+      chunks.push(chunk);
+    }
+
+    Ok(Self {
+      chunks: chunks.into_boxed_slice(),
+    })
+  }
+
+  /// Returns the resolved sections, in order of first appearance.
+  ///
+  /// The `size` of each section is the size found in the file, before padding,
+  /// or [`Chunk::UNSIZED`] once edited with [`Scenario::get_mut`].
+  #[inline]
+  pub const fn chunks(&self) -> &[Chunk] {
+    &self.chunks
+  }
+
+  /// Returns the resolved sections as bytes, one section per name.
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    let mut output: Vec<u8> = Vec::new();
+
+    for chunk in self.chunks.iter() {
+      chunk.to_writer(&mut output)?;
+    }
+
+    Ok(output)
+  }
+
+  /// Returns the resolved section with the given `name`.
+  #[inline]
+  pub fn get(&self, name: Magic) -> Option<&Item> {
+    self
+      .chunks
+      .iter()
+      .find(|chunk| chunk.name == name)
+      .map(|chunk| &chunk.item)
+  }
+
+  /// Returns the resolved section with the given `name`, for editing.
+  ///
+  /// The section is written with the size of its edited content, dropping
+  /// the size and trailing data found in the file.
+  #[inline]
+  pub fn get_mut(&mut self, name: Magic) -> Option<&mut Item> {
+    let chunk: &mut Chunk = self.chunks.iter_mut().find(|chunk| chunk.name == name)?;
+
+    chunk.size = Chunk::UNSIZED;
+    chunk.trailing = Box::default();
+
+    Some(&mut chunk.item)
+  }
+
+  /// Insert a section, replacing any existing section with the same `name`.
+  ///
+  /// New sections are added at the end.
+  pub fn insert(&mut self, name: Magic, item: Item) -> Result<()> {
+    let chunk: Chunk = Chunk::new(name, item)?;
+
+    if let Some(index) = self.position(name) {
+      self.chunks[index] = chunk;
+    } else {
+      let mut chunks: Vec<Chunk> = take(&mut self.chunks).into_vec();
+
+      chunks.push(chunk);
+
+      self.chunks = chunks.into_boxed_slice();
+    }
+
+    Ok(())
+  }
+
+  /// Remove the section with the given `name`.
+  pub fn remove(&mut self, name: Magic) -> Option<Item> {
+    let index: usize = self.position(name)?;
+    let mut chunks: Vec<Chunk> = take(&mut self.chunks).into_vec();
+    let chunk: Chunk = chunks.remove(index);
+
+    self.chunks = chunks.into_boxed_slice();
+
+    Some(chunk.item)
+  }
+
+  #[inline]
+  fn position(&self, name: Magic) -> Option<usize> {
+    self.chunks.iter().position(|chunk| chunk.name == name)
+  }
+
+  /// Returns the map dimensions (`DIM`).
+  pub fn dimensions(&self) -> Option<Dim> {
+    match self.get(consts::MAGIC_DIM) {
+      Some(Item::Dim(item)) => Some(*item),
+      _ => None,
+    }
+  }
+
+  /// Returns the map tileset (`ERA`).
+  pub fn tileset(&self) -> Option<Tileset> {
+    match self.get(consts::MAGIC_ERA) {
+      Some(Item::Era(Era { tileset })) => Some(*tileset),
+      _ => None,
+    }
+  }
+
+  /// Returns the pre-placed units (`UNIT`).
+  pub fn units(&self) -> &[UnitData] {
+    match self.get(consts::MAGIC_UNIT) {
+      Some(Item::Unit(item)) => &item.units,
+      _ => &[],
+    }
+  }
+
+  /// Returns the locations (`MRGN`).
+  pub fn locations(&self) -> &[MrgnData] {
+    match self.get(consts::MAGIC_MRGN) {
+      Some(Item::Mrgn(item)) => &item.locations,
+      _ => &[],
+    }
+  }
+
+  /// Returns the triggers (`TRIG`).
+  pub fn triggers(&self) -> &[TrigData] {
+    match self.get(consts::MAGIC_TRIG) {
+      Some(Item::Trig(item)) => &item.triggers,
+      _ => &[],
+    }
+  }
+
+  /// Returns the mission briefing triggers (`MBRF`).
+  pub fn briefing(&self) -> &[TrigData] {
+    match self.get(consts::MAGIC_MBRF) {
+      Some(Item::Mbrf(item)) => &item.triggers,
+      _ => &[],
+    }
+  }
+
+  /// Returns the string table.
+  ///
+  /// `STRx` is used if present, like StarCraft: Remastered does.
+  pub fn strings(&self) -> Option<Strings<'_>> {
+    if let Some(Item::Strx(item)) = self.get(consts::MAGIC_STRX) {
+      return Some(Strings::Strx(item));
+    }
+
+    if let Some(Item::Str(item)) = self.get(consts::MAGIC_STR) {
+      return Some(Strings::Str(item));
+    }
+
+    None
+  }
+}
+
+impl TryFrom<FilePtr<'_>> for Scenario {
+  type Error = Error;
+
+  #[inline]
+  fn try_from(other: FilePtr<'_>) -> Result<Self, Self::Error> {
+    other.read().and_then(Self::new)
+  }
+}
+
+serde_struct!(Scenario { chunks });
+
+// =============================================================================
+// Strings
+// =============================================================================
+
+/// The string table of a [`Scenario`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Strings<'a> {
+  /// Legacy string table (`STR`).
+  Str(&'a Str),
+  /// Extended string table (`STRx`).
+  Strx(&'a Strx),
+}
+
+impl<'a> Strings<'a> {
+  /// Returns the string with the given (1-based) `index`.
+  #[inline]
+  pub fn get(&self, index: usize) -> Option<&'a ChkString> {
+    match self {
+      Self::Str(item) => item.get(index),
+      Self::Strx(item) => item.get(index),
+    }
+  }
+
+  /// Returns the number of string slots.
+  #[inline]
+  pub fn len(&self) -> usize {
+    match self {
+      Self::Str(item) => item.offsets.len(),
+      Self::Strx(item) => item.offsets.len(),
+    }
+  }
+
+  /// Returns `true` if there are no string slots.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+// Split the raw data into (name, content) sections
+//
+// Section sizes are signed: a negative size moves the cursor back that many
+// bytes from the end of the header and contributes no content.
+fn split(data: &[u8]) -> Vec<(Magic, &[u8])> {
+  let mut output: Vec<(Magic, &[u8])> = Vec::new();
+  let mut visited: HashSet<usize> = HashSet::new();
+  let mut cursor: usize = 0;
+
+  // Stop at the end of the data, or when backward jumps loop
+  while cursor + 8 <= data.len() && visited.insert(cursor) {
+    let mut name: [u8; 4] = [0; 4];
+    let mut size: [u8; 4] = [0; 4];
+
+    name.copy_from_slice(&data[cursor..cursor + 4]);
+    size.copy_from_slice(&data[cursor + 4..cursor + 8]);
+
+    let start: usize = cursor + 8;
+    let size: i32 = i32::from_le_bytes(size);
+
+    if size < 0 {
+      match start.checked_sub(size.unsigned_abs() as usize) {
+        Some(position) => cursor = position,
+        None => break,
+      }
+
+      continue;
+    }
+
+    let end: usize = start.saturating_add(size as usize).min(data.len());
+
+    // Sections with non-UTF-8 names are never used by the game
+    if from_utf8(&name).is_ok() {
+      // SAFETY: We just checked that `name` is valid UTF-8
+      output.push((unsafe { Magic::new_unchecked(name) }, &data[start..end]));
+    }
+
+    cursor = end;
+  }
+
+  output
+}
@@ -0,0 +1,7 @@
+mod availability;
+mod core;
+mod validate;
+
+pub use self::core::Scenario;
+pub use self::core::Strings;
+pub use self::validate::GameMode;
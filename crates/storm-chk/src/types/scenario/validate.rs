@@ -0,0 +1,391 @@
+use storm_core::types::Diagnostic;
+use storm_core::types::Magic;
+use storm_core::types::Severity;
+
+use crate::consts;
+use crate::item::Dim;
+use crate::item::Ver;
+use crate::parse::BoxedSize;
+use crate::parse::ChunkType;
+use crate::types::Chunk;
+use crate::types::Item;
+use crate::types::Scenario;
+use crate::types::Strings;
+
+// Sections required for all versions and all game types
+const REQUIRED_ALL: &[Magic] = &[
+  consts::MAGIC_VER,
+  consts::MAGIC_DIM,
+  consts::MAGIC_ERA,
+  consts::MAGIC_FORC,
+  consts::MAGIC_MTXM,
+  consts::MAGIC_OWNR,
+  consts::MAGIC_SIDE,
+  consts::MAGIC_SPRP,
+  consts::MAGIC_THG2,
+  consts::MAGIC_UNIT,
+  consts::MAGIC_VCOD,
+];
+
+// Sections required for all versions, except in Melee
+const REQUIRED_UMS: &[Magic] = &[
+  consts::MAGIC_MASK,
+  consts::MAGIC_MBRF,
+  consts::MAGIC_MRGN,
+  consts::MAGIC_PUNI,
+  consts::MAGIC_TRIG,
+  consts::MAGIC_UPRP,
+];
+
+// Sections required for Vanilla and Hybrid (Original mode), except in Melee
+const REQUIRED_ORIGINAL: &[Magic] = &[
+  consts::MAGIC_PTEC,
+  consts::MAGIC_TECS,
+  consts::MAGIC_UNIS,
+  consts::MAGIC_UPGR,
+  consts::MAGIC_UPGS,
+];
+
+// Sections required for Hybrid (Expansion mode) and Brood War, except in Melee
+const REQUIRED_EXPANSION: &[Magic] = &[
+  consts::MAGIC_PTEX,
+  consts::MAGIC_PUPX,
+  consts::MAGIC_TECX,
+  consts::MAGIC_UNIX,
+  consts::MAGIC_UPGX,
+];
+
+// =============================================================================
+// Game Mode
+// =============================================================================
+
+/// The game types a [`Scenario`] can be played in.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GameMode {
+  /// Melee (and other built-in game types) only.
+  Melee,
+  /// Use Map Settings.
+  Ums,
+}
+
+impl GameMode {
+  /// Returns the name of the game mode.
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Melee => "Melee",
+      Self::Ums => "Use Map Settings",
+    }
+  }
+}
+
+// =============================================================================
+// Validation
+// =============================================================================
+
+impl Scenario {
+  /// Returns the format version (`VER`).
+  pub fn version(&self) -> Option<u16> {
+    match self.get(consts::MAGIC_VER) {
+      Some(Item::Ver(item)) => Some(item.version),
+      _ => None,
+    }
+  }
+
+  /// Check the scenario for problems StarCraft would reject or work around
+  /// when played in the given game `mode`.
+  ///
+  /// The game mode isn't stored in the scenario; it's chosen in the lobby.
+  ///
+  /// ## Checks
+  ///
+  /// - unsupported or unknown format versions
+  /// - required sections for the version and game mode
+  /// - section sizes
+  /// - string indices (`SPRP`, `FORC`, `MRGN`, `UNIS`/`UNIx`)
+  /// - location indices in triggers
+  /// - terrain sizes (`MTXM`, `TILE`, `ISOM`, `MASK`) against `DIM`
+  pub fn validate(&self, mode: GameMode) -> Vec<Diagnostic> {
+    let mut output: Vec<Diagnostic> = Vec::new();
+
+    validate_sections(self, mode, &mut output);
+    validate_sizes(self, &mut output);
+    validate_strings(self, &mut output);
+    validate_locations(self, &mut output);
+    validate_terrain(self, &mut output);
+
+    output
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+fn validate_sections(scenario: &Scenario, mode: GameMode, output: &mut Vec<Diagnostic>) {
+  let mut required: Vec<Magic> = REQUIRED_ALL.to_vec();
+  let ums: bool = mode == GameMode::Ums;
+
+  // Vanilla, Hybrid and Brood War use the original and/or expansion sections
+  let (original, expansion): (bool, bool) = match scenario.version() {
+    Some(Ver::V1) => (true, false),
+    Some(Ver::V2 | Ver::V3) => (true, true),
+    Some(Ver::V4 | Ver::V5) => (false, true),
+    Some(
+      version @ (Ver::UV1
+      | Ver::UV2
+      | Ver::UV3
+      | Ver::UV4
+      | Ver::UV5
+      | Ver::UV6
+      | Ver::UV7
+      | Ver::UV8),
+    ) => {
+      Diagnostic::new(
+        "unsupported-version",
+        Severity::Error,
+        format_args!("unsupported format version: {version:#06X}"),
+      )
+      .report(output);
+
+      (false, false)
+    }
+    Some(version) => {
+      Diagnostic::new(
+        "unknown-version",
+        Severity::Error,
+        format_args!("unknown format version: {version:#06X}"),
+      )
+      .report(output);
+
+      (false, false)
+    }
+    None => (false, false),
+  };
+
+  if expansion && !original {
+    required.push(consts::MAGIC_COLR);
+  }
+
+  if ums {
+    required.extend_from_slice(REQUIRED_UMS);
+
+    if original {
+      required.extend_from_slice(REQUIRED_ORIGINAL);
+    }
+
+    if expansion {
+      required.extend_from_slice(REQUIRED_EXPANSION);
+    }
+  }
+
+  for name in required {
+    if scenario.get(name).is_none() {
+      Diagnostic::new(
+        "missing-section",
+        Severity::Error,
+        format_args!("missing required section: {:?}", name.as_str()),
+      )
+      .report(output);
+    }
+  }
+
+  if scenario.strings().is_none() {
+    Diagnostic::new(
+      "missing-section",
+      Severity::Error,
+      "missing required section: \"STR \" or \"STRx\"",
+    )
+    .report(output);
+  }
+}
+
+fn validate_sizes(scenario: &Scenario, output: &mut Vec<Diagnostic>) {
+  for chunk in scenario.chunks() {
+    let size: u32 = chunk.size;
+
+    // Edited sections are written with the size of their content
+    if size == Chunk::UNSIZED {
+      continue;
+    }
+
+    let valid: bool = match Item::chunk_type(chunk.name) {
+      Some(ChunkType::Sized(expected)) => size == expected,
+      Some(ChunkType::Boxed(BoxedSize::Int(entry))) => size.is_multiple_of(entry),
+      Some(ChunkType::Boxed(BoxedSize::Dyn)) | None => continue,
+    };
+
+    if !valid {
+      Diagnostic::new(
+        "section-size",
+        Severity::Warning,
+        format_args!(
+          "invalid size for section {:?}: {size} bytes",
+          chunk.name.as_str()
+        ),
+      )
+      .report(output);
+    }
+  }
+}
+
+fn validate_strings(scenario: &Scenario, output: &mut Vec<Diagnostic>) {
+  let Some(strings) = scenario.strings() else {
+    return;
+  };
+
+  if let Some(Item::Sprp(item)) = scenario.get(consts::MAGIC_SPRP) {
+    check_string(&strings, item.name, output, || {
+      "scenario name (SPRP)".into()
+    });
+    check_string(&strings, item.desc, output, || {
+      "scenario description (SPRP)".into()
+    });
+  }
+
+  if let Some(Item::Forc(item)) = scenario.get(consts::MAGIC_FORC) {
+    for (force, name) in item.names.iter().enumerate() {
+      check_string(&strings, *name, output, || {
+        format!("force {} name (FORC)", force + 1)
+      });
+    }
+  }
+
+  for (location, data) in scenario.locations().iter().enumerate() {
+    check_string(&strings, data.index, output, || {
+      format!("location {} name (MRGN)", location + 1)
+    });
+  }
+
+  let units: Option<(&[u16], &str)> = match scenario.get(consts::MAGIC_UNIX) {
+    Some(Item::Unix(item)) => Some((&item.string, "UNIx")),
+    _ => match scenario.get(consts::MAGIC_UNIS) {
+      Some(Item::Unis(item)) => Some((&item.string, "UNIS")),
+      _ => None,
+    },
+  };
+
+  if let Some((names, section)) = units {
+    for (unit, name) in names.iter().enumerate() {
+      check_string(&strings, *name, output, || {
+        format!("unit {unit} name ({section})")
+      });
+    }
+  }
+}
+
+fn check_string<F>(strings: &Strings<'_>, index: u16, output: &mut Vec<Diagnostic>, context: F)
+where
+  F: FnOnce() -> String,
+{
+  if index != 0 && strings.get(index.into()).is_none() {
+    Diagnostic::new(
+      "string-index",
+      Severity::Warning,
+      format_args!("invalid string index {index} in {}", context()),
+    )
+    .report(output);
+  }
+}
+
+fn validate_locations(scenario: &Scenario, output: &mut Vec<Diagnostic>) {
+  let count: usize = scenario.locations().len();
+
+  for (index, trigger) in scenario.triggers().iter().enumerate() {
+    for (position, condition) in trigger.conditions.iter().enumerate() {
+      let Some(location) = condition.decode().location() else {
+        continue;
+      };
+
+      if location as usize > count {
+        Diagnostic::new(
+          "location-index",
+          Severity::Error,
+          format_args!(
+            "invalid location {location} in trigger {} condition {}",
+            index + 1,
+            position + 1,
+          ),
+        )
+        .report(output);
+      }
+    }
+
+    for (position, action) in trigger.actions.iter().enumerate() {
+      for location in action.decode().locations() {
+        if location as usize > count {
+          Diagnostic::new(
+            "location-index",
+            Severity::Error,
+            format_args!(
+              "invalid location {location} in trigger {} action {}",
+              index + 1,
+              position + 1,
+            ),
+          )
+          .report(output);
+        }
+      }
+    }
+  }
+}
+
+fn validate_terrain(scenario: &Scenario, output: &mut Vec<Diagnostic>) {
+  let Some(Dim { w, h }) = scenario.dimensions() else {
+    return;
+  };
+
+  let tiles: usize = usize::from(w) * usize::from(h);
+  let diamonds: usize = (usize::from(w) / 2 + 1) * (usize::from(h) + 1) * 4;
+
+  let sizes: [(Magic, Option<usize>, usize); 4] = [
+    (
+      consts::MAGIC_MTXM,
+      terrain_len(scenario, consts::MAGIC_MTXM),
+      tiles,
+    ),
+    (
+      consts::MAGIC_TILE,
+      terrain_len(scenario, consts::MAGIC_TILE),
+      tiles,
+    ),
+    (
+      consts::MAGIC_ISOM,
+      terrain_len(scenario, consts::MAGIC_ISOM),
+      diamonds,
+    ),
+    (
+      consts::MAGIC_MASK,
+      terrain_len(scenario, consts::MAGIC_MASK),
+      tiles,
+    ),
+  ];
+
+  for (name, size, expected) in sizes {
+    let Some(size) = size else {
+      continue;
+    };
+
+    if size != expected {
+      Diagnostic::new(
+        "terrain-size",
+        Severity::Warning,
+        format_args!(
+          "section {:?} has {size} entries, expected {expected} for {w}x{h}",
+          name.as_str()
+        ),
+      )
+      .report(output);
+    }
+  }
+}
+
+fn terrain_len(scenario: &Scenario, name: Magic) -> Option<usize> {
+  match scenario.get(name)? {
+    Item::Mtxm(item) => Some(item.tiles.len()),
+    Item::Tile(item) => Some(item.tiles.len()),
+    Item::Isom(item) => Some(item.tiles.len()),
+    Item::Mask(item) => Some(item.tiles.len()),
+    _ => None,
+  }
+}
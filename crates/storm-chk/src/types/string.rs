@@ -24,6 +24,11 @@ impl ChkString {
     unsafe { &*(slice as *const [u8] as *const Self) }
   }
 
+  #[inline]
+  pub const fn as_bytes(&self) -> &[u8] {
+    &self.inner
+  }
+
   #[inline]
   pub fn as_any(&self) -> AnyString<'_> {
     AnyString::read(&self.inner)
@@ -37,14 +42,12 @@ impl ChkString {
       return None;
     }
 
+    // Offsets are relative to `content`; unused entries (offset 0) point before
+    // it and wrap around out of bounds
     let offset: u32 = offsets[index - 1].into();
-    let string: &[u8] = &content[offset as usize..];
+    let string: &[u8] = content.get(offset as usize..)?;
 
-    if offset != 0 {
-      Some(Self::from_slice(Self::read_to_nul(string)))
-    } else {
-      None
-    }
+    Some(Self::from_slice(Self::read_to_nul(string)))
   }
 
   fn read_to_nul(data: &[u8]) -> &[u8] {
@@ -70,6 +73,30 @@ impl Display for ChkString {
   }
 }
 
+only_serde! {
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+
+  impl Serialize for ChkString {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      serializer.serialize_str(self.as_any().as_str())
+    }
+  }
+
+  /// Strings are deserialized from text and stored as UTF-8.
+  impl<'de> Deserialize<'de> for Box<ChkString> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      let data: Box<[u8]> = String::deserialize(deserializer)?.into_bytes().into_boxed_slice();
+
+      // SAFETY: ChkString is `repr(transparent)` and takes ownership of `data`.
+      Ok(unsafe { Box::from_raw(Box::into_raw(data) as *mut ChkString) })
+    }
+  }
+}
+
 // =============================================================================
 // Encoding
 // =============================================================================
@@ -82,6 +109,22 @@ pub enum Encoding {
   Invalid,
 }
 
+impl Encoding {
+  /// Encode `text` with this encoding.
+  ///
+  /// Returns `None` if `text` contains characters the encoding can not
+  /// represent. `Invalid` encodes as UTF-8.
+  pub fn encode<'a>(&self, text: &'a str) -> Option<Cow<'a, [u8]>> {
+    let (data, _, errors): (Cow<'a, [u8]>, _, bool) = match self {
+      Self::Utf8 | Self::Invalid => return Some(Cow::Borrowed(text.as_bytes())),
+      Self::Cp949 => EUC_KR.encode(text),
+      Self::Cp1252 => WINDOWS_1252.encode(text),
+    };
+
+    (!errors).then_some(data)
+  }
+}
+
 // =============================================================================
 // Any String
 // =============================================================================
@@ -0,0 +1,513 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use storm_core::error::Error;
+use storm_core::error::Result;
+
+use crate::consts;
+use crate::item::Str;
+use crate::item::Strx;
+use crate::item::Ver;
+use crate::trigger::Action;
+use crate::trigger::BriefingAction;
+use crate::types::AnyString;
+use crate::types::ChkString;
+use crate::types::Encoding;
+use crate::types::Item;
+use crate::types::Scenario;
+use crate::types::Strings;
+
+// =============================================================================
+// String Table
+// =============================================================================
+
+/// An editable string table, convertible to `STR ` and `STRx`.
+///
+/// Strings are addressed by their (1-based) index, like the rest of the
+/// scenario does. Removed and unused slots are kept empty so the indices of
+/// other strings do not change; [`StringTable::compact`] renumbers them.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct StringTable {
+  strings: Vec<Option<Box<[u8]>>>,
+  encoding: Encoding,
+}
+
+impl StringTable {
+  /// The largest `STR ` section offset.
+  pub const STR_LIMIT: usize = u16::MAX as usize;
+
+  /// Create a new, empty `StringTable` using the given `encoding`.
+  #[inline]
+  pub const fn new(encoding: Encoding) -> Self {
+    Self {
+      strings: Vec::new(),
+      encoding,
+    }
+  }
+
+  /// Create a new `StringTable` from the strings of a [`Scenario`].
+  ///
+  /// The encoding is detected from the existing strings, defaulting to UTF-8.
+  /// Empty strings are read as empty slots.
+  #[inline]
+  pub fn from_scenario(scenario: &Scenario) -> Self {
+    scenario
+      .strings()
+      .map_or_else(|| Self::new(Encoding::Utf8), Self::from)
+  }
+
+  /// Returns every string reference of `scenario`, along with the (1-based)
+  /// string index.
+  ///
+  /// Trigger and briefing actions only refer to the strings their action type
+  /// uses. Unused references (index 0) are skipped.
+  pub fn references(scenario: &Scenario) -> Vec<(StringRef, u32)> {
+    references(scenario)
+  }
+
+  /// Returns the encoding used for new strings.
+  #[inline]
+  pub const fn encoding(&self) -> Encoding {
+    self.encoding
+  }
+
+  /// Returns the number of string slots.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.strings.len()
+  }
+
+  /// Returns `true` if there are no string slots.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.strings.is_empty()
+  }
+
+  /// Returns the string with the given (1-based) `index`.
+  pub fn get(&self, index: usize) -> Option<AnyString<'_>> {
+    let string: &[u8] = self.strings.get(index.checked_sub(1)?)?.as_deref()?;
+
+    Some(AnyString::read(string))
+  }
+
+  /// Add a new string, returning its (1-based) index.
+  ///
+  /// The first empty slot is reused, if any.
+  pub fn add(&mut self, text: &str) -> Result<usize> {
+    let data: Box<[u8]> = self.encode(text)?;
+
+    if let Some(index) = self.strings.iter().position(Option::is_none) {
+      self.strings[index] = Some(data);
+      return Ok(index + 1);
+    }
+
+    self.strings.push(Some(data));
+
+    Ok(self.strings.len())
+  }
+
+  /// Replace the string with the given (1-based) `index`.
+  ///
+  /// The table grows if `index` is past the last slot.
+  pub fn replace(&mut self, index: usize, text: &str) -> Result<()> {
+    let Some(slot) = index.checked_sub(1) else {
+      return Err(Error::message("invalid string index: 0"));
+    };
+
+    let data: Box<[u8]> = self.encode(text)?;
+
+    if slot >= self.strings.len() {
+      self.strings.resize(slot + 1, None);
+    }
+
+    self.strings[slot] = Some(data);
+
+    Ok(())
+  }
+
+  /// Remove the string with the given (1-based) `index`, leaving its slot
+  /// empty.
+  ///
+  /// References to the string are not updated.
+  pub fn remove(&mut self, index: usize) -> Option<String> {
+    let slot: &mut Option<Box<[u8]>> = self.strings.get_mut(index.checked_sub(1)?)?;
+    let data: Box<[u8]> = slot.take()?;
+
+    Some(AnyString::read(&data).into_string())
+  }
+
+  /// Remove every string not referenced by `scenario` and renumber the rest,
+  /// updating the references.
+  ///
+  /// References to missing strings are kept, pointing to empty slots.
+  pub fn compact(&mut self, scenario: &mut Scenario) {
+    let used: BTreeSet<u32> = references(scenario)
+      .into_iter()
+      .map(|(_, index)| index)
+      .collect();
+
+    let mut strings: Vec<Option<Box<[u8]>>> = Vec::with_capacity(used.len());
+    let mut mapping: HashMap<u32, u32> = HashMap::with_capacity(used.len());
+
+    for index in used {
+      let slot: usize = index as usize - 1;
+
+      strings.push(self.strings.get_mut(slot).and_then(Option::take));
+      mapping.insert(index, strings.len() as u32);
+    }
+
+    remap(scenario, |index| mapping[&index]);
+
+    self.strings = strings;
+  }
+
+  /// Merge identical strings, updating the references of `scenario` to use
+  /// the first copy.
+  ///
+  /// The slots of the duplicates are left empty.
+  pub fn deduplicate(&mut self, scenario: &mut Scenario) {
+    let mut first: HashMap<&[u8], u32> = HashMap::new();
+    let mut mapping: HashMap<u32, u32> = HashMap::new();
+
+    for (slot, string) in self.strings.iter().enumerate() {
+      let Some(string) = string.as_deref() else {
+        continue;
+      };
+
+      let index: u32 = slot as u32 + 1;
+      let canonical: u32 = *first.entry(string).or_insert(index);
+
+      if canonical != index {
+        mapping.insert(index, canonical);
+      }
+    }
+
+    remap(scenario, |index| {
+      mapping.get(&index).copied().unwrap_or(index)
+    });
+
+    for index in mapping.keys() {
+      self.strings[*index as usize - 1] = None;
+    }
+  }
+
+  /// Returns the table as a `STR ` section.
+  ///
+  /// Fails if the table does not fit the 16-bit offsets of `STR `.
+  pub fn to_str(&self) -> Result<Str> {
+    let Ok(entries) = u16::try_from(self.strings.len()) else {
+      return Err(Error::message("too many strings for STR"));
+    };
+
+    // Offsets are stored relative to the start of the section
+    let cursor: usize = 2 + self.strings.len() * 2;
+    let (offsets, content): (Vec<usize>, Vec<u8>) = self.layout();
+
+    let offsets: Box<[u16]> = offsets
+      .into_iter()
+      .map(|offset| match offset {
+        offset if cursor + offset <= Self::STR_LIMIT => Ok(offset as u16),
+        _ => Err(Error::message("too much string data for STR")),
+      })
+      .collect::<Result<_>>()?;
+
+    Ok(Str {
+      entries,
+      offsets,
+      content: content.into_boxed_slice(),
+    })
+  }
+
+  /// Returns the table as a `STRx` section.
+  pub fn to_strx(&self) -> Strx {
+    let (offsets, content): (Vec<usize>, Vec<u8>) = self.layout();
+    let offsets: Box<[u32]> = offsets.into_iter().map(|offset| offset as u32).collect();
+
+    Strx {
+      entries: self.strings.len() as u32,
+      offsets,
+      content: content.into_boxed_slice(),
+    }
+  }
+
+  /// Store the table in `scenario`.
+  ///
+  /// `STRx` is used if the scenario already uses it or the table does not fit
+  /// in `STR `; the other section is removed. Writing `STRx` raises the format
+  /// version (`VER`) to the matching StarCraft Remastered version, the first
+  /// to read `STRx`.
+  pub fn write(&self, scenario: &mut Scenario) -> Result<()> {
+    let extended: bool = matches!(scenario.get(consts::MAGIC_STRX), Some(Item::Strx(_)));
+
+    let item: Item = match self.to_str() {
+      Ok(item) if !extended => item.into(),
+      _ => self.to_strx().into(),
+    };
+
+    if let Item::Str(_) = item {
+      scenario.insert(consts::MAGIC_STR, item)?;
+      scenario.remove(consts::MAGIC_STRX);
+    } else {
+      scenario.insert(consts::MAGIC_STRX, item)?;
+      scenario.remove(consts::MAGIC_STR);
+
+      let version: Option<u16> = match scenario.version() {
+        Some(Ver::V1 | Ver::V2) => Some(Ver::V3),
+        Some(Ver::V4) => Some(Ver::V5),
+        _ => None,
+      };
+
+      if let Some(version) = version {
+        scenario.insert(consts::MAGIC_VER, Ver { version }.into())?;
+      }
+    }
+
+    Ok(())
+  }
+
+  fn encode(&self, text: &str) -> Result<Box<[u8]>> {
+    if text.contains('\0') {
+      return Err(Error::message("strings can not contain NUL characters"));
+    }
+
+    match self.encoding.encode(text) {
+      Some(data) => Ok(Box::from(data.as_ref())),
+      None => Err(Error::message(format_args!(
+        "string can not be encoded as {:?}: {text:?}",
+        self.encoding
+      ))),
+    }
+  }
+
+  // Returns the content offset of each string and the content, storing
+  // identical strings once; empty slots share the NUL of an empty string
+  fn layout(&self) -> (Vec<usize>, Vec<u8>) {
+    let mut offsets: Vec<usize> = Vec::with_capacity(self.strings.len());
+    let mut content: Vec<u8> = Vec::new();
+    let mut shared: HashMap<&[u8], usize> = HashMap::new();
+
+    for string in self.strings.iter() {
+      let string: &[u8] = string.as_deref().unwrap_or_default();
+      let offset: usize = *shared.entry(string).or_insert_with(|| {
+        let offset: usize = content.len();
+
+        content.extend_from_slice(string);
+        content.push(0);
+
+        offset
+      });
+
+      offsets.push(offset);
+    }
+
+    (offsets, content)
+  }
+}
+
+impl From<Strings<'_>> for StringTable {
+  fn from(other: Strings<'_>) -> Self {
+    let strings: Vec<Option<Box<[u8]>>> = (1..=other.len())
+      .map(|index| other.get(index).map(ChkString::as_bytes))
+      .map(|string| string.filter(|string| !string.is_empty()).map(Box::from))
+      .collect();
+
+    let encoding: Encoding = strings
+      .iter()
+      .flatten()
+      .map(|string| AnyString::read(string).kind())
+      .find(|kind| *kind != Encoding::Utf8)
+      .unwrap_or(Encoding::Utf8);
+
+    Self { strings, encoding }
+  }
+}
+
+// =============================================================================
+// String Reference
+// =============================================================================
+
+/// A place in a [`Scenario`] referring to a string by index.
+///
+/// Positions are 0-based.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StringRef {
+  /// Scenario name (`SPRP`).
+  ScenarioName,
+  /// Scenario description (`SPRP`).
+  ScenarioDesc,
+  /// Force name (`FORC`).
+  Force(usize),
+  /// Location name (`MRGN`).
+  Location(usize),
+  /// Unit name (`UNIS`).
+  UnitClassic(usize),
+  /// Unit name (`UNIx`).
+  Unit(usize),
+  /// Switch name (`SWNM`).
+  Switch(usize),
+  /// WAV file name (`WAV `).
+  Wav(usize),
+  /// Trigger action text (`TRIG`).
+  TriggerText(usize, usize),
+  /// Trigger action WAV file name (`TRIG`).
+  TriggerWav(usize, usize),
+  /// Briefing action text (`MBRF`).
+  BriefingText(usize, usize),
+  /// Briefing action WAV file name (`MBRF`).
+  BriefingWav(usize, usize),
+}
+
+// Returns every (used) string reference of `scenario`
+fn references(scenario: &Scenario) -> Vec<(StringRef, u32)> {
+  let mut output: Vec<(StringRef, u32)> = Vec::new();
+
+  let mut push = |reference: StringRef, index: u32| {
+    if index != 0 {
+      output.push((reference, index));
+    }
+  };
+
+  for chunk in scenario.chunks() {
+    match chunk.item {
+      Item::Sprp(ref item) => {
+        push(StringRef::ScenarioName, item.name.into());
+        push(StringRef::ScenarioDesc, item.desc.into());
+      }
+      Item::Forc(ref item) => {
+        for (index, name) in item.names.iter().enumerate() {
+          push(StringRef::Force(index), (*name).into());
+        }
+      }
+      Item::Mrgn(ref item) => {
+        for (index, data) in item.locations.iter().enumerate() {
+          push(StringRef::Location(index), data.index.into());
+        }
+      }
+      Item::Unis(ref item) => {
+        for (index, name) in item.string.iter().enumerate() {
+          push(StringRef::UnitClassic(index), (*name).into());
+        }
+      }
+      Item::Unix(ref item) => {
+        for (index, name) in item.string.iter().enumerate() {
+          push(StringRef::Unit(index), (*name).into());
+        }
+      }
+      Item::Swnm(ref item) => {
+        for (index, name) in item.switches.iter().enumerate() {
+          push(StringRef::Switch(index), *name);
+        }
+      }
+      Item::Wav(ref item) => {
+        for (index, name) in item.wav.iter().enumerate() {
+          push(StringRef::Wav(index), *name);
+        }
+      }
+      Item::Trig(ref item) => {
+        for (trigger, data) in item.triggers.iter().enumerate() {
+          for (action, data) in data.actions.iter().enumerate() {
+            let data: Action = data.decode();
+
+            if let Some(text) = data.text() {
+              push(StringRef::TriggerText(trigger, action), text);
+            }
+
+            if let Some(wav) = data.wav() {
+              push(StringRef::TriggerWav(trigger, action), wav);
+            }
+          }
+        }
+      }
+      Item::Mbrf(ref item) => {
+        for (trigger, data) in item.triggers.iter().enumerate() {
+          for (action, data) in data.actions.iter().enumerate() {
+            let data: BriefingAction = data.decode_briefing();
+
+            if let Some(text) = data.text() {
+              push(StringRef::BriefingText(trigger, action), text);
+            }
+
+            if let Some(wav) = data.wav() {
+              push(StringRef::BriefingWav(trigger, action), wav);
+            }
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  output
+}
+
+// Replace every string reference of `scenario` with `mapping(index)`
+fn remap<F>(scenario: &mut Scenario, mut mapping: F)
+where
+  F: FnMut(u32) -> u32,
+{
+  for (reference, index) in references(scenario) {
+    let index: u32 = mapping(index);
+
+    match reference {
+      StringRef::ScenarioName => {
+        if let Some(Item::Sprp(item)) = scenario.get_mut(consts::MAGIC_SPRP) {
+          item.name = index as u16;
+        }
+      }
+      StringRef::ScenarioDesc => {
+        if let Some(Item::Sprp(item)) = scenario.get_mut(consts::MAGIC_SPRP) {
+          item.desc = index as u16;
+        }
+      }
+      StringRef::Force(position) => {
+        if let Some(Item::Forc(item)) = scenario.get_mut(consts::MAGIC_FORC) {
+          item.names[position] = index as u16;
+        }
+      }
+      StringRef::Location(position) => {
+        if let Some(Item::Mrgn(item)) = scenario.get_mut(consts::MAGIC_MRGN) {
+          item.locations[position].index = index as u16;
+        }
+      }
+      StringRef::UnitClassic(position) => {
+        if let Some(Item::Unis(item)) = scenario.get_mut(consts::MAGIC_UNIS) {
+          item.string[position] = index as u16;
+        }
+      }
+      StringRef::Unit(position) => {
+        if let Some(Item::Unix(item)) = scenario.get_mut(consts::MAGIC_UNIX) {
+          item.string[position] = index as u16;
+        }
+      }
+      StringRef::Switch(position) => {
+        if let Some(Item::Swnm(item)) = scenario.get_mut(consts::MAGIC_SWNM) {
+          item.switches[position] = index;
+        }
+      }
+      StringRef::Wav(position) => {
+        if let Some(Item::Wav(item)) = scenario.get_mut(consts::MAGIC_WAV) {
+          item.wav[position] = index;
+        }
+      }
+      StringRef::TriggerText(trigger, action) => {
+        if let Some(Item::Trig(item)) = scenario.get_mut(consts::MAGIC_TRIG) {
+          item.triggers[trigger].actions[action].txt_index = index;
+        }
+      }
+      StringRef::TriggerWav(trigger, action) => {
+        if let Some(Item::Trig(item)) = scenario.get_mut(consts::MAGIC_TRIG) {
+          item.triggers[trigger].actions[action].wav_index = index;
+        }
+      }
+      StringRef::BriefingText(trigger, action) => {
+        if let Some(Item::Mbrf(item)) = scenario.get_mut(consts::MAGIC_MBRF) {
+          item.triggers[trigger].actions[action].txt_index = index;
+        }
+      }
+      StringRef::BriefingWav(trigger, action) => {
+        if let Some(Item::Mbrf(item)) = scenario.get_mut(consts::MAGIC_MBRF) {
+          item.triggers[trigger].actions[action].wav_index = index;
+        }
+      }
+    }
+  }
+}
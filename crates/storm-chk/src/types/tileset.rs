@@ -32,3 +32,31 @@ impl Tileset {
 }
 
 delegate_fmt!(Tileset -> as_str);
+
+only_serde! {
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+
+  use crate::serial;
+
+  impl Serialize for Tileset {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let name: Option<&str> = (self.0 <= 0x0007).then(|| self.as_str());
+      serial::serialize_named(serializer, name, self.0)
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Tileset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      serial::deserialize_named(deserializer, 0x0000..=0x0007, |value| {
+        Self(value).as_str()
+      })
+      .map(Self)
+    }
+  }
+}
+
+serde_field!(Tileset);
@@ -0,0 +1,92 @@
+use storm_chk::consts;
+use storm_chk::id::TechId;
+use storm_chk::id::UpgradeId;
+use storm_chk::item::Ptec;
+use storm_chk::item::Upgr;
+use storm_chk::item::Ver;
+use storm_chk::types::Player;
+use storm_chk::types::Scenario;
+use storm_core::types::File;
+
+const P1: Player = match Player::from_u8(0) {
+  Some(player) => player,
+  None => unreachable!(),
+};
+
+const P2: Player = match Player::from_u8(1) {
+  Some(player) => player,
+  None => unreachable!(),
+};
+
+fn scenario(version: u16) -> Scenario {
+  let mut scenario: Scenario = Scenario::new(File::new(Vec::new())).unwrap();
+
+  scenario
+    .insert(consts::MAGIC_VER, Ver { version }.into())
+    .unwrap();
+  scenario
+}
+
+// A scenario with only the original sections, as written by the original game
+fn original(version: u16) -> Scenario {
+  let mut scenario: Scenario = scenario(version);
+  let mut ptec: Ptec = Ptec::default();
+  let mut upgr: Upgr = Upgr::default();
+
+  ptec.set_state(P1, TechId::Lockdown, false, true);
+  upgr.set_levels(P1, UpgradeId::U238Shells, 1, 1);
+
+  scenario.insert(consts::MAGIC_PTEC, ptec.into()).unwrap();
+  scenario.insert(consts::MAGIC_UPGR, upgr.into()).unwrap();
+  scenario
+}
+
+#[test]
+fn test_player_layer_overrides_global() {
+  let mut scenario: Scenario = scenario(Ver::V4);
+
+  scenario
+    .set_global_tech_state(TechId::Lockdown, false, true)
+    .unwrap();
+  scenario
+    .set_tech_state(P1, TechId::Lockdown, true, false)
+    .unwrap();
+
+  assert_eq!(scenario.tech_state(P1, TechId::Lockdown), (true, false));
+  assert_eq!(scenario.tech_state(P2, TechId::Lockdown), (false, true));
+
+  scenario
+    .set_global_upgrade_levels(UpgradeId::U238Shells, 1, 1)
+    .unwrap();
+  scenario
+    .set_upgrade_levels(P1, UpgradeId::U238Shells, 0, 0)
+    .unwrap();
+
+  assert_eq!(scenario.upgrade_levels(P1, UpgradeId::U238Shells), (0, 0));
+  assert_eq!(scenario.upgrade_levels(P2, UpgradeId::U238Shells), (1, 1));
+}
+
+#[test]
+fn test_missing_sections_use_defaults() {
+  let scenario: Scenario = scenario(Ver::V4);
+
+  assert_eq!(scenario.tech_state(P1, TechId::ScannerSweep), (true, true));
+  assert_eq!(scenario.tech_state(P1, TechId::Lockdown), (true, false));
+  assert_eq!(scenario.upgrade_levels(P1, UpgradeId::ProtossArmor), (0, 3));
+  assert_eq!(scenario.upgrade_levels(P1, UpgradeId::U238Shells), (0, 1));
+}
+
+#[test]
+fn test_original_sections_by_version() {
+  // Original scenarios read the original sections
+  let scenario: Scenario = original(Ver::V1);
+
+  assert_eq!(scenario.tech_state(P1, TechId::Lockdown), (false, true));
+  assert_eq!(scenario.upgrade_levels(P1, UpgradeId::U238Shells), (1, 1));
+
+  // Brood War scenarios without `PTEx` and `PUPx` use the game defaults
+  let scenario: Scenario = original(Ver::V4);
+
+  assert_eq!(scenario.tech_state(P1, TechId::Lockdown), (true, false));
+  assert_eq!(scenario.upgrade_levels(P1, UpgradeId::U238Shells), (0, 1));
+}
@@ -0,0 +1,118 @@
+use storm_chk::consts;
+use storm_chk::item::Mbrf;
+use storm_chk::item::TrigAction;
+use storm_chk::item::TrigCondition;
+use storm_chk::item::TrigData;
+use storm_chk::item::TrigFlags;
+use storm_chk::trigger::compile_briefing;
+use storm_chk::trigger::print_briefing;
+use storm_chk::trigger::BriefingAction;
+use storm_chk::trigger::Condition;
+use storm_chk::trigger::Modifier;
+use storm_chk::trigger::TriggerNames;
+use storm_chk::types::Encoding;
+use storm_chk::types::Scenario;
+use storm_chk::types::StringTable;
+use storm_core::types::File;
+
+// A briefing with every known action type, for the first player
+fn briefing() -> TrigData {
+  let actions: [BriefingAction; 9] = [
+    BriefingAction::Wait { time: 500 },
+    BriefingAction::PlayWav {
+      wav: 2,
+      duration: 1200,
+    },
+    BriefingAction::TextMessage {
+      text: 1,
+      time: 3000,
+    },
+    BriefingAction::MissionObjectives { text: 1 },
+    BriefingAction::ShowPortrait {
+      unit: 0x0A,
+      slot: 1,
+    },
+    BriefingAction::SpeakingPortrait {
+      slot: 1,
+      time: 2000,
+    },
+    BriefingAction::HidePortrait { slot: 1 },
+    BriefingAction::Transmission {
+      text: 1,
+      slot: 3,
+      modifier: Modifier::ADD,
+      amount: 1000,
+      wav: 2,
+      duration: 1200,
+    },
+    BriefingAction::SkipTutorialEnabled,
+  ];
+
+  let mut data: TrigData = TrigData {
+    conditions: [TrigCondition::default(); 0x10],
+    actions: [TrigAction::default(); 0x40],
+    execution: TrigFlags::empty(),
+    players: [0; 0x1B],
+    current: 0,
+  };
+
+  data.conditions[0] = Condition::MissionBriefing.encode();
+  data.players[0] = 1;
+
+  for (raw, action) in data.actions.iter_mut().zip(actions) {
+    *raw = action.encode();
+  }
+
+  data
+}
+
+#[test]
+fn test_briefing_opcodes() {
+  for opcode in 0..=u8::MAX {
+    let raw: TrigAction = TrigAction {
+      action: opcode,
+      ..TrigAction::default()
+    };
+
+    let action: BriefingAction = raw.decode_briefing();
+
+    assert_eq!(action.opcode(), opcode);
+    assert_eq!(action.encode().decode_briefing(), action);
+    assert_eq!(
+      matches!(action, BriefingAction::Unknown(_)),
+      BriefingAction::name_of(opcode).is_none(),
+      "{opcode:#04X}"
+    );
+  }
+
+  // Briefing actions have their own opcodes, unlike trigger actions
+  assert_eq!(BriefingAction::name_of(0x03), Some("Text Message"));
+  assert_eq!(BriefingAction::name_of(0x0A), None);
+}
+
+#[test]
+fn test_briefing_text_roundtrip() {
+  let mut scenario: Scenario = Scenario::new(File::new(Vec::new())).unwrap();
+  let mut table: StringTable = StringTable::new(Encoding::Utf8);
+
+  table.add("Welcome, Commander.").unwrap();
+  table.add("sound\\briefing.wav").unwrap();
+  table.write(&mut scenario).unwrap();
+
+  let triggers: Box<[TrigData]> = Box::new([briefing()]);
+
+  scenario
+    .insert(consts::MAGIC_MBRF, Mbrf { triggers }.into())
+    .unwrap();
+
+  let mut names: TriggerNames = TriggerNames::new(&scenario);
+  let text: String = print_briefing(scenario.briefing(), &names);
+
+  for action in briefing().actions.iter().take(9) {
+    assert!(text.contains(action.decode_briefing().name()), "{text}");
+  }
+
+  let triggers: Vec<TrigData> = compile_briefing(&text, &mut names).unwrap();
+
+  assert_eq!(triggers, [briefing()], "{text}");
+}
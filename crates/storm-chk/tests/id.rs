@@ -0,0 +1,41 @@
+use storm_chk::id::UnitId;
+
+#[test]
+fn test_expansion_units() {
+  let units: Vec<UnitId> = UnitId::ALL
+    .into_iter()
+    .filter(|unit| !unit.is_classic())
+    .collect();
+
+  assert_eq!(
+    units,
+    [
+      UnitId::Medic,
+      UnitId::Valkyrie,
+      UnitId::Corsair,
+      UnitId::DarkTemplar,
+      UnitId::Devourer,
+      UnitId::DarkArchon,
+      UnitId::Arbiter_Danimoth,
+      UnitId::Scout_Artanis,
+      UnitId::Scantid,
+      UnitId::Kakaru,
+      UnitId::Ursadon,
+      UnitId::LurkerEgg,
+      UnitId::Corsair_Raszagal,
+      UnitId::Ghost_SamirDuran,
+      UnitId::Ghost_AlexeiStukov,
+      UnitId::Battlecruiser_GerardDuGalle,
+      UnitId::Lurker,
+      UnitId::InfestedTerran_InfestedDuran,
+      UnitId::DisruptionWeb,
+      UnitId::UrajCrystal,
+      UnitId::KhalisCrystal,
+      UnitId::XelNagaTemple,
+      UnitId::WarpGate,
+      UnitId::PsiDisrupter,
+      UnitId::PowerGenerator,
+      UnitId::OvermindCocoon,
+    ]
+  );
+}
@@ -0,0 +1,157 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use storm_chk::types::ChunkList;
+use storm_chk::types::Scenario;
+use storm_core::types::File;
+
+// Known sections and the size of their content (or entries)
+const SIZED: &[(&[u8; 4], usize)] = &[
+  (b"COLR", 0x8),
+  (b"CRGB", 0x20),
+  (b"DIM ", 0x4),
+  (b"ERA ", 0x2),
+  (b"FORC", 0x14),
+  (b"IOWN", 0xC),
+  (b"IVE2", 0x2),
+  (b"IVER", 0x2),
+  (b"OWNR", 0xC),
+  (b"PTEC", 0x390),
+  (b"PTEx", 0x688),
+  (b"PUNI", 0x1644),
+  (b"PUPx", 0x90E),
+  (b"SIDE", 0xC),
+  (b"SPRP", 0x4),
+  (b"SWNM", 0x400),
+  (b"TECS", 0xD8),
+  (b"TECx", 0x18C),
+  (b"TYPE", 0x4),
+  (b"UNIS", 0xFD0),
+  (b"UNIx", 0x1048),
+  (b"UPGR", 0x6D4),
+  (b"UPGS", 0x256),
+  (b"UPGx", 0x31A),
+  (b"UPRP", 0x500),
+  (b"UPUS", 0x40),
+  (b"VCOD", 0x410),
+  (b"VER ", 0x2),
+  (b"WAV ", 0x800),
+];
+
+const BOXED: &[(&[u8; 4], usize)] = &[
+  (b"DD2 ", 0x08),
+  (b"MBRF", 0x960),
+  (b"MRGN", 0x14),
+  (b"THG2", 0x0A),
+  (b"TRIG", 0x960),
+  (b"UNIT", 0x24),
+];
+
+const DYNAMIC: &[&[u8; 4]] = &[b"ISOM", b"MASK", b"MTXM", b"TILE"];
+
+fn section(name: &[u8; 4], data: Vec<u8>) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::with_capacity(data.len() + 8);
+
+  output.extend_from_slice(name);
+  output.extend_from_slice(&(data.len() as u32).to_le_bytes());
+  output.extend_from_slice(&data);
+  output
+}
+
+fn sized() -> impl Strategy<Value = Vec<u8>> {
+  (0..SIZED.len()).prop_flat_map(|index| {
+    let (name, size) = SIZED[index];
+    vec(any::<u8>(), size).prop_map(move |data| section(name, data))
+  })
+}
+
+fn boxed() -> impl Strategy<Value = Vec<u8>> {
+  (0..BOXED.len(), 0..4_usize).prop_flat_map(|(index, count)| {
+    let (name, size) = BOXED[index];
+    vec(any::<u8>(), size * count).prop_map(move |data| section(name, data))
+  })
+}
+
+fn dynamic() -> impl Strategy<Value = Vec<u8>> {
+  (0..DYNAMIC.len(), vec(any::<u8>(), 1..64))
+    .prop_map(|(index, data)| section(DYNAMIC[index], data))
+}
+
+fn strings() -> impl Strategy<Value = Vec<u8>> {
+  (
+    any::<bool>(),
+    vec(any::<u16>(), 0..8),
+    vec(any::<u8>(), 0..64),
+  )
+    .prop_map(|(extended, offsets, content)| {
+      let mut data: Vec<u8> = Vec::new();
+
+      if extended {
+        data.extend_from_slice(&(offsets.len() as u32).to_le_bytes());
+        offsets
+          .iter()
+          .for_each(|offset| data.extend_from_slice(&u32::from(*offset).to_le_bytes()));
+      } else {
+        data.extend_from_slice(&(offsets.len() as u16).to_le_bytes());
+        offsets
+          .iter()
+          .for_each(|offset| data.extend_from_slice(&offset.to_le_bytes()));
+      }
+
+      data.extend_from_slice(&content);
+      section(if extended { b"STRx" } else { b"STR " }, data)
+    })
+}
+
+fn unknown() -> impl Strategy<Value = Vec<u8>> {
+  (prop::array::uniform4(b'a'..=b'z'), vec(any::<u8>(), 0..32))
+    .prop_map(|(name, data)| section(&name, data))
+}
+
+fn empty() -> impl Strategy<Value = Vec<u8>> {
+  (0..SIZED.len()).prop_map(|index| section(SIZED[index].0, Vec::new()))
+}
+
+fn chk() -> impl Strategy<Value = Vec<u8>> {
+  let section = prop_oneof![sized(), boxed(), dynamic(), strings(), unknown(), empty()];
+
+  vec(section, 0..12).prop_map(|sections| sections.concat())
+}
+
+proptest! {
+  #![proptest_config(ProptestConfig::with_cases(128))]
+
+  #[test]
+  fn chunk_list_roundtrip(data in chk()) {
+    let list: ChunkList = ChunkList::new(File::new(data.clone())).unwrap();
+    let bytes: Vec<u8> = list.to_bytes().unwrap();
+
+    prop_assert_eq!(&bytes, &data);
+    prop_assert_eq!(ChunkList::new(File::new(bytes)).unwrap(), list);
+  }
+
+  #[test]
+  fn scenario_roundtrip(data in chk()) {
+    let scenario: Scenario = Scenario::new(File::new(data)).unwrap();
+    let bytes: Vec<u8> = scenario.to_bytes().unwrap();
+
+    prop_assert_eq!(Scenario::new(File::new(bytes)).unwrap(), scenario);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn chunk_list_json_roundtrip(data in chk()) {
+    let list: ChunkList = ChunkList::new(File::new(data)).unwrap();
+    let json: String = serde_json::to_string(&list).unwrap();
+
+    prop_assert_eq!(serde_json::from_str::<ChunkList>(&json).unwrap(), list);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn scenario_json_roundtrip(data in chk()) {
+    let scenario: Scenario = Scenario::new(File::new(data)).unwrap();
+    let json: String = serde_json::to_string(&scenario).unwrap();
+
+    prop_assert_eq!(serde_json::from_str::<Scenario>(&json).unwrap(), scenario);
+  }
+}
@@ -0,0 +1,152 @@
+use storm_chk::consts;
+use storm_chk::item::Dim;
+use storm_chk::item::Ver;
+use storm_chk::types::Chunk;
+use storm_chk::types::Item;
+use storm_chk::types::Scenario;
+use storm_core::types::File;
+use storm_core::types::Magic;
+
+fn section(name: &[u8; 4], size: i32, data: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::with_capacity(data.len() + 8);
+
+  output.extend_from_slice(name);
+  output.extend_from_slice(&size.to_le_bytes());
+  output.extend_from_slice(data);
+  output
+}
+
+fn parse(sections: &[Vec<u8>]) -> Scenario {
+  Scenario::new(File::new(sections.concat())).unwrap()
+}
+
+fn tiles(scenario: &Scenario) -> Vec<u16> {
+  match scenario.get(consts::MAGIC_MTXM) {
+    Some(Item::Mtxm(item)) => item.tiles.to_vec(),
+    item => panic!("Expected MTXM, found {item:?}"),
+  }
+}
+
+#[test]
+fn test_later_sections_override() {
+  let scenario: Scenario = parse(&[
+    section(b"VER ", 2, &0x3B_u16.to_le_bytes()),
+    section(b"DIM ", 4, &[0x40, 0x00, 0x40, 0x00]),
+    section(b"VER ", 2, &0xCD_u16.to_le_bytes()),
+  ]);
+
+  let names: Vec<Magic> = scenario.chunks().iter().map(|chunk| chunk.name).collect();
+
+  assert_eq!(names, [consts::MAGIC_VER, consts::MAGIC_DIM]);
+  assert_eq!(
+    scenario.get(consts::MAGIC_VER),
+    Some(&Item::Ver(Ver { version: 0xCD }))
+  );
+}
+
+#[test]
+fn test_mtxm_overlay() {
+  let scenario: Scenario = parse(&[
+    section(b"MTXM", 8, &[1, 0, 2, 0, 3, 0, 4, 0]),
+    section(b"MTXM", 4, &[5, 0, 6, 0]),
+  ]);
+
+  assert_eq!(tiles(&scenario), [5, 6, 3, 4]);
+
+  // A longer overlay replaces the terrain entirely
+  let scenario: Scenario = parse(&[
+    section(b"MTXM", 4, &[1, 0, 2, 0]),
+    section(b"MTXM", 6, &[5, 0, 6, 0, 7, 0]),
+  ]);
+
+  assert_eq!(tiles(&scenario), [5, 6, 7]);
+}
+
+#[test]
+fn test_short_sections_padded() {
+  let scenario: Scenario = parse(&[section(b"DIM ", 2, &[0x80, 0x00])]);
+  let chunk: &Chunk = &scenario.chunks()[0];
+
+  assert_eq!(chunk.size, 2);
+  assert_eq!(chunk.item, Item::Dim(Dim { w: 0x80, h: 0 }));
+}
+
+#[test]
+fn test_partial_entries_truncated() {
+  let scenario: Scenario = parse(&[section(b"UNIT", 0x24 + 5, &[0xAA; 0x24 + 5])]);
+
+  assert_eq!(scenario.units().len(), 1);
+  assert_eq!(scenario.chunks()[0].size, 0x24 + 5);
+}
+
+#[test]
+fn test_sections_past_end_truncated() {
+  let scenario: Scenario = parse(&[section(b"MTXM", 0x100, &[1, 0, 2, 0])]);
+
+  assert_eq!(tiles(&scenario), [1, 2]);
+}
+
+#[test]
+fn test_negative_size_jumps_back() {
+  // Jumping back to a section already read ends the file
+  let scenario: Scenario = parse(&[
+    section(b"VER ", 2, &0x3B_u16.to_le_bytes()),
+    section(b"DIM ", 4, &[0x40, 0x00, 0x40, 0x00]),
+    section(b"JUMP", -30, &[]),
+  ]);
+
+  let names: Vec<Magic> = scenario.chunks().iter().map(|chunk| chunk.name).collect();
+
+  assert_eq!(names, [consts::MAGIC_VER, consts::MAGIC_DIM]);
+
+  // Jumping into the content of another section reads it as sections
+  let scenario: Scenario = parse(&[
+    section(b"JUNK", 12, &section(b"MTXM", 4, &[9, 0, 9, 0])),
+    section(b"HIDE", -20, &[]),
+  ]);
+
+  assert_eq!(tiles(&scenario), [9, 9]);
+
+  // Jumping before the start of the data ends the file
+  let scenario: Scenario = parse(&[
+    section(b"VER ", 2, &0x3B_u16.to_le_bytes()),
+    section(b"BACK", i32::MIN, &[]),
+    section(b"DIM ", 4, &[0x40, 0x00, 0x40, 0x00]),
+  ]);
+
+  assert_eq!(scenario.chunks().len(), 1);
+}
+
+#[test]
+fn test_edited_sections_resized() {
+  let mut scenario: Scenario = parse(&[
+    section(b"MTXM", 5, &[1, 0, 2, 0, 3]),
+    section(b"DIM ", 4, &[0x40, 0x00, 0x40, 0x00]),
+  ]);
+
+  if let Some(Item::Mtxm(item)) = scenario.get_mut(consts::MAGIC_MTXM) {
+    item.tiles = Box::new([4, 5, 6]);
+  }
+
+  assert_eq!(scenario.chunks()[0].size, Chunk::UNSIZED);
+
+  let scenario: Scenario = parse(&[scenario.to_bytes().unwrap()]);
+
+  assert_eq!(tiles(&scenario), [4, 5, 6]);
+  assert_eq!(scenario.chunks()[0].size, 6);
+}
+
+#[test]
+fn test_trailing_data_kept() {
+  let data: Vec<u8> = [
+    section(b"DIM ", 6, &[0x40, 0x00, 0x40, 0x00, 0xAB, 0xCD]),
+    section(b"UNIT", 0x24 + 5, &[0xAA; 0x24 + 5]),
+  ]
+  .concat();
+
+  let scenario: Scenario = parse(std::slice::from_ref(&data));
+
+  assert_eq!(scenario.chunks()[0].trailing.as_ref(), [0xAB, 0xCD]);
+  assert_eq!(scenario.chunks()[1].trailing.as_ref(), [0xAA; 5]);
+  assert_eq!(scenario.to_bytes().unwrap(), data);
+}
@@ -0,0 +1,201 @@
+use storm_chk::consts;
+use storm_chk::item::Sprp;
+use storm_chk::item::Str;
+use storm_chk::item::Trig;
+use storm_chk::item::TrigAction;
+use storm_chk::item::TrigCondition;
+use storm_chk::item::TrigData;
+use storm_chk::item::TrigFlags;
+use storm_chk::item::Ver;
+use storm_chk::trigger::Action;
+use storm_chk::types::Encoding;
+use storm_chk::types::Item;
+use storm_chk::types::Scenario;
+use storm_chk::types::StringRef;
+use storm_chk::types::StringTable;
+use storm_core::types::File;
+
+fn trigger(actions: &[TrigAction]) -> TrigData {
+  let mut data: TrigData = TrigData {
+    conditions: [TrigCondition::default(); 0x10],
+    actions: [TrigAction::default(); 0x40],
+    execution: TrigFlags::empty(),
+    players: [0; 0x1B],
+    current: 0,
+  };
+
+  data.actions[..actions.len()].copy_from_slice(actions);
+  data
+}
+
+// A scenario with the given strings, scenario name and trigger actions
+fn scenario(strings: &[&str], name: u16, actions: &[TrigAction]) -> (Scenario, StringTable) {
+  let mut scenario: Scenario = Scenario::new(File::new(Vec::new())).unwrap();
+  let mut table: StringTable = StringTable::new(Encoding::Utf8);
+
+  for string in strings {
+    table.add(string).unwrap();
+  }
+
+  let triggers: Box<[TrigData]> = Box::new([trigger(actions)]);
+
+  table.write(&mut scenario).unwrap();
+  scenario
+    .insert(consts::MAGIC_SPRP, Sprp { name, desc: 0 }.into())
+    .unwrap();
+  scenario
+    .insert(consts::MAGIC_TRIG, Trig { triggers }.into())
+    .unwrap();
+
+  (scenario, table)
+}
+
+// A Wait action with stale string indices, which the game never reads
+fn wait(index: u32) -> TrigAction {
+  TrigAction {
+    txt_index: index,
+    wav_index: index,
+    ..Action::Wait { time: 1000 }.encode()
+  }
+}
+
+fn name(scenario: &Scenario) -> u16 {
+  match scenario.get(consts::MAGIC_SPRP) {
+    Some(Item::Sprp(item)) => item.name,
+    item => panic!("Expected SPRP, found {item:?}"),
+  }
+}
+
+fn text(table: &StringTable, index: usize) -> Option<String> {
+  table.get(index).map(|string| string.into_string())
+}
+
+#[test]
+fn test_add_reuses_empty_slots() {
+  let mut table: StringTable = StringTable::new(Encoding::Utf8);
+
+  assert_eq!(table.add("a").unwrap(), 1);
+  assert_eq!(table.add("b").unwrap(), 2);
+  assert_eq!(table.add("c").unwrap(), 3);
+  assert!(table.add("nul\0").is_err());
+
+  assert_eq!(table.remove(2).as_deref(), Some("b"));
+  assert_eq!(table.remove(2), None);
+  assert_eq!(text(&table, 2), None);
+
+  assert_eq!(table.add("d").unwrap(), 2);
+  assert_eq!(table.len(), 3);
+  assert_eq!(text(&table, 2).as_deref(), Some("d"));
+}
+
+#[test]
+fn test_references_follow_action_types() {
+  let (scenario, _) = scenario(
+    &["a", "b", "c"],
+    1,
+    &[Action::DisplayText { text: 2 }.encode(), wait(3)],
+  );
+
+  assert_eq!(
+    StringTable::references(&scenario),
+    [
+      (StringRef::ScenarioName, 1),
+      (StringRef::TriggerText(0, 0), 2)
+    ]
+  );
+}
+
+#[test]
+fn test_compact() {
+  let (mut scenario, mut table) = scenario(
+    &["a", "b", "c", "d"],
+    3,
+    &[Action::DisplayText { text: 4 }.encode(), wait(2)],
+  );
+
+  table.compact(&mut scenario);
+
+  assert_eq!(table.len(), 2);
+  assert_eq!(text(&table, 1).as_deref(), Some("c"));
+  assert_eq!(text(&table, 2).as_deref(), Some("d"));
+  assert_eq!(name(&scenario), 1);
+
+  let actions: &[TrigAction] = &scenario.triggers()[0].actions;
+
+  assert_eq!(actions[0].decode(), Action::DisplayText { text: 2 });
+  assert_eq!(actions[1], wait(2));
+}
+
+#[test]
+fn test_deduplicate() {
+  let (mut scenario, mut table) = scenario(
+    &["a", "b", "a"],
+    3,
+    &[Action::DisplayText { text: 2 }.encode()],
+  );
+
+  table.deduplicate(&mut scenario);
+
+  assert_eq!(name(&scenario), 1);
+  assert_eq!(text(&table, 2).as_deref(), Some("b"));
+  assert_eq!(text(&table, 3), None);
+  assert_eq!(
+    scenario.triggers()[0].actions[0].decode(),
+    Action::DisplayText { text: 2 }
+  );
+}
+
+#[test]
+fn test_write_upgrades_to_strx() {
+  let (mut scenario, mut table) = scenario(&["a"], 1, &[]);
+
+  scenario
+    .insert(consts::MAGIC_VER, Ver { version: Ver::V4 }.into())
+    .unwrap();
+
+  assert!(scenario.get(consts::MAGIC_STR).is_some());
+
+  // Offsets past 16 bits don't fit in `STR `
+  table.add(&"x".repeat(StringTable::STR_LIMIT)).unwrap();
+  table.add("b").unwrap();
+  table.write(&mut scenario).unwrap();
+
+  assert!(scenario.get(consts::MAGIC_STR).is_none());
+  assert!(scenario.get(consts::MAGIC_STRX).is_some());
+  assert_eq!(scenario.version(), Some(Ver::V5));
+
+  // `STRx` is kept once used, even if the strings fit in `STR `
+  table.remove(2);
+  table.remove(3);
+  table.write(&mut scenario).unwrap();
+
+  assert!(scenario.get(consts::MAGIC_STR).is_none());
+  assert_eq!(StringTable::from_scenario(&scenario), table);
+
+  // The scenario reads back the same
+  let bytes: Vec<u8> = scenario.to_bytes().unwrap();
+
+  assert_eq!(Scenario::new(File::new(bytes)).unwrap(), scenario);
+}
+
+#[test]
+fn test_empty_slots_point_to_nul() {
+  let mut table: StringTable = StringTable::new(Encoding::Utf8);
+
+  table.add("a").unwrap();
+  table.add("b").unwrap();
+  table.remove(1);
+
+  let item: Str = table.to_str().unwrap();
+  let offset: usize = usize::from(item.offsets[0]);
+
+  assert_eq!(item.content.get(offset), Some(&0));
+  assert_eq!(item.get(1).map(|string| string.as_bytes()), Some(&b""[..]));
+
+  // Empty strings read back as empty slots
+  let mut scenario: Scenario = Scenario::new(File::new(Vec::new())).unwrap();
+
+  table.write(&mut scenario).unwrap();
+
+  assert_eq!(StringTable::from_scenario(&scenario), table);
+}
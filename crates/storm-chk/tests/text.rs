@@ -0,0 +1,113 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use storm_chk::item::Mbrf;
+use storm_chk::item::Trig;
+use storm_chk::item::TrigData;
+use storm_chk::parse::WriteChunk;
+use storm_chk::trigger::compile_briefing;
+use storm_chk::trigger::compile_triggers;
+use storm_chk::trigger::print_briefing;
+use storm_chk::trigger::print_triggers;
+use storm_chk::trigger::TriggerNames;
+use storm_chk::types::Scenario;
+use storm_core::types::File;
+
+const TRIGGER_SIZE: usize = 0x960;
+
+fn section(name: &[u8; 4], data: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::with_capacity(data.len() + 8);
+
+  output.extend_from_slice(name);
+  output.extend_from_slice(&(data.len() as u32).to_le_bytes());
+  output.extend_from_slice(data);
+  output
+}
+
+// A `STR ` section with a few short strings, some of them duplicates
+fn strings() -> impl Strategy<Value = Vec<u8>> {
+  vec("[a-z ]{0,4}", 0..6).prop_map(|strings| {
+    let cursor: usize = 2 + strings.len() * 2;
+    let mut offsets: Vec<u8> = Vec::new();
+    let mut content: Vec<u8> = Vec::new();
+
+    for string in strings.iter() {
+      offsets.extend_from_slice(&((cursor + content.len()) as u16).to_le_bytes());
+      content.extend_from_slice(string.as_bytes());
+      content.push(0);
+    }
+
+    let mut data: Vec<u8> = (strings.len() as u16).to_le_bytes().to_vec();
+
+    data.extend_from_slice(&offsets);
+    data.extend_from_slice(&content);
+
+    section(b"STR ", &data)
+  })
+}
+
+// Random triggers, with action and condition types biased towards known ones
+fn triggers() -> impl Strategy<Value = Vec<u8>> {
+  (1..4_usize)
+    .prop_flat_map(|count| vec(any::<u8>(), TRIGGER_SIZE * count))
+    .prop_flat_map(|data| {
+      let count: usize = data.len() / TRIGGER_SIZE;
+      let kinds = vec(0..0x40_u8, count * (0x10 + 0x40));
+
+      (Just(data), kinds)
+    })
+    .prop_map(|(mut data, kinds)| {
+      let mut kinds = kinds.into_iter();
+
+      for trigger in data.chunks_mut(TRIGGER_SIZE) {
+        for condition in trigger[..0x140].chunks_mut(0x14) {
+          condition[0x0F] = kinds.next().unwrap() % 0x18;
+        }
+
+        for action in trigger[0x140..0x940].chunks_mut(0x20) {
+          action[0x1A] = kinds.next().unwrap();
+        }
+      }
+
+      data
+    })
+}
+
+fn scenario(strings: &[u8], name: &[u8; 4], triggers: &[u8]) -> Scenario {
+  let data: Vec<u8> = [strings, &section(name, triggers)].concat();
+
+  Scenario::new(File::new(data)).unwrap()
+}
+
+proptest! {
+  #![proptest_config(ProptestConfig::with_cases(64))]
+
+  #[test]
+  fn triggers_text_roundtrip(strings in strings(), data in triggers()) {
+    let scenario: Scenario = scenario(&strings, b"TRIG", &data);
+    let mut names: TriggerNames = TriggerNames::new(&scenario);
+
+    let text: String = print_triggers(scenario.triggers(), &names);
+    let triggers: Vec<TrigData> = compile_triggers(&text, &mut names).unwrap();
+
+    let item: Trig = Trig {
+      triggers: triggers.into_boxed_slice(),
+    };
+
+    prop_assert_eq!(item.to_bytes().unwrap(), data, "{}", text);
+  }
+
+  #[test]
+  fn briefing_text_roundtrip(strings in strings(), data in triggers()) {
+    let scenario: Scenario = scenario(&strings, b"MBRF", &data);
+    let mut names: TriggerNames = TriggerNames::new(&scenario);
+
+    let text: String = print_briefing(scenario.briefing(), &names);
+    let triggers: Vec<TrigData> = compile_briefing(&text, &mut names).unwrap();
+
+    let item: Mbrf = Mbrf {
+      triggers: triggers.into_boxed_slice(),
+    };
+
+    prop_assert_eq!(item.to_bytes().unwrap(), data, "{}", text);
+  }
+}
@@ -0,0 +1,165 @@
+use proptest::prelude::*;
+use storm_chk::item::TrigAction;
+use storm_chk::item::TrigActionFlags;
+use storm_chk::item::TrigCondition;
+use storm_chk::item::TrigConditionFlags;
+use storm_chk::trigger::Action;
+use storm_chk::trigger::Alliance;
+use storm_chk::trigger::Comparison;
+use storm_chk::trigger::Condition;
+use storm_chk::trigger::Group;
+use storm_chk::trigger::Modifier;
+use storm_chk::trigger::Order;
+use storm_chk::trigger::Resource;
+use storm_chk::trigger::Score;
+use storm_chk::trigger::StateAction;
+use storm_chk::trigger::SwitchAction;
+use storm_chk::trigger::SwitchState;
+
+// Random raw actions, with every opcode and any flags
+fn actions() -> impl Strategy<Value = TrigAction> {
+  (
+    any::<[u32; 6]>(),
+    any::<u16>(),
+    any::<[u8; 4]>(),
+    any::<u16>(),
+  )
+    .prop_map(|(values, kind, bytes, mask)| TrigAction {
+      location: values[0],
+      txt_index: values[1],
+      wav_index: values[2],
+      time: values[3],
+      group: values[4],
+      state: values[5],
+      kind,
+      action: bytes[0],
+      units: bytes[1],
+      flags: TrigActionFlags::from_bits_retain(bytes[2]),
+      _padding: bytes[3],
+      mask,
+    })
+}
+
+// Random raw conditions, with every opcode and any flags
+fn conditions() -> impl Strategy<Value = TrigCondition> {
+  (
+    any::<[u32; 3]>(),
+    any::<u16>(),
+    any::<[u8; 4]>(),
+    any::<u16>(),
+  )
+    .prop_map(|(values, unit_id, bytes, mask)| TrigCondition {
+      location: values[0],
+      group: values[1],
+      count: values[2],
+      unit_id,
+      state: bytes[0],
+      condition: bytes[1],
+      kind: bytes[2],
+      flags: TrigConditionFlags::from_bits_retain(bytes[3]),
+      mask,
+    })
+}
+
+#[test]
+fn test_action_opcodes() {
+  for opcode in 0..=u8::MAX {
+    let raw: TrigAction = TrigAction {
+      action: opcode,
+      ..TrigAction::default()
+    };
+
+    let action: Action = Action::decode(&raw);
+
+    assert_eq!(action.opcode(), opcode);
+    assert_eq!(Action::decode(&action.encode()), action);
+    assert_eq!(
+      matches!(action, Action::Unknown(_)),
+      Action::name_of(opcode).is_none(),
+      "{opcode:#04X}"
+    );
+  }
+}
+
+#[test]
+fn test_condition_opcodes() {
+  for opcode in 0..=u8::MAX {
+    let raw: TrigCondition = TrigCondition {
+      condition: opcode,
+      ..TrigCondition::default()
+    };
+
+    let condition: Condition = Condition::decode(&raw);
+
+    assert_eq!(condition.opcode(), opcode);
+    assert_eq!(Condition::decode(&condition.encode()), condition);
+    assert_eq!(
+      matches!(condition, Condition::Unknown(_)),
+      Condition::name_of(opcode).is_none(),
+      "{opcode:#04X}"
+    );
+  }
+}
+
+#[test]
+fn test_args_roundtrip() {
+  for value in 0..=u8::MAX {
+    assert_eq!(Comparison::from_u8(value).as_u8(), value);
+    assert_eq!(Modifier::from_u8(value).as_u8(), value);
+    assert_eq!(SwitchState::from_u8(value).as_u8(), value);
+    assert_eq!(SwitchAction::from_u8(value).as_u8(), value);
+    assert_eq!(StateAction::from_u8(value).as_u8(), value);
+    assert_eq!(Order::from_u8(value).as_u8(), value);
+  }
+
+  for value in 0..=u16::MAX {
+    assert_eq!(Resource::from_u16(value).as_u16(), value);
+    assert_eq!(Score::from_u16(value).as_u16(), value);
+    assert_eq!(Alliance::from_u16(value).as_u16(), value);
+  }
+
+  for value in [0, 0x0B, 0x0C, 0x1A, 0x1B, u32::MAX] {
+    assert_eq!(Group::from_u32(value).as_u32(), value);
+  }
+
+  assert_eq!(Group::player(7), Group::from_u32(7));
+  assert_eq!(Group::force(0).to_string(), "Force 1");
+  assert_eq!(
+    Group::NON_AV_PLAYERS.to_string(),
+    "Non Allied Victory Players"
+  );
+  assert_eq!(Comparison::EXACTLY.to_string(), "Exactly");
+  assert_eq!(Comparison::from_u8(2).to_string(), "Unknown");
+}
+
+proptest! {
+  #![proptest_config(ProptestConfig::with_cases(1024))]
+
+  #[test]
+  fn action_roundtrip(raw in actions()) {
+    let action: Action = Action::decode(&raw);
+    let encoded: TrigAction = action.encode();
+
+    prop_assert_eq!(Action::decode(&encoded), action);
+    prop_assert_eq!(Action::decode(&encoded).encode(), encoded);
+
+    // Unknown actions keep every field, flags included
+    if let Action::Unknown(_) = action {
+      prop_assert_eq!(encoded, raw);
+    }
+  }
+
+  #[test]
+  fn condition_roundtrip(raw in conditions()) {
+    let condition: Condition = Condition::decode(&raw);
+    let encoded: TrigCondition = condition.encode();
+
+    prop_assert_eq!(Condition::decode(&encoded), condition);
+    prop_assert_eq!(Condition::decode(&encoded).encode(), encoded);
+
+    // Unknown conditions keep every field, flags included
+    if let Condition::Unknown(_) = condition {
+      prop_assert_eq!(encoded, raw);
+    }
+  }
+}
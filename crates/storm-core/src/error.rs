@@ -2,6 +2,8 @@ use std::error::Error as StdError;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::path::Path;
+use std::path::PathBuf;
 
 use crate::utils::CompressionFormat;
 
@@ -11,6 +13,7 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 pub struct Error {
   kind: ErrorKind,
   from: ErrorSource,
+  context: Option<Box<ErrorContext>>,
 }
 
 impl Error {
@@ -20,6 +23,7 @@ impl Error {
     Self {
       kind,
       from: ErrorSource::Ignore,
+      context: None,
     }
   }
 
@@ -29,6 +33,7 @@ impl Error {
     Self {
       kind,
       from: ErrorSource::Source(Box::new(source)),
+      context: None,
     }
   }
 
@@ -38,6 +43,7 @@ impl Error {
     Self {
       kind: ErrorKind::Other,
       from: ErrorSource::String(message.to_string()),
+      context: None,
     }
   }
 
@@ -46,20 +52,74 @@ impl Error {
   pub const fn kind(&self) -> ErrorKind {
     self.kind
   }
-}
 
-impl Display for Error {
-  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+  /// Returns the context in which the error occurred, if known.
+  #[inline]
+  pub fn context(&self) -> Option<&ErrorContext> {
+    self.context.as_deref()
+  }
+
+  /// Set the path of the archive, unless already known.
+  #[inline]
+  pub fn with_path(mut self, path: &Path) -> Self {
+    self
+      .context_mut()
+      .path
+      .get_or_insert_with(|| path.to_owned());
+    self
+  }
+
+  /// Set the name of the file in the archive, unless already known.
+  #[inline]
+  pub fn with_file(mut self, file: &str) -> Self {
+    self
+      .context_mut()
+      .file
+      .get_or_insert_with(|| file.to_owned());
+    self
+  }
+
+  /// Set the block table index, unless already known.
+  #[inline]
+  pub fn with_block(mut self, block: u32) -> Self {
+    self.context_mut().block.get_or_insert(block);
+    self
+  }
+
+  /// Set the (0-based) sector index, unless already known.
+  #[inline]
+  pub fn with_sector(mut self, sector: u32) -> Self {
+    self.context_mut().sector.get_or_insert(sector);
+    self
+  }
+
+  /// Set the byte offset in the archive file (absolute), unless already known.
+  #[inline]
+  pub fn with_offset(mut self, offset: u64) -> Self {
+    self.context_mut().offset.get_or_insert(offset);
+    self
+  }
+
+  #[inline]
+  fn context_mut(&mut self) -> &mut ErrorContext {
+    self.context.get_or_insert_with(Default::default)
+  }
+
+  fn fmt_kind(&self, f: &mut Formatter<'_>) -> FmtResult {
     match self.kind {
       ErrorKind::InvalidIO => write!(f, "i/o error: {}", self.from),
       ErrorKind::InvalidUtf8 => write!(f, "invalid utf8: {}", self.from),
       ErrorKind::InvalidMagic => write!(f, "invalid magic signature"),
-      ErrorKind::InvalidLen(name) => write!(f, "invalid md5 for {name}"),
-      ErrorKind::InvalidMd5(name) => write!(f, "invalid len for {name}"),
+      ErrorKind::InvalidLen(name) => write!(f, "invalid len for {name}"),
+      ErrorKind::InvalidMd5(name) => write!(f, "invalid md5 for {name}"),
       ErrorKind::FileInvalidSize => write!(f, "file invalid: bad size"),
       ErrorKind::FileInvalidType => write!(f, "file invalid: bad type"),
       ErrorKind::FileCorruptData => write!(f, "file corrupted/unreadable"),
       ErrorKind::FileDataMissing => write!(f, "file not found"),
+      ErrorKind::LimitFileSize => write!(f, "limit exceeded: file size"),
+      ErrorKind::LimitTotalSize => write!(f, "limit exceeded: total size"),
+      ErrorKind::LimitRatio => write!(f, "limit exceeded: compression ratio"),
+      ErrorKind::LimitSectorCount => write!(f, "limit exceeded: sector count"),
       ErrorKind::DecompressionInvalid(mode) => {
         write!(f, "invalid decompression algorithm: {mode:#04X}")
       }
@@ -80,6 +140,7 @@ impl Display for Error {
       ErrorKind::DecompressionStatus(status) => {
         write!(f, "decompression failed: {status}")
       }
+      ErrorKind::Cancelled => write!(f, "operation cancelled"),
       ErrorKind::Other => {
         write!(f, "{}", self.from)
       }
@@ -87,6 +148,18 @@ impl Display for Error {
   }
 }
 
+impl Display for Error {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    self.fmt_kind(f)?;
+
+    if let Some(ref context) = self.context {
+      write!(f, " ({context})")?;
+    }
+
+    Ok(())
+  }
+}
+
 impl StdError for Error {
   #[inline]
   fn source(&self) -> Option<&(dyn StdError + 'static)> {
@@ -140,6 +213,13 @@ pub enum ErrorKind {
   FileCorruptData,
   FileDataMissing,
   // ===========================================================================
+  // Limit Errors
+  // ===========================================================================
+  LimitFileSize,
+  LimitTotalSize,
+  LimitRatio,
+  LimitSectorCount,
+  // ===========================================================================
   // Decompression Errors
   // ===========================================================================
   DecompressionInvalid(u8),
@@ -150,9 +230,61 @@ pub enum ErrorKind {
   // ===========================================================================
   // Misc.
   // ===========================================================================
+  Cancelled,
   Other,
 }
 
+// =============================================================================
+// Error Context
+// =============================================================================
+
+/// Where an [`Error`] occurred.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ErrorContext {
+  /// Path of the archive file.
+  pub path: Option<PathBuf>,
+  /// Name of the file in the archive.
+  pub file: Option<String>,
+  /// Index of the block table entry.
+  pub block: Option<u32>,
+  /// Index of the sector in the file.
+  pub sector: Option<u32>,
+  /// Byte offset in the archive file (absolute).
+  pub offset: Option<u64>,
+}
+
+impl Display for ErrorContext {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let mut prefix: &str = "";
+
+    if let Some(ref path) = self.path {
+      write!(f, "{prefix}archive: {}", path.display())?;
+      prefix = ", ";
+    }
+
+    if let Some(ref file) = self.file {
+      write!(f, "{prefix}file: {file:?}")?;
+      prefix = ", ";
+    }
+
+    if let Some(block) = self.block {
+      write!(f, "{prefix}block: {block}")?;
+      prefix = ", ";
+    }
+
+    if let Some(sector) = self.sector {
+      write!(f, "{prefix}sector: {sector}")?;
+      prefix = ", ";
+    }
+
+    if let Some(offset) = self.offset {
+      write!(f, "{prefix}offset: {offset:#X}")?;
+    }
+
+    Ok(())
+  }
+}
+
 #[derive(Debug)]
 enum ErrorSource {
   Ignore,
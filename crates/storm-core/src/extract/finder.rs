@@ -9,6 +9,10 @@ use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
 use crate::extract;
+use crate::extract::FileInfo;
+use crate::parse::read_archive_from;
+use crate::parse::Handle;
+use crate::parse::OpenOptions;
 use crate::types::Archive;
 use crate::types::BTable;
 use crate::types::BTableEntry;
@@ -18,38 +22,49 @@ use crate::types::HTableEntry;
 use crate::types::Header;
 use crate::utils;
 use crate::utils::HashType;
+use crate::utils::NameHash;
 
 /// Search the archive for a file matching the given `query`.
 pub fn find_file<'a, Q>(archive: &'a Archive, query: Q) -> Result<FilePtr<'a>>
 where
   Q: Into<Query<'a>>,
 {
-  if let Some(file) = search(archive, query.into()) {
+  let query: Query<'a> = query.into();
+
+  check_file(archive, query, search(archive, query))
+}
+
+// Check the result of a search for the file matching `query`
+pub(crate) fn check_file<'a>(
+  archive: &'a Archive,
+  query: Query<'a>,
+  found: Option<FilePtr<'a>>,
+) -> Result<FilePtr<'a>> {
+  if let Some(file) = found {
     // Check if the file really exists
     if file.btentry.is_exists() {
       if is_impossibly_large(archive, &file) {
-        return Err(Error::new(ErrorKind::FileCorruptData));
+        return Err(file.error(ErrorKind::FileCorruptData));
       }
 
       return Ok(file);
     }
   }
 
-  Err(Error::new(ErrorKind::FileDataMissing))
+  Err(
+    Error::new(ErrorKind::FileDataMissing)
+      .with_file(query.filename)
+      .with_path(archive.handle.path()),
+  )
 }
 
 fn search<'a>(archive: &'a Archive, query: Query<'a>) -> Option<FilePtr<'a>> {
   let htable: &HTable = archive.htable();
-  let btable: &BTable = archive.btable();
 
   // First check the hash table
   if !htable.is_empty() {
     if let Some(entry) = search_htable(archive, query) {
-      return Some(FilePtr {
-        query,
-        archive,
-        btentry: &btable[entry.position as usize],
-      });
+      return Some(FilePtr::new(archive, query, entry));
     }
   }
 
@@ -63,14 +78,12 @@ fn search_htable<'a>(archive: &'a Archive, query: Query<'_>) -> Option<&'a HTabl
   let header: &Header = archive.header();
   let htable: &HTable = archive.htable();
 
-  let hash1: u32 = utils::hash(query.filename, HashType::NameA);
-  let hash2: u32 = utils::hash(query.filename, HashType::NameB);
-  let index: u32 = utils::hash(query.filename, HashType::Table);
-  let start: usize = index as usize & (htable.len() - 1);
+  let hashes: NameHash = NameHash::new(query.filename);
+  let start: usize = start_slot(hashes.table, htable.len());
 
   for entry in htable[start..].iter().chain(htable[..start].iter()) {
     // Check both hashes and block position for a matching entry
-    if is_match(header, entry, hash1, hash2) {
+    if is_match(header, entry, hashes.hash1, hashes.hash2) {
       // Check if the entry matches language and platform, only if values given
       if query.is_exact(entry) {
         return Some(entry);
@@ -91,7 +104,16 @@ fn search_htable<'a>(archive: &'a Archive, query: Query<'_>) -> Option<&'a HTabl
   best
 }
 
-const fn is_match(header: &Header, entry: &HTableEntry, hash1: u32, hash2: u32) -> bool {
+// Returns the slot a search for a name with the given table `hash` starts at
+//
+// Note: Tables that are not a power of two (e.g. protected archives) are
+//       searched the same way, like the game does.
+#[inline]
+pub(crate) const fn start_slot(hash: u32, length: usize) -> usize {
+  hash as usize & (length - 1)
+}
+
+pub(crate) const fn is_match(header: &Header, entry: &HTableEntry, hash1: u32, hash2: u32) -> bool {
   if entry.position & BT_MASK >= header.v1().btable_entries {
     return false;
   }
@@ -114,10 +136,77 @@ fn is_impossibly_large(archive: &Archive, file: &FilePtr<'_>) -> bool {
 pub struct FilePtr<'a> {
   pub(crate) query: Query<'a>,
   pub(crate) archive: &'a Archive,
+  pub(crate) block: u32,
+  pub(crate) htentry: &'a HTableEntry,
   pub(crate) btentry: &'a BTableEntry,
 }
 
-impl FilePtr<'_> {
+impl<'a> FilePtr<'a> {
+  // Create a pointer to the block referenced by the hash table `entry`
+  #[inline]
+  pub(crate) fn new(archive: &'a Archive, query: Query<'a>, entry: &'a HTableEntry) -> Self {
+    let btable: &'a BTable = archive.btable();
+    let block: u32 = entry.position & BT_MASK;
+
+    Self {
+      query,
+      archive,
+      block,
+      htentry: entry,
+      btentry: &btable[block as usize],
+    }
+  }
+
+  /// Returns the name of the file.
+  #[inline]
+  pub const fn name(&self) -> &'a str {
+    self.query.filename
+  }
+
+  /// Returns the index of the file in the block table.
+  #[inline]
+  pub const fn block(&self) -> u32 {
+    self.block
+  }
+
+  /// Returns the block table entry of the file.
+  #[inline]
+  pub const fn btentry(&self) -> &'a BTableEntry {
+    self.btentry
+  }
+
+  /// Returns detailed metadata of the file.
+  ///
+  /// This reads the sector offset table, the first bytes of each sector and
+  /// the `(attributes)` file (if any, once per archive), but not the file data
+  /// itself.
+  #[inline]
+  pub fn info(&self) -> Result<FileInfo> {
+    FileInfo::new(self)
+  }
+
+  /// Parse the file as an archive nested in this one.
+  ///
+  /// Stored (uncompressed and unencrypted) files are read in place; others are
+  /// extracted to memory first.
+  pub fn open_archive(&self) -> Result<Archive> {
+    let entry: &BTableEntry = self.btentry;
+
+    let handle: Handle =
+      if entry.is_any_compression() || entry.is_encrypted() || entry.is_patch_file() {
+        Handle::with_bytes(self.archive.handle.path(), self.read()?.into_vec())
+      } else {
+        Handle::with_window(
+          &self.archive.handle,
+          self.offset(),
+          u64::from(entry.file_size),
+        )
+      }
+      .map_err(|error| self.context(error))?;
+
+    read_archive_from(handle, &OpenOptions::new()).map_err(|error| self.context(error))
+  }
+
   /// Read file data represented by this pointer.
   #[inline]
   pub fn read(self) -> Result<File> {
@@ -130,6 +219,20 @@ impl FilePtr<'_> {
     self.archive.offset + u64::from(self.btentry.offset)
   }
 
+  /// Create an error of the given `kind` with the context of this file.
+  #[inline]
+  pub(crate) fn error(&self, kind: ErrorKind) -> Error {
+    self.context(Error::new(kind))
+  }
+
+  /// Add the context of this file to the given `error`.
+  pub(crate) fn context(&self, error: Error) -> Error {
+    error
+      .with_file(self.query.filename)
+      .with_block(self.block)
+      .with_path(self.archive.handle.path())
+  }
+
   /// Computes the encryption key of the file.
   pub fn encryption_key(&self) -> u32 {
     let mut key: u32 = 0;
@@ -158,6 +261,8 @@ impl Debug for FilePtr<'_> {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     f.debug_struct("FilePtr")
       .field("query", &self.query)
+      .field("block", &self.block)
+      .field("htentry", &self.htentry)
       .field("btentry", &self.btentry)
       .finish_non_exhaustive()
   }
@@ -195,8 +300,14 @@ impl<'a> Query<'a> {
     self.platform = NonZeroU8::new(platform);
   }
 
+  /// Returns the file name to search for.
+  #[inline]
+  pub const fn filename(&self) -> &'a str {
+    self.filename
+  }
+
   #[inline]
-  const fn is_exact(&self, entry: &HTableEntry) -> bool {
+  pub(crate) const fn is_exact(&self, entry: &HTableEntry) -> bool {
     if let (Some(language), Some(platform)) = (self.language, self.platform) {
       entry.language == language.get() && entry.platform == platform.get()
     } else {
@@ -205,7 +316,7 @@ impl<'a> Query<'a> {
   }
 
   #[inline]
-  const fn is_language(&self, entry: &HTableEntry) -> bool {
+  pub(crate) const fn is_language(&self, entry: &HTableEntry) -> bool {
     if let Some(language) = self.language {
       entry.language == language.get()
     } else {
@@ -214,7 +325,7 @@ impl<'a> Query<'a> {
   }
 
   #[inline]
-  fn is_platform(&self, entry: &HTableEntry) -> bool {
+  pub(crate) fn is_platform(&self, entry: &HTableEntry) -> bool {
     if let Some(platform) = self.platform {
       entry.platform == platform.get()
     } else {
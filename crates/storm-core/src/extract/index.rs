@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use storm_utils::traits::Parse;
+use storm_utils::traits::ReadExt;
+use storm_utils::traits::SeekExt;
+
+use crate::error::Result;
+use crate::extract::finder::check_file;
+use crate::extract::finder::is_match;
+use crate::extract::finder::start_slot;
+use crate::extract::FilePtr;
+use crate::extract::Query;
+use crate::parse::Handle;
+use crate::traits::ExtTable;
+use crate::traits::ExtTableHeader;
+use crate::types::Archive;
+use crate::types::BTableEntry;
+use crate::types::ExtBTable;
+use crate::types::ExtHTable;
+use crate::types::HTable;
+use crate::types::HTableEntry;
+use crate::types::Header;
+use crate::types::HeaderV3;
+use crate::utils;
+use crate::utils::hash_jenkins;
+use crate::utils::NameHash;
+
+// =============================================================================
+// Name Index
+// =============================================================================
+
+/// A precomputed index of the hash table (and HET table) for fast repeated
+/// lookups.
+///
+/// Hash table lookups have exactly the same semantics as
+/// [`Archive::find_file`], including locale/platform preference and searches
+/// ending at entries that have always been empty.
+///
+/// Names not found in the hash table are looked up in the HET table of V3+
+/// archives (if any). HET entries have no locale or platform; they're only
+/// found by queries without a language or platform. The BET index of an entry
+/// is the index of its block table entry.
+#[derive(Debug)]
+pub struct NameIndex<'a> {
+  archive: &'a Archive,
+  // Slots (ascending) of the entries with the given name hashes
+  entries: HashMap<(u32, u32), Vec<u32>>,
+  // Number of "empty forever" entries before each slot
+  forever: Vec<u32>,
+  // HET/BET lookup data (if any)
+  extended: Option<ExtIndex>,
+}
+
+impl<'a> NameIndex<'a> {
+  /// Build the index of the given `archive`.
+  pub fn new(archive: &'a Archive) -> Self {
+    let htable: &HTable = archive.htable();
+
+    let mut entries: HashMap<(u32, u32), Vec<u32>> = HashMap::new();
+    let mut forever: Vec<u32> = Vec::with_capacity(htable.len() + 1);
+    let mut count: u32 = 0;
+
+    for (slot, entry) in htable.iter().enumerate() {
+      forever.push(count);
+
+      if entry.position == HTableEntry::EMPTY_FOREVER {
+        count += 1;
+      }
+
+      entries
+        .entry((entry.hash1, entry.hash2))
+        .or_default()
+        .push(slot as u32);
+    }
+
+    forever.push(count);
+
+    Self {
+      archive,
+      entries,
+      forever,
+      extended: ExtIndex::new(archive),
+    }
+  }
+
+  /// Returns the indexed archive.
+  #[inline]
+  pub const fn archive(&self) -> &'a Archive {
+    self.archive
+  }
+
+  /// Search the archive for a file matching the given `query`.
+  #[inline]
+  pub fn find_file<Q>(&self, query: Q) -> Result<FilePtr<'a>>
+  where
+    Q: Into<Query<'a>>,
+  {
+    let query: Query<'a> = query.into();
+
+    self.find_hashed(query, &NameHash::new(query.filename()))
+  }
+
+  /// Search the archive for a file matching the given `query`, with the name
+  /// already hashed (see [`NameHash::batch`]).
+  pub fn find_hashed(&self, query: Query<'a>, hashes: &NameHash) -> Result<FilePtr<'a>> {
+    let found: Option<FilePtr<'a>> = self
+      .search(query, hashes)
+      .map(|entry| FilePtr::new(self.archive, query, entry))
+      .or_else(|| self.search_het(query));
+
+    check_file(self.archive, query, found)
+  }
+
+  fn search(&self, query: Query<'a>, hashes: &NameHash) -> Option<&'a HTableEntry> {
+    let header: &Header = self.archive.header();
+    let htable: &'a HTable = self.archive.htable();
+
+    if htable.is_empty() {
+      return None;
+    }
+
+    let slots: &[u32] = self.entries.get(&(hashes.hash1, hashes.hash2))?;
+    let start: usize = start_slot(hashes.table, htable.len());
+
+    // Visit the entries in probe order
+    let mut slots: Vec<usize> = slots.iter().map(|slot| *slot as usize).collect();
+
+    slots.sort_by_key(|slot| (slot + htable.len() - start) % htable.len());
+
+    // Keep a reference to the best possible candidate
+    let mut best: Option<&'a HTableEntry> = None;
+
+    for slot in slots {
+      // The search ends at entries that have always been empty
+      if !self.is_reachable(start, slot) {
+        break;
+      }
+
+      let entry: &'a HTableEntry = &htable[slot];
+
+      if is_match(header, entry, hashes.hash1, hashes.hash2) {
+        // Check if the entry matches language and platform, only if values given
+        if query.is_exact(entry) {
+          return Some(entry);
+        }
+
+        // Check if the entry matches language and platform
+        if query.is_language(entry) && query.is_platform(entry) {
+          best = Some(entry);
+        }
+      }
+    }
+
+    best
+  }
+
+  fn search_het(&self, query: Query<'a>) -> Option<FilePtr<'a>> {
+    let extended: &ExtIndex = self.extended.as_ref()?;
+
+    if !query.is_language(&HET_ENTRY) || !query.is_platform(&HET_ENTRY) {
+      return None;
+    }
+
+    let block: u32 = extended.search(hash_jenkins(query.filename()))?;
+    let btentry: &'a BTableEntry = self.archive.btable().get(block as usize)?;
+
+    Some(FilePtr {
+      query,
+      archive: self.archive,
+      block,
+      htentry: &HET_ENTRY,
+      btentry,
+    })
+  }
+
+  // Returns `true` if no "empty forever" entry is found probing from `start`
+  // to (excluding) `slot`
+  fn is_reachable(&self, start: usize, slot: usize) -> bool {
+    let count: u32 = if slot >= start {
+      self.forever[slot] - self.forever[start]
+    } else {
+      let size: usize = self.forever.len() - 1;
+
+      (self.forever[size] - self.forever[start]) + self.forever[slot]
+    };
+
+    count == 0
+  }
+}
+
+// =============================================================================
+// Extended Table Index
+// =============================================================================
+
+// Hash table entry standing in for files found in the HET table
+static HET_ENTRY: HTableEntry = HTableEntry {
+  hash1: 0,
+  hash2: 0,
+  language: 0,
+  platform: 0,
+  _padding: 0,
+  position: 0,
+};
+
+// Marks HET slots that have always been empty
+const HET_FREE: u8 = 0x00;
+
+#[derive(Debug)]
+struct ExtIndex {
+  // Size of the full name hash (bits)
+  hash_bits: u32,
+  // The top 8 bits of the name hash, per HET slot
+  hashes: Vec<u8>,
+  // BET index, per HET slot
+  indices: Vec<u32>,
+  // The name hash without the top 8 bits, per BET entry
+  hashes2: Vec<u64>,
+}
+
+impl ExtIndex {
+  // Read the HET and BET tables; `None` if missing, compressed or damaged
+  fn new(archive: &Archive) -> Option<Self> {
+    let header: &HeaderV3 = archive.header().v3()?;
+
+    if header.het_table_position == 0 || header.bet_table_position == 0 {
+      return None;
+    }
+
+    let (het, data): (ExtHTable, Vec<u8>) = read_table(archive, header.het_table_position)?;
+    let hashes: &[u8] = data.get(ExtHTable::SIZE..)?;
+    let hashes: &[u8] = hashes.get(..het.total_count as usize)?;
+    let indices: &[u8] = &data[ExtHTable::SIZE + hashes.len()..];
+
+    if !(8..=64).contains(&het.name_hash_bit_size) || het.index_size > 32 || hashes.is_empty() {
+      return None;
+    }
+
+    let indices: Vec<u32> = (0..u64::from(het.total_count))
+      .map(|slot| {
+        read_bits(
+          indices,
+          slot * u64::from(het.index_size_total),
+          het.index_size,
+        )
+      })
+      .map(|index| index.map(|index| index as u32))
+      .collect::<Option<_>>()?;
+
+    let (bet, data): (ExtBTable, Vec<u8>) = read_table(archive, header.bet_table_position)?;
+    let table_size: u64 = (u64::from(bet.entry_count) * u64::from(bet.entry_size)).div_ceil(8);
+    let offset: u64 = ExtBTable::SIZE as u64 + u64::from(bet.flag_count) * 4 + table_size;
+    let hashes2: &[u8] = data.get(usize::try_from(offset).ok()?..)?;
+
+    if bet.bc_name_hash_2 > 64 {
+      return None;
+    }
+
+    let hashes2: Vec<u64> = (0..u64::from(bet.entry_count))
+      .map(|index| {
+        let position: u64 = index * u64::from(bet.bt_name_hash_2);
+
+        read_bits(hashes2, position, bet.bc_name_hash_2)
+      })
+      .collect::<Option<_>>()?;
+
+    Some(Self {
+      hash_bits: het.name_hash_bit_size,
+      hashes: hashes.to_vec(),
+      indices,
+      hashes2,
+    })
+  }
+
+  // Returns the BET index of the name with the given (jenkins) `hash`
+  fn search(&self, hash: u64) -> Option<u32> {
+    let bits: u32 = self.hash_bits;
+    let mask: u64 = u64::MAX >> (64 - bits);
+    let hash: u64 = (hash & mask) | (1 << (bits - 1));
+
+    let hash1: u8 = (hash >> (bits - 8)) as u8;
+    let hash2: u64 = hash & (mask >> 8);
+    let start: usize = (hash % self.hashes.len() as u64) as usize;
+
+    for slot in (start..self.hashes.len()).chain(0..start) {
+      if self.hashes[slot] == HET_FREE {
+        break;
+      }
+
+      if self.hashes[slot] == hash1 {
+        let index: u32 = self.indices[slot];
+
+        if self.hashes2.get(index as usize) == Some(&hash2) {
+          return Some(index);
+        }
+      }
+    }
+
+    None
+  }
+}
+
+// Read and decrypt an (uncompressed) extended table at `position`, returning
+// the table and its data (including the table header)
+fn read_table<T: ExtTable>(archive: &Archive, position: u64) -> Option<(T, Vec<u8>)> {
+  let mut reader: Handle = archive.handle.duplicate_exact().ok()?;
+  let mut header: [u8; 0x0C] = [0; 0x0C];
+
+  let position: u64 = archive.offset + position;
+
+  reader.seek_start(position).ok()?;
+  reader.read_bytes(&mut header).ok()?;
+
+  let table_header: T::Header = T::Header::from_slice(&header).ok()?;
+  let size: u64 = u64::from(u32::from_le_bytes([
+    header[8], header[9], header[10], header[11],
+  ]));
+
+  // Compressed tables are not supported
+  if let Some(v4) = archive.header().v4() {
+    if T::comp_size(v4) < T::Header::SIZE as u64 + size {
+      return None;
+    }
+  }
+
+  if position + T::Header::SIZE as u64 + size > reader.size() {
+    return None;
+  }
+
+  let mut data: Vec<u8> = vec![0; T::Header::SIZE + size as usize];
+
+  data[..T::Header::SIZE].copy_from_slice(&header);
+  reader.read_bytes(&mut data[T::Header::SIZE..]).ok()?;
+  utils::decrypt(&mut data[T::Header::SIZE..], T::HKEY).ok()?;
+
+  let table: T = T::from_slice(table_header, &data[T::Header::SIZE..]).ok()?;
+
+  Some((table, data))
+}
+
+// Read `length` bits (LSB first) at bit `position` of `data`
+fn read_bits(data: &[u8], position: u64, length: u32) -> Option<u64> {
+  let mut value: u64 = 0;
+
+  for bit in 0..u64::from(length) {
+    let index: u64 = position + bit;
+    let byte: u8 = *data.get(usize::try_from(index / 8).ok()?)?;
+
+    value |= u64::from((byte >> (index % 8)) & 1) << bit;
+  }
+
+  Some(value)
+}
@@ -0,0 +1,206 @@
+use storm_utils::traits::ReadExt;
+use storm_utils::traits::SeekExt;
+use storm_utils::utils::DigestMd5;
+
+use crate::consts;
+use crate::error::Result;
+use crate::extract::reader::fake_compression;
+use crate::extract::sector::sector_count;
+use crate::extract::FilePtr;
+use crate::extract::Sectors;
+use crate::parse::Handle;
+use crate::types::BTableEntryFlags;
+use crate::types::Locale;
+use crate::utils;
+
+only_serde! {
+  use serde::ser::SerializeStruct;
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// File Info
+// =============================================================================
+
+/// Detailed metadata of a file in an archive.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct FileInfo {
+  /// The name of the file.
+  pub name: String,
+  /// The locale of the file.
+  pub locale: Locale,
+  /// The platform of the file.
+  pub platform: u8,
+  /// The index of the file in the block table.
+  pub block: u32,
+  /// The offset of the file data in the archive file (absolute).
+  pub offset: u64,
+  /// The compressed size of the file.
+  pub comp_size: u32,
+  /// The uncompressed size of the file.
+  pub file_size: u32,
+  /// The block table flags of the file.
+  pub bitflags: BTableEntryFlags,
+  /// The encryption key of the file (`0` if not encrypted).
+  pub encryption_key: u32,
+  /// The number of sectors in the file.
+  pub sector_count: u32,
+  /// The compression method of each sector (see `consts::COMP_*`).
+  ///
+  /// `None` if the sector is stored uncompressed.
+  pub compression: Vec<Option<u8>>,
+  /// CRC32 of the file data (uncompressed), from `(attributes)`.
+  pub crc: Option<u32>,
+  /// MD5 of the file data (uncompressed), from `(attributes)`.
+  pub md5: Option<DigestMd5>,
+  /// Last modification time (unix), from `(attributes)`.
+  pub time: Option<u64>,
+}
+
+impl FileInfo {
+  pub(crate) fn new(pointer: &FilePtr<'_>) -> Result<Self> {
+    let encryption_key: u32 = pointer.encryption_key();
+    let compression: Vec<Option<u8>> = read_compression(pointer, encryption_key)?;
+
+    let mut this: Self = Self {
+      name: pointer.name().to_owned(),
+      locale: Locale::from_u16(pointer.htentry.language),
+      platform: pointer.htentry.platform,
+      block: pointer.block(),
+      offset: pointer.offset(),
+      comp_size: pointer.btentry.comp_size,
+      file_size: pointer.btentry.file_size,
+      bitflags: pointer.btentry.bitflags,
+      encryption_key,
+      sector_count: compression.len() as u32,
+      compression,
+      crc: None,
+      md5: None,
+      time: None,
+    };
+
+    // Missing or damaged attributes are not an error - they're optional
+    if let Some(attributes) = pointer.archive.attributes() {
+      let index: usize = this.block as usize;
+
+      this.crc = attributes.crc.get(index).copied();
+      this.md5 = attributes.md5.get(index).copied();
+      this.time = attributes
+        .time
+        .get(index)
+        .copied()
+        .filter(|time| *time != 0);
+    }
+
+    Ok(this)
+  }
+}
+
+only_serde! {
+  impl Serialize for FileInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("FileInfo", 14)?;
+      state.serialize_field("name", &self.name)?;
+      state.serialize_field("locale", &self.locale.into_u16())?;
+      state.serialize_field("platform", &self.platform)?;
+      state.serialize_field("block", &self.block)?;
+      state.serialize_field("offset", &self.offset)?;
+      state.serialize_field("comp_size", &self.comp_size)?;
+      state.serialize_field("file_size", &self.file_size)?;
+      state.serialize_field("bitflags", &self.bitflags)?;
+      state.serialize_field("encryption_key", &self.encryption_key)?;
+      state.serialize_field("sector_count", &self.sector_count)?;
+      state.serialize_field("compression", &self.compression)?;
+      state.serialize_field("crc", &self.crc)?;
+      state.serialize_field("md5", &self.md5)?;
+      state.serialize_field("time", &self.time)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Sector Compression
+// =============================================================================
+
+pub(crate) fn read_compression(pointer: &FilePtr<'_>, enc_key: u32) -> Result<Vec<Option<u8>>> {
+  let file_size: u32 = pointer.btentry.file_size;
+  let comp_size: u32 = pointer.btentry.comp_size;
+
+  if file_size == 0 || comp_size == 0 || pointer.btentry.is_patch_file() {
+    return Ok(Vec::new());
+  }
+
+  // Uncompressed files are never compressed - no need to read anything
+  if !pointer.btentry.is_any_compression() {
+    return Ok(vec![None; sector_count(pointer) as usize]);
+  }
+
+  let mut reader: Handle = pointer.archive.handle.duplicate_exact()?;
+
+  if pointer.btentry.is_single_unit() {
+    let method: Option<u8> = read_method(pointer, &mut reader, 0, comp_size, file_size, enc_key)?;
+
+    return Ok(vec![method]);
+  }
+
+  let sector_size: u32 = pointer.archive.sector_size();
+  let sectors: Sectors = Sectors::read(pointer, enc_key)?;
+  let mut output: Vec<Option<u8>> = Vec::with_capacity(sectors.count as usize);
+
+  // Note: Skip the trailing sector checksum entry (if any)
+  for (index, slice) in sectors.table[..=sectors.count as usize]
+    .windows(2)
+    .enumerate()
+  {
+    let [this, next] = slice else {
+      panic!("Invalid Window Length");
+    };
+
+    let length: u32 = next.saturating_sub(*this);
+    let expected: u32 = sector_size.min(file_size - index as u32 * sector_size);
+    let key: u32 = enc_key.wrapping_add(index as u32);
+
+    output.push(read_method(
+      pointer,
+      &mut reader,
+      *this,
+      length,
+      expected,
+      key,
+    )?);
+  }
+
+  Ok(output)
+}
+
+fn read_method(
+  pointer: &FilePtr<'_>,
+  reader: &mut Handle,
+  offset: u32,
+  length: u32,
+  expected: u32,
+  enc_key: u32,
+) -> Result<Option<u8>> {
+  if length == 0 || fake_compression(pointer, length as usize, expected as usize) {
+    return Ok(None);
+  }
+
+  // Imploded data has no compression mask
+  if pointer.btentry.is_imploded() {
+    return Ok(Some(consts::COMP_PKWARE));
+  }
+
+  // Note: Decryption works on whole words so read one
+  let mut buffer: Vec<u8> = vec![0; length.min(4) as usize];
+
+  reader.seek_start(pointer.offset() + u64::from(offset))?;
+  reader.read_bytes(&mut buffer)?;
+
+  if pointer.btentry.is_encrypted() {
+    utils::decrypt(&mut buffer, enc_key)?;
+  }
+
+  Ok(Some(buffer[0]))
+}
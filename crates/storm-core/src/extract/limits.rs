@@ -0,0 +1,85 @@
+// =============================================================================
+// Extraction Limits
+// =============================================================================
+
+/// Resource limits enforced before allocating memory for a file.
+///
+/// ## Defaults
+///
+/// - files larger than 256 MB (uncompressed) are rejected
+/// - the total extracted size, compression ratio and sector count are not
+///   limited
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Limits {
+  pub(crate) file_size: Option<u64>,
+  pub(crate) total_size: Option<u64>,
+  pub(crate) ratio: Option<u32>,
+  pub(crate) sectors: Option<u32>,
+}
+
+impl Limits {
+  /// The default maximum uncompressed size of a single file (256 MB).
+  pub const FILE_SIZE: u64 = 0x10000000;
+
+  /// Create a new `Limits` with the default settings.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      file_size: Some(Self::FILE_SIZE),
+      total_size: None,
+      ratio: None,
+      sectors: None,
+    }
+  }
+
+  /// Create a new `Limits` with every limit disabled.
+  #[inline]
+  pub const fn unlimited() -> Self {
+    Self {
+      file_size: None,
+      total_size: None,
+      ratio: None,
+      sectors: None,
+    }
+  }
+
+  /// Set the maximum uncompressed size (bytes) of a single file.
+  #[inline]
+  pub fn file_size(&mut self, value: Option<u64>) -> &mut Self {
+    self.file_size = value;
+    self
+  }
+
+  /// Set the maximum uncompressed size (bytes) of all files extracted from the
+  /// archive.
+  ///
+  /// Only file data handed to the caller counts towards the total; special
+  /// files read internally (e.g. the `(listfile)` and `(attributes)`) and
+  /// recovered orphans do not.
+  #[inline]
+  pub fn total_size(&mut self, value: Option<u64>) -> &mut Self {
+    self.total_size = value;
+    self
+  }
+
+  /// Set the maximum ratio of uncompressed size to compressed size.
+  #[inline]
+  pub fn ratio(&mut self, value: Option<u32>) -> &mut Self {
+    self.ratio = value;
+    self
+  }
+
+  /// Set the maximum number of sectors in a single file.
+  #[inline]
+  pub fn sectors(&mut self, value: Option<u32>) -> &mut Self {
+    self.sectors = value;
+    self
+  }
+}
+
+impl Default for Limits {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
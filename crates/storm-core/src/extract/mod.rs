@@ -1,9 +1,31 @@
 mod finder;
+mod index;
+mod info;
+mod limits;
+mod progress;
 mod reader;
+mod search;
 mod sector;
+mod unpack;
+
+pub(crate) use self::finder::start_slot;
+pub(crate) use self::info::read_compression;
+pub(crate) use self::reader::read_file_with_key;
+pub(crate) use self::reader::read_metadata;
+pub(crate) use self::sector::sector_count;
+pub(crate) use self::unpack::extract_all;
 
 pub use self::finder::find_file;
 pub use self::finder::FilePtr;
 pub use self::finder::Query;
+pub use self::index::NameIndex;
+pub use self::info::FileInfo;
+pub use self::limits::Limits;
+pub use self::progress::CancelToken;
+pub use self::progress::Progress;
+pub use self::progress::ProgressState;
+pub use self::progress::Tracker;
 pub use self::reader::read_file;
+pub use self::search::Pattern;
+pub use self::search::Search;
 pub use self::sector::Sectors;
@@ -0,0 +1,186 @@
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+
+// =============================================================================
+// Progress
+// =============================================================================
+
+/// The state of a long-running operation, passed to [`Progress::update`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ProgressState<'a> {
+  /// The number of files processed so far.
+  pub files: u64,
+  /// The total number of files to process, if known.
+  pub total: Option<u64>,
+  /// The number of bytes processed so far.
+  pub bytes: u64,
+  /// The name of the file being processed.
+  pub current: &'a str,
+}
+
+/// A callback receiving progress updates from long-running operations.
+///
+/// Implemented for `()` (ignores every update) and for closures.
+pub trait Progress {
+  /// Called before and after each file is processed.
+  fn update(&mut self, state: &ProgressState<'_>);
+}
+
+impl Progress for () {
+  #[inline]
+  fn update(&mut self, _state: &ProgressState<'_>) {}
+}
+
+impl<F> Progress for F
+where
+  F: FnMut(&ProgressState<'_>),
+{
+  #[inline]
+  fn update(&mut self, state: &ProgressState<'_>) {
+    self(state)
+  }
+}
+
+// =============================================================================
+// Cancel Token
+// =============================================================================
+
+/// A flag used to stop a long-running operation from another thread.
+///
+/// Clones share the same flag. Operations check the token before each file
+/// and fail with [`ErrorKind::Cancelled`] once it is set.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+  inner: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+  /// Create a new `CancelToken`.
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Request cancellation of every operation using this token.
+  #[inline]
+  pub fn cancel(&self) {
+    self.inner.store(true, Ordering::Relaxed);
+  }
+
+  /// Returns `true` if cancellation was requested.
+  #[inline]
+  pub fn is_cancelled(&self) -> bool {
+    self.inner.load(Ordering::Relaxed)
+  }
+}
+
+// =============================================================================
+// Tracker
+// =============================================================================
+
+/// Progress reporting and cancellation for a long-running operation.
+///
+/// ## Defaults
+///
+/// - progress updates are ignored
+/// - the operation can not be cancelled
+#[derive(Default)]
+pub struct Tracker<'a> {
+  progress: Option<&'a mut dyn Progress>,
+  cancel: Option<CancelToken>,
+  total: Option<u64>,
+  files: u64,
+  bytes: u64,
+}
+
+impl<'a> Tracker<'a> {
+  /// Create a new `Tracker` with the default settings.
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Set the callback receiving progress updates.
+  #[inline]
+  pub fn progress(&mut self, value: &'a mut dyn Progress) -> &mut Self {
+    self.progress = Some(value);
+    self
+  }
+
+  /// Set the token used to cancel the operation.
+  #[inline]
+  pub fn cancel(&mut self, value: CancelToken) -> &mut Self {
+    self.cancel = Some(value);
+    self
+  }
+
+  /// Returns the number of files processed so far.
+  #[inline]
+  pub const fn files(&self) -> u64 {
+    self.files
+  }
+
+  /// Returns the number of bytes processed so far.
+  #[inline]
+  pub const fn bytes(&self) -> u64 {
+    self.bytes
+  }
+
+  /// Set the total number of files the operation will process.
+  #[inline]
+  pub fn total(&mut self, value: u64) {
+    self.total = Some(value);
+  }
+
+  /// Called before processing the file with the given `name`.
+  ///
+  /// Returns an error if the operation was cancelled.
+  pub fn start(&mut self, name: &str) -> Result<()> {
+    if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+      return Err(Error::new(ErrorKind::Cancelled));
+    }
+
+    self.report(name);
+
+    Ok(())
+  }
+
+  /// Called after processing `bytes` of the file with the given `name`.
+  pub fn finish(&mut self, name: &str, bytes: u64) {
+    self.files += 1;
+    self.bytes += bytes;
+    self.report(name);
+  }
+
+  fn report(&mut self, current: &str) {
+    let state: ProgressState<'_> = ProgressState {
+      files: self.files,
+      total: self.total,
+      bytes: self.bytes,
+      current,
+    };
+
+    if let Some(progress) = self.progress.as_mut() {
+      progress.update(&state);
+    }
+  }
+}
+
+impl Debug for Tracker<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("Tracker")
+      .field("cancel", &self.cancel)
+      .field("total", &self.total)
+      .field("files", &self.files)
+      .field("bytes", &self.bytes)
+      .finish_non_exhaustive()
+  }
+}
@@ -1,28 +1,141 @@
+use std::sync::atomic::Ordering;
 use storm_utils::traits::ReadExt;
 use storm_utils::traits::SeekExt;
 
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
 use crate::extract::FilePtr;
+use crate::extract::Limits;
 use crate::extract::Sectors;
 use crate::parse::Handle;
 use crate::types::File;
 use crate::utils;
 
 pub fn read_file(pointer: FilePtr<'_>) -> Result<File> {
-  let enc_key: u32 = pointer.encryption_key();
+  read_file_with_key(pointer, pointer.encryption_key(), true)
+}
+
+/// Read archive metadata (e.g. the listfile) that is not kept by the caller,
+/// without counting it against the total size limit.
+pub(crate) fn read_metadata(pointer: FilePtr<'_>) -> Result<File> {
+  read_file_with_key(pointer, pointer.encryption_key(), false)
+}
 
+/// Read the file data with a known encryption key (e.g. a recovered one).
+///
+/// Only data handed to the caller should be `charged` to the total size limit.
+pub(crate) fn read_file_with_key(
+  pointer: FilePtr<'_>,
+  enc_key: u32,
+  charged: bool,
+) -> Result<File> {
+  read_file_inner(pointer, enc_key, charged).map_err(|error| {
+    error
+      .with_file(pointer.name())
+      .with_block(pointer.block())
+      .with_offset(pointer.offset())
+      .with_path(pointer.archive.handle.path())
+  })
+}
+
+fn read_file_inner(pointer: FilePtr<'_>, enc_key: u32, charged: bool) -> Result<File> {
   // TODO: investigate
   if pointer.btentry.comp_size == 0 || pointer.btentry.file_size == 0 {
     return Ok(File::empty());
   }
 
-  if pointer.btentry.is_patch_file() {
+  // Check everything we can before allocating anything
+  check_limits(&pointer)?;
+
+  if charged {
+    reserve_total(&pointer)?;
+  }
+
+  let result: Result<File> = if pointer.btentry.is_patch_file() {
     read_patch(pointer, enc_key)
   } else if pointer.btentry.is_single_unit() {
     read_single_unit(pointer, enc_key)
   } else {
     read_sectors(pointer, enc_key)
+  };
+
+  // The caller only keeps the data if the file could be read
+  if charged && result.is_err() {
+    release_total(&pointer);
   }
+
+  result
+}
+
+// =============================================================================
+// Resource Limits
+// =============================================================================
+
+fn check_limits(pointer: &FilePtr<'_>) -> Result<()> {
+  let limits: &Limits = &pointer.archive.limits;
+  let file_size: u64 = u64::from(pointer.btentry.file_size);
+  let comp_size: u64 = u64::from(pointer.btentry.comp_size);
+
+  // The compressed data must be in the file
+  if pointer.offset() + comp_size > pointer.archive.handle.size() {
+    return Err(Error::new(ErrorKind::FileCorruptData));
+  }
+
+  if let Some(max) = limits.file_size {
+    if file_size > max {
+      return Err(Error::new(ErrorKind::LimitFileSize));
+    }
+  }
+
+  if let Some(max) = limits.ratio {
+    if file_size > comp_size * u64::from(max) {
+      return Err(Error::new(ErrorKind::LimitRatio));
+    }
+  }
+
+  if let Some(max) = limits.sectors {
+    if !pointer.btentry.is_single_unit() {
+      let sector_size: u64 = u64::from(pointer.archive.sector_size());
+      let sector_count: u64 = ((file_size - 1) / sector_size) + 1;
+
+      if sector_count > u64::from(max) {
+        return Err(Error::new(ErrorKind::LimitSectorCount));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+// Reserve the file size from the total - this is never given back once read
+// since the caller keeps the extracted data.
+fn reserve_total(pointer: &FilePtr<'_>) -> Result<()> {
+  let limits: &Limits = &pointer.archive.limits;
+  let file_size: u64 = u64::from(pointer.btentry.file_size);
+
+  let total: u64 = pointer
+    .archive
+    .extracted
+    .fetch_add(file_size, Ordering::Relaxed)
+    + file_size;
+
+  if let Some(max) = limits.total_size {
+    if total > max {
+      release_total(pointer);
+
+      return Err(Error::new(ErrorKind::LimitTotalSize));
+    }
+  }
+
+  Ok(())
+}
+
+fn release_total(pointer: &FilePtr<'_>) {
+  pointer
+    .archive
+    .extracted
+    .fetch_sub(u64::from(pointer.btentry.file_size), Ordering::Relaxed);
 }
 
 // =============================================================================
@@ -54,7 +167,9 @@ fn read_single_unit(pointer: FilePtr<'_>, enc_key: u32) -> Result<File> {
   cursor += read_chunk(&pointer, &buffer, &mut output)?;
 
   // Sanity Check - We read enough data as indicated by `file_size`
-  assert!(cursor as u32 == pointer.btentry.file_size);
+  if cursor as u32 != pointer.btentry.file_size {
+    return Err(Error::new(ErrorKind::FileCorruptData));
+  }
 
   Ok(File::new(output))
 }
@@ -69,7 +184,7 @@ fn read_sectors(pointer: FilePtr<'_>, enc_key: u32) -> Result<File> {
 
 fn read_sectors_uncompressed(pointer: FilePtr<'_>, enc_key: u32) -> Result<File> {
   // Create a "fake" sector offset table and use for reading
-  read_from_sectors(pointer, enc_key, Sectors::new_fake(&pointer))
+  read_from_sectors(pointer, enc_key, Sectors::new_fake(&pointer)?)
 }
 
 fn read_sectors_compressed(pointer: FilePtr<'_>, enc_key: u32) -> Result<File> {
@@ -100,18 +215,26 @@ fn read_from_sectors(pointer: FilePtr<'_>, enc_key: u32, sectors: Sectors) -> Re
       panic!("Invalid Window Length");
     };
 
-    let length: usize = (next - this) as usize;
+    let offset: u64 = pointer.offset() + u64::from(*this);
+
+    let sector_error =
+      |error: Error| -> Error { error.with_sector(index as u32).with_offset(offset) };
 
     // Sanity Check - length should NOT be larger than target sector size
-    assert!(length as u32 <= sector_size);
+    let length: usize = match next.checked_sub(*this) {
+      Some(length) if length <= sector_size => length as usize,
+      _ => return Err(sector_error(Error::new(ErrorKind::FileCorruptData))),
+    };
 
     // We only need need to operate on a small slice of the sector data buffer
     let window: &mut [u8] = &mut buffer[..length];
     let output: &mut [u8] = &mut output[cursor..];
 
     // Move the reader to the sector offset and read all "compressed" data
-    reader.seek_start(pointer.offset() + u64::from(*this))?;
-    reader.read_bytes(window)?;
+    reader.seek_start(offset)?;
+    reader
+      .read_bytes(window)
+      .map_err(|error| sector_error(error.into()))?;
 
     // Decrypt if necessary
     //
@@ -125,11 +248,13 @@ fn read_from_sectors(pointer: FilePtr<'_>, enc_key: u32, sectors: Sectors) -> Re
       panic!("TODO: CRC");
     }
 
-    cursor += read_chunk(&pointer, window, output)?;
+    cursor += read_chunk(&pointer, window, output).map_err(sector_error)?;
   }
 
   // Sanity Check - We read enough data as indicated by `file_size`
-  assert!(cursor as u32 == pointer.btentry.file_size);
+  if cursor as u32 != pointer.btentry.file_size {
+    return Err(Error::new(ErrorKind::FileCorruptData));
+  }
 
   Ok(File::new(output))
 }
@@ -142,15 +267,17 @@ fn read_chunk(pointer: &FilePtr<'_>, buffer: &[u8], output: &mut [u8]) -> Result
     utils::decompress(buffer, output)?
   } else if !fake && pointer.btentry.is_imploded() {
     utils::decompress_pkware(buffer, output)?
-  } else {
-    output[..buffer.len()].copy_from_slice(buffer);
+  } else if let Some(output) = output.get_mut(..buffer.len()) {
+    output.copy_from_slice(buffer);
     buffer.len()
+  } else {
+    return Err(Error::new(ErrorKind::FileCorruptData));
   };
 
   Ok(bytes)
 }
 
-fn fake_compression(pointer: &FilePtr<'_>, window: usize, output: usize) -> bool {
+pub(crate) fn fake_compression(pointer: &FilePtr<'_>, window: usize, output: usize) -> bool {
   // Sector could not be compressed
   if window == pointer.btentry.file_size as usize {
     return true;
@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use crate::extract::find_file;
+use crate::extract::FilePtr;
+use crate::types::Archive;
+use crate::types::Repair;
+use crate::utils;
+
+#[cfg(feature = "regex")]
+use crate::error::Error;
+#[cfg(feature = "regex")]
+use crate::error::Result;
+
+// =============================================================================
+// Search Pattern
+// =============================================================================
+
+/// A pattern matched against file names.
+///
+/// Names are matched case-insensitively, with `/` and `\` treated as the same
+/// character (see [`utils::normalize`]).
+#[derive(Clone, Debug)]
+pub enum Pattern {
+  /// A wildcard mask - `*` matches any sequence, `?` matches any character.
+  Glob(String),
+  /// A regular expression.
+  #[cfg(feature = "regex")]
+  Regex(regex::Regex),
+}
+
+impl Pattern {
+  /// Create a new wildcard `Pattern`.
+  #[inline]
+  pub fn glob(pattern: &str) -> Self {
+    Self::Glob(pattern.to_owned())
+  }
+
+  /// Create a new regular expression `Pattern`.
+  ///
+  /// The expression is matched against names with `/` replaced by `\`.
+  #[cfg(feature = "regex")]
+  pub fn regex(pattern: &str) -> Result<Self> {
+    regex::RegexBuilder::new(pattern)
+      .case_insensitive(true)
+      .build()
+      .map(Self::Regex)
+      .map_err(Error::message)
+  }
+
+  /// Returns `true` if the given `name` matches the pattern.
+  pub fn is_match(&self, name: &str) -> bool {
+    match self {
+      Self::Glob(pattern) => {
+        let pattern: Vec<char> = utils::normalize(pattern).chars().collect();
+        let name: Vec<char> = utils::normalize(name).chars().collect();
+
+        is_glob_match(&pattern, &name)
+      }
+      #[cfg(feature = "regex")]
+      Self::Regex(regex) => regex.is_match(&name.replace('/', "\\")),
+    }
+  }
+}
+
+impl From<&str> for Pattern {
+  #[inline]
+  fn from(other: &str) -> Self {
+    Self::glob(other)
+  }
+}
+
+/// The expression is rebuilt to match case-insensitively, like
+/// [`Pattern::regex`].
+#[cfg(feature = "regex")]
+impl From<regex::Regex> for Pattern {
+  #[inline]
+  fn from(other: regex::Regex) -> Self {
+    Self::regex(other.as_str()).unwrap_or(Self::Regex(other))
+  }
+}
+
+// =============================================================================
+// File Search
+// =============================================================================
+
+/// Files in an archive with names matching a [`Pattern`].
+///
+/// Names are taken from the `(listfile)`, [`Repair::KNOWN_NAMES`] and any
+/// names added with [`Search::names`].
+#[derive(Debug)]
+pub struct Search<'a> {
+  archive: &'a Archive,
+  pattern: Pattern,
+  names: Vec<String>,
+  unique: HashSet<String>,
+}
+
+impl<'a> Search<'a> {
+  /// Create a new `Search` of the given `archive`.
+  pub fn new(archive: &'a Archive, pattern: Pattern) -> Self {
+    let mut this: Self = Self {
+      archive,
+      pattern,
+      names: Vec::new(),
+      unique: HashSet::new(),
+    };
+
+    this.names(Repair::KNOWN_NAMES.iter().copied());
+
+    if let Ok(list) = archive.load_listfile() {
+      this.names(list.iter().filter_map(|entry| entry.as_utf8().ok()));
+    }
+
+    this
+  }
+
+  /// Add extra names to search (e.g. from an external listfile).
+  pub fn names<I, S>(&mut self, names: I) -> &mut Self
+  where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+  {
+    for name in names {
+      let name: &str = name.as_ref();
+
+      if self.unique.insert(utils::normalize(name)) {
+        self.names.push(name.to_owned());
+      }
+    }
+
+    self
+  }
+
+  /// Returns an iterator over the matching files in the archive.
+  pub fn iter(&self) -> impl Iterator<Item = FilePtr<'_>> + '_ {
+    self
+      .names
+      .iter()
+      .filter(|name| self.pattern.is_match(name))
+      .filter_map(|name| find_file(self.archive, name.as_str()).ok())
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+// Match a (normalized) wildcard pattern against a (normalized) name
+fn is_glob_match(pattern: &[char], name: &[char]) -> bool {
+  let mut p_index: usize = 0;
+  let mut n_index: usize = 0;
+
+  // Position of the last `*` in the pattern and the name position it matched
+  let mut backtrack: Option<(usize, usize)> = None;
+
+  while n_index < name.len() {
+    match pattern.get(p_index) {
+      Some('*') => {
+        backtrack = Some((p_index, n_index));
+        p_index += 1;
+      }
+      Some('?') => {
+        p_index += 1;
+        n_index += 1;
+      }
+      Some(ch) if *ch == name[n_index] => {
+        p_index += 1;
+        n_index += 1;
+      }
+      _ => match backtrack {
+        // Let the last `*` consume one more character and retry
+        Some((star, matched)) => {
+          backtrack = Some((star, matched + 1));
+          p_index = star + 1;
+          n_index = matched + 1;
+        }
+        None => return false,
+      },
+    }
+  }
+
+  pattern[p_index..].iter().all(|ch| *ch == '*')
+}
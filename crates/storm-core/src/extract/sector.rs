@@ -2,6 +2,8 @@ use std::io::Cursor;
 use storm_utils::traits::ReadExt;
 use storm_utils::traits::SeekExt;
 
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
 use crate::extract::FilePtr;
 use crate::parse::Handle;
@@ -20,7 +22,7 @@ pub struct Sectors {
 
 impl Sectors {
   // TODO: Replace with iterator
-  pub(crate) fn new_fake(pointer: &FilePtr<'_>) -> Self {
+  pub(crate) fn new_fake(pointer: &FilePtr<'_>) -> Result<Self> {
     let sector_size: u32 = pointer.archive.sector_size();
     let sector_count: u32 = sector_count(pointer);
 
@@ -34,8 +36,9 @@ impl Sectors {
     };
 
     // Sanity Checks
-    sectors.assert(pointer);
-    sectors
+    sectors.validate(pointer)?;
+
+    Ok(sectors)
   }
 
   pub(crate) fn read(pointer: &FilePtr<'_>, enc_key: u32) -> Result<Self> {
@@ -77,27 +80,34 @@ impl Sectors {
     };
 
     // Sanity Checks
-    sectors.assert(pointer);
+    sectors.validate(pointer)?;
 
     Ok(sectors)
   }
 
-  // TODO: Maybe return errors
-  fn assert(&self, pointer: &FilePtr<'_>) {
+  fn validate(&self, pointer: &FilePtr<'_>) -> Result<()> {
     // Sector count can't be zero
-    assert!(self.count > 0);
+    if self.count == 0 {
+      return Err(Error::new(ErrorKind::FileCorruptData));
+    }
 
     // Sector table can't be empty
-    assert!(self.table.len() > 1);
+    if self.table.len() <= 1 {
+      return Err(Error::new(ErrorKind::FileCorruptData));
+    }
 
     // Last sector is just an indicator of the compressed size
-    assert!(self.table[self.count as usize] == pointer.btentry.comp_size);
+    if self.table[self.count as usize] != pointer.btentry.comp_size {
+      return Err(Error::new(ErrorKind::FileCorruptData));
+    }
+
+    Ok(())
   }
 }
 
 // Determine the number of data sectors
 #[inline]
-fn sector_count(pointer: &FilePtr<'_>) -> u32 {
+pub(crate) fn sector_count(pointer: &FilePtr<'_>) -> u32 {
   ((pointer.btentry.file_size - 1) / pointer.archive.sector_size()) + 1
 }
 
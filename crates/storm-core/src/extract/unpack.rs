@@ -0,0 +1,67 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::error::Result;
+use crate::extract::FilePtr;
+use crate::extract::Search;
+use crate::extract::Tracker;
+use crate::types::Archive;
+use crate::types::File;
+
+// Extract every file with a known name to the directory at `root`
+pub(crate) fn extract_all(
+  archive: &Archive,
+  root: &Path,
+  tracker: &mut Tracker<'_>,
+) -> Result<u64> {
+  let search: Search<'_> = archive.find_files("*");
+  let mut count: u64 = 0;
+
+  // Skip names that would escape the output directory
+  let pointers: Vec<(FilePtr<'_>, PathBuf)> = search
+    .iter()
+    .filter_map(|pointer| Some((pointer, local_path(root, pointer.name())?)))
+    .collect();
+
+  tracker.total(pointers.len() as u64);
+
+  for (pointer, path) in pointers {
+    let name: &str = pointer.name();
+
+    tracker.start(name)?;
+
+    let data: File = pointer.read()?;
+
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    fs::write(&path, &*data).map_err(|error| pointer.context(error.into()))?;
+
+    tracker.finish(name, data.size() as u64);
+    count += 1;
+  }
+
+  Ok(count)
+}
+
+// Convert an archive file name to a path inside `root`
+fn local_path(root: &Path, name: &str) -> Option<PathBuf> {
+  let mut output: PathBuf = root.to_path_buf();
+  let mut empty: bool = true;
+
+  for part in name.split(['\\', '/']) {
+    match part {
+      "" | "." => {}
+      ".." => return None,
+      part if part.contains(':') => return None,
+      part => {
+        output.push(part);
+        empty = false;
+      }
+    }
+  }
+
+  (!empty).then_some(output)
+}
@@ -2,11 +2,13 @@ use std::fs::File;
 use std::fs::Metadata;
 use std::io;
 use std::io::BufReader;
+use std::io::Cursor;
 use std::io::Read;
 use std::io::Seek;
 use std::io::SeekFrom;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::error::Error;
 use crate::error::ErrorKind;
@@ -19,7 +21,7 @@ use crate::types::HeaderV1;
 
 #[derive(Debug)]
 pub struct Handle {
-  file: BufReader<File>,
+  source: Source,
   path: PathBuf,
   size: u64,
 }
@@ -28,14 +30,24 @@ impl Handle {
   // Use a buffer size of 8KB - for performance.
   const BUFFER: usize = 0x2000;
 
-  // Don't try to parse files larger than 128 MB.
-  const MAX: u64 = 0x08000000;
+  /// Don't try to parse files larger than 128 MB (by default).
+  pub const MAX: u64 = 0x08000000;
 
   // All MPQs MUST have a header (at minimum).
   const MIN: u64 = HeaderV1::SIZE as u64;
 
   /// Create a new handle from the file at the given `path`.
+  #[inline]
   pub fn new<P>(path: &P) -> Result<Self>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    Self::with_max_size(path, Self::MAX)
+  }
+
+  /// Create a new handle from the file at the given `path`, rejecting files
+  /// larger than `max_size` bytes.
+  pub fn with_max_size<P>(path: &P, max_size: u64) -> Result<Self>
   where
     P: AsRef<Path> + ?Sized,
   {
@@ -51,21 +63,78 @@ impl Handle {
     let size: u64 = meta.len();
 
     // Check the file size and don't read anything invalid
-    if !(Self::MIN..=Self::MAX).contains(&size) {
+    if !(Self::MIN..=max_size).contains(&size) {
       return Err(Error::new(ErrorKind::FileInvalidSize));
     }
 
     Ok(Self {
-      file: Self::create_reader(Self::BUFFER, file),
+      source: Source::File(Self::create_reader(Self::BUFFER, file)),
       path: path.to_owned(),
       size,
     })
   }
 
-  /// Returns a reference to the file.
+  /// Create a new handle reading `size` bytes at `offset` of the `parent`
+  /// handle.
+  ///
+  /// Offsets of the new handle are relative to `offset`.
+  pub fn with_window(parent: &Self, offset: u64, size: u64) -> Result<Self> {
+    if !(Self::MIN..=parent.size).contains(&size) || offset > parent.size - size {
+      return Err(Error::new(ErrorKind::FileInvalidSize));
+    }
+
+    let (file, base): (BufReader<File>, u64) = match parent.source {
+      Source::File(ref file) => (Self::clone_file(file, Self::BUFFER)?, 0),
+      Source::Window(ref file, base) => (Self::clone_file(file, Self::BUFFER)?, base),
+      Source::Memory(ref data) => {
+        let start: usize = offset as usize;
+        let end: usize = start + size as usize;
+
+        return Self::with_bytes(parent.path(), data.get_ref()[start..end].to_vec());
+      }
+    };
+
+    Ok(Self {
+      source: Source::Window(file, base + offset),
+      path: parent.path.clone(),
+      size,
+    })
+  }
+
+  /// Create a new handle reading from `data` in memory.
+  ///
+  /// The `path` is only used to identify the source (e.g. in errors).
+  pub fn with_bytes<P>(path: &P, data: Vec<u8>) -> Result<Self>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    let size: u64 = data.len() as u64;
+
+    if size < Self::MIN {
+      return Err(Error::new(ErrorKind::FileInvalidSize));
+    }
+
+    Ok(Self {
+      source: Source::Memory(Cursor::new(Arc::from(data))),
+      path: path.as_ref().to_owned(),
+      size,
+    })
+  }
+
+  /// Returns a reference to the file, or `None` if the handle reads from
+  /// memory.
+  #[inline]
+  pub fn file(&self) -> Option<&File> {
+    match self.source {
+      Source::File(ref file) | Source::Window(ref file, _) => Some(file.get_ref()),
+      Source::Memory(_) => None,
+    }
+  }
+
+  /// Returns a reference to the source of the handle data.
   #[inline]
-  pub fn file(&self) -> &File {
-    self.file.get_ref()
+  pub const fn source(&self) -> &Source {
+    &self.source
   }
 
   /// Returns the path to the file.
@@ -81,9 +150,14 @@ impl Handle {
   }
 
   /// Returns the byte capacity of the internal buffer.
+  ///
+  /// Handles reading from memory have no buffer.
   #[inline]
   pub fn capacity(&self) -> usize {
-    self.file.capacity()
+    match self.source {
+      Source::File(ref file) | Source::Window(ref file, _) => file.capacity(),
+      Source::Memory(_) => 0,
+    }
   }
 
   /// Create a clone of the file handle with the same buffer capacity.
@@ -95,17 +169,23 @@ impl Handle {
   /// Create a clone of the file handle with the specified buffer `capacity`.
   #[inline]
   pub fn duplicate(&self, capacity: usize) -> Result<Self> {
+    let source: Source = match self.source {
+      Source::File(ref file) => Source::File(Self::clone_file(file, capacity)?),
+      Source::Window(ref file, base) => Source::Window(Self::clone_file(file, capacity)?, base),
+      Source::Memory(ref data) => Source::Memory(Cursor::new(Arc::clone(data.get_ref()))),
+    };
+
     Ok(Self {
-      file: self.clone_file(capacity)?,
+      source,
       path: self.path.clone(),
       size: self.size,
     })
   }
 
   #[inline]
-  fn clone_file(&self, capacity: usize) -> Result<BufReader<File>> {
-    self
-      .file()
+  fn clone_file(file: &BufReader<File>, capacity: usize) -> Result<BufReader<File>> {
+    file
+      .get_ref()
       .try_clone()
       .map(|file| Self::create_reader(capacity, file))
       .map_err(Into::into)
@@ -118,19 +198,62 @@ impl Handle {
 }
 
 impl Read for Handle {
-  #[inline]
   fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
-    self.file.read(buffer)
+    match self.source {
+      Source::File(ref mut file) => file.read(buffer),
+      Source::Window(ref mut file, base) => {
+        // Don't read past the end of the window
+        let position: u64 = file.stream_position()?.saturating_sub(base);
+        let remaining: u64 = self.size.saturating_sub(position);
+        let length: usize = buffer.len().min(remaining.try_into().unwrap_or(usize::MAX));
+
+        file.read(&mut buffer[..length])
+      }
+      Source::Memory(ref mut data) => data.read(buffer),
+    }
   }
 }
 
 impl Seek for Handle {
-  #[inline]
   fn seek(&mut self, from: SeekFrom) -> io::Result<u64> {
-    self.file.seek(from)
+    match self.source {
+      Source::File(ref mut file) => file.seek(from),
+      Source::Window(ref mut file, base) => {
+        let from: SeekFrom = match from {
+          SeekFrom::Start(offset) => SeekFrom::Start(base + offset),
+          SeekFrom::End(offset) => SeekFrom::Start(
+            (base + self.size)
+              .checked_add_signed(offset)
+              .ok_or(io::ErrorKind::InvalidInput)?,
+          ),
+          SeekFrom::Current(offset) => SeekFrom::Current(offset),
+        };
+
+        file
+          .seek(from)?
+          .checked_sub(base)
+          .ok_or_else(|| io::ErrorKind::InvalidInput.into())
+      }
+      Source::Memory(ref mut data) => data.seek(from),
+    }
   }
 }
 
+// =============================================================================
+// Handle Source
+// =============================================================================
+
+/// The source of [`Handle`] data.
+#[derive(Debug)]
+pub enum Source {
+  /// A whole file.
+  File(BufReader<File>),
+  /// A range of a file, starting at the given offset.
+  Window(BufReader<File>, u64),
+  /// Data in memory.
+  Memory(Cursor<Arc<[u8]>>),
+}
+
 only_serde! {
   use serde::ser::SerializeStruct;
   use serde::Serialize;
@@ -1,6 +1,14 @@
 mod handle;
+mod options;
 mod parser;
 
 pub use self::handle::Handle;
+pub use self::handle::Source;
+pub use self::options::OpenOptions;
+pub use self::options::Verify;
 pub use self::parser::read_archive;
+pub use self::parser::read_archive_from;
+pub use self::parser::read_archive_repair;
+pub use self::parser::read_archive_with;
 pub use self::parser::read_header;
+pub use self::parser::scan_archives;
@@ -0,0 +1,155 @@
+use std::path::Path;
+
+use crate::error::Result;
+use crate::extract::Limits;
+use crate::parse::read_archive_with;
+use crate::parse::Handle;
+use crate::types::Archive;
+
+// =============================================================================
+// Open Options
+// =============================================================================
+
+/// Options that control how an archive is located and parsed.
+///
+/// ## Defaults
+///
+/// - the header is scanned for from the start until the end of the file
+/// - V4 header/table sizes and MD5s are [strictly verified][Verify::Strict]
+/// - user data blocks are followed
+/// - the header version is read from the file
+/// - files larger than 128 MB are rejected
+/// - HET/BET tables and the strong signature are loaded
+/// - files are extracted with the default [`Limits`]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct OpenOptions {
+  pub(crate) offset: u64,
+  pub(crate) max_scan: Option<u64>,
+  pub(crate) verify: Verify,
+  pub(crate) user_data: bool,
+  pub(crate) version: Option<u16>,
+  pub(crate) max_size: u64,
+  pub(crate) ext_tables: bool,
+  pub(crate) signature: bool,
+  pub(crate) limits: Limits,
+}
+
+impl OpenOptions {
+  /// Create a new `OpenOptions` with the default settings.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      offset: 0,
+      max_scan: None,
+      verify: Verify::Strict,
+      user_data: true,
+      version: None,
+      max_size: Handle::MAX,
+      ext_tables: true,
+      signature: true,
+      limits: Limits::new(),
+    }
+  }
+
+  /// Set the offset (absolute) at which the search for the header starts.
+  ///
+  /// See [`Archive::scan_all`] for finding the offsets of embedded archives.
+  #[inline]
+  pub fn offset(&mut self, value: u64) -> &mut Self {
+    self.offset = value;
+    self
+  }
+
+  /// Set how far (bytes) past the start offset the header is searched for.
+  ///
+  /// `None` scans until the end of the file.
+  #[inline]
+  pub fn max_scan(&mut self, value: Option<u64>) -> &mut Self {
+    self.max_scan = value;
+    self
+  }
+
+  /// Set how V4 header/table sizes and MD5 digests are verified.
+  #[inline]
+  pub fn verify(&mut self, value: Verify) -> &mut Self {
+    self.verify = value;
+    self
+  }
+
+  /// Set whether user data blocks are followed to locate the header.
+  ///
+  /// If `false`, user data blocks are skipped like any other non-header data.
+  #[inline]
+  pub fn user_data(&mut self, value: bool) -> &mut Self {
+    self.user_data = value;
+    self
+  }
+
+  /// Force the header to be parsed as the given format version.
+  ///
+  /// `None` uses the version stored in the header.
+  #[inline]
+  pub fn version(&mut self, value: Option<u16>) -> &mut Self {
+    self.version = value;
+    self
+  }
+
+  /// Set the maximum size (bytes) of the archive file.
+  #[inline]
+  pub fn max_size(&mut self, value: u64) -> &mut Self {
+    self.max_size = value;
+    self
+  }
+
+  /// Set whether the HET/BET tables are loaded.
+  #[inline]
+  pub fn ext_tables(&mut self, value: bool) -> &mut Self {
+    self.ext_tables = value;
+    self
+  }
+
+  /// Set whether the strong digital signature is loaded.
+  #[inline]
+  pub fn signature(&mut self, value: bool) -> &mut Self {
+    self.signature = value;
+    self
+  }
+
+  /// Set the resource limits enforced when extracting files.
+  #[inline]
+  pub fn limits(&mut self, value: Limits) -> &mut Self {
+    self.limits = value;
+    self
+  }
+
+  /// Parse an archive from the file at the given `path` with these options.
+  #[inline]
+  pub fn open<P>(&self, path: &P) -> Result<Archive>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    read_archive_with(path, self)
+  }
+}
+
+impl Default for OpenOptions {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// =============================================================================
+// Verification Mode
+// =============================================================================
+
+/// How V4 header/table sizes and MD5 digests are verified.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verify {
+  /// Fail to open the archive on a mismatch.
+  Strict,
+  /// Record a diagnostic on a mismatch and continue.
+  Warn,
+  /// Skip verification entirely.
+  Off,
+}
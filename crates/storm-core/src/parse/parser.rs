@@ -1,9 +1,14 @@
+use std::collections::BTreeSet;
 use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::OnceLock;
 use storm_utils::traits::Parse;
 use storm_utils::traits::ReadExt;
 use storm_utils::traits::SeekExt;
 use storm_utils::utils::DigestMd5;
 
+use crate::consts::BT_MASK;
 use crate::consts::HJUMP;
 use crate::consts::MAGIC_ID;
 use crate::consts::MAGIC_SIGN;
@@ -12,30 +17,160 @@ use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
 use crate::parse::Handle;
+use crate::parse::OpenOptions;
+use crate::parse::Verify;
 use crate::traits::ExtTable;
 use crate::traits::ExtTableHeader;
 use crate::traits::Table;
 use crate::traits::TableEntry;
+use crate::types::btable_position;
+use crate::types::htable_position;
 use crate::types::Archive;
 use crate::types::BTable;
+use crate::types::BTableEntryFlags;
+use crate::types::Diagnostic;
 use crate::types::ExtBTable;
 use crate::types::ExtHTable;
 use crate::types::HTable;
+use crate::types::HTableEntry;
 use crate::types::Header;
+use crate::types::HeaderV1;
+use crate::types::HeaderV2;
+use crate::types::HeaderV3;
 use crate::types::HeaderV4;
+use crate::types::Heuristic;
 use crate::types::Magic;
+use crate::types::Severity;
 use crate::types::Signature;
 use crate::types::UserData;
 use crate::utils;
 
 /// Parse an archive from the file at the given `path`.
+#[inline]
 pub fn read_archive<P>(path: &P) -> Result<Archive>
 where
   P: AsRef<Path> + ?Sized,
 {
-  Handle::new(path)
-    .map(Buffer::new)
+  read_archive_with(path, &OpenOptions::new())
+}
+
+/// Parse an archive from the file at the given `path` with custom `options`.
+pub fn read_archive_with<P>(path: &P, options: &OpenOptions) -> Result<Archive>
+where
+  P: AsRef<Path> + ?Sized,
+{
+  Handle::with_max_size(path, options.max_size)
+    .map(|reader| Buffer::new(reader, *options))
     .and_then(Buffer::parse_archive)
+    .map_err(|error| error.with_path(path.as_ref()))
+}
+
+/// Parse an archive from the given `handle` with custom `options`.
+///
+/// `options.max_size` is ignored; the handle is already open.
+pub fn read_archive_from(handle: Handle, options: &OpenOptions) -> Result<Archive> {
+  let path: PathBuf = handle.path().to_owned();
+
+  Buffer::new(handle, *options)
+    .parse_archive()
+    .map_err(|error| error.with_path(&path))
+}
+
+/// Leniently parse an archive from the file at the given `path`.
+///
+/// Returns the archive along with every recovery heuristic that was applied.
+pub fn read_archive_repair<P>(path: &P) -> Result<(Archive, Vec<Heuristic>)>
+where
+  P: AsRef<Path> + ?Sized,
+{
+  let options: OpenOptions = OpenOptions::new();
+  let mut buffer: Buffer = Handle::new(path).map(|reader| Buffer::new(reader, options))?;
+
+  buffer.repair = Some(Vec::new());
+  buffer
+    .parse_archive_repair()
+    .map_err(|error| error.with_path(path.as_ref()))
+}
+
+/// Find the offset (absolute) of every valid archive header in the file at the
+/// given `path`.
+///
+/// Headers are searched at every 512-byte boundary and at the offset given by
+/// each user data block. Every offset is checked by parsing the header and
+/// checking that its tables are in the file, so random matches of the header
+/// signature are not reported.
+///
+/// The size of the file is not limited (e.g. installers).
+pub fn scan_archives<P>(path: &P) -> Result<Vec<u64>>
+where
+  P: AsRef<Path> + ?Sized,
+{
+  let mut reader: Handle =
+    Handle::with_max_size(path, u64::MAX).map_err(|error| error.with_path(path.as_ref()))?;
+
+  let mut output: BTreeSet<u64> = BTreeSet::new();
+  let mut magic: [u8; 4] = [0; 4];
+  let mut offset: u64 = 0;
+
+  while offset + HeaderV1::SIZE as u64 <= reader.size() {
+    reader.seek_start(offset)?;
+    reader.read_bytes(&mut magic)?;
+
+    if magic == MAGIC_ID && is_archive(&reader, offset) {
+      output.insert(offset);
+    }
+
+    // The user data block points to the header, which may not be aligned
+    if magic == MAGIC_UD {
+      let udata: UserData = reader.parse_context(Magic::UD)?;
+      let target: u64 = offset + u64::from(udata.header_offset);
+
+      if target != offset && target + HeaderV1::SIZE as u64 <= reader.size() {
+        reader.seek_start(target)?;
+        reader.read_bytes(&mut magic)?;
+
+        if magic == MAGIC_ID && is_archive(&reader, target) {
+          output.insert(target);
+        }
+      }
+    }
+
+    offset += HJUMP;
+  }
+
+  Ok(output.into_iter().collect())
+}
+
+// Returns `true` if a plausible archive header is located at `offset`
+fn is_archive(reader: &Handle, offset: u64) -> bool {
+  let mut options: OpenOptions = OpenOptions::new();
+
+  options.offset(offset);
+  options.max_scan(Some(0));
+  options.user_data(false);
+
+  let Ok(header) = reader
+    .duplicate_exact()
+    .map(|reader| Buffer::new(reader, options))
+    .and_then(Buffer::parse_header)
+  else {
+    return false;
+  };
+
+  if (header.header_size as usize) < HeaderV1::SIZE {
+    return false;
+  }
+
+  // The header and tables must be in the file
+  let regions: [(u64, u64); 3] = [
+    (0, u64::from(header.header_size)),
+    htable_position(&header),
+    btable_position(&header),
+  ];
+
+  regions.into_iter().all(|(start, size)| {
+    size == 0 || offset.saturating_add(start).saturating_add(size) <= reader.size()
+  })
 }
 
 /// Parse an archive header from the file at the given `path`.
@@ -44,8 +179,9 @@ where
   P: AsRef<Path> + ?Sized,
 {
   Handle::new(path)
-    .map(Buffer::new)
+    .map(|reader| Buffer::new(reader, OpenOptions::new()))
     .and_then(Buffer::parse_header)
+    .map_err(|error| error.with_path(path.as_ref()))
 }
 
 // =============================================================================
@@ -66,26 +202,32 @@ where
 
 #[derive(Debug)]
 struct Buffer {
-  offset: u64,             // current position in the reader
-  reader: Handle,          // file handle
-  buffer: Vec<u8>,         // buffer re-used to avoid extra allocations
-  magic: [u8; 4],          // "magic"
-  found: bool,             // true if the archive header was found
-  udata: Option<UserData>, // additional user-data
+  offset: u64,                    // current position in the reader
+  reader: Handle,                 // file handle
+  buffer: Vec<u8>,                // buffer re-used to avoid extra allocations
+  magic: [u8; 4],                 // "magic"
+  found: bool,                    // true if the archive header was found
+  udata: Option<UserData>,        // additional user-data
+  repair: Option<Vec<Heuristic>>, // applied heuristics (lenient mode only)
+  options: OpenOptions,           // parse options
+  diagnostics: Vec<Diagnostic>,   // non-fatal oddities
 }
 
 impl Buffer {
   const BUFFER: usize = 0x200;
 
   #[inline]
-  fn new(reader: Handle) -> Self {
+  fn new(reader: Handle, options: OpenOptions) -> Self {
     Self {
-      offset: 0,
+      offset: options.offset,
       reader,
       buffer: vec![0; Self::BUFFER],
       magic: [0; 4],
       found: false,
       udata: None,
+      repair: None,
+      options,
+      diagnostics: Vec::new(),
     }
   }
 
@@ -94,21 +236,98 @@ impl Buffer {
     self.offset + offset
   }
 
+  // Records the heuristic and returns `true` if parsing leniently
+  #[inline]
+  fn recover(&mut self, heuristic: Heuristic) -> bool {
+    if let Some(ref mut repair) = self.repair {
+      repair.push(heuristic);
+      true
+    } else {
+      false
+    }
+  }
+
+  // Returns `true` if V4 sizes and digests should be checked
+  #[inline]
+  fn is_verify(&self) -> bool {
+    self.repair.is_some() || self.options.verify != Verify::Off
+  }
+
   #[inline]
   fn parse_header(mut self) -> Result<Header> {
     self.header()
   }
 
+  fn parse_archive_repair(mut self) -> Result<(Archive, Vec<Heuristic>)> {
+    let mut header: Header = self.header()?;
+    let mut htable: HTable = self.table(&header)?;
+    let mut btable: BTable = self.table(&header)?;
+
+    self.inspect(&header, &htable, &btable);
+    self.repair_tables(&mut header, &mut htable, &mut btable);
+
+    let signature: Option<Signature> = self.signature(&header)?;
+
+    let mut ext_htable: Option<ExtHTable> = None;
+    let mut ext_btable: Option<ExtBTable> = None;
+
+    if let Some(header) = header.v4() {
+      if header.het_table_position != 0 {
+        match self.etable(header) {
+          Ok(table) => ext_htable = Some(table),
+          Err(_) => {
+            self.recover(Heuristic::ExtTableIgnored(ExtHTable::NAME));
+          }
+        }
+      }
+
+      if header.bet_table_position != 0 {
+        match self.etable(header) {
+          Ok(table) => ext_btable = Some(table),
+          Err(_) => {
+            self.recover(Heuristic::ExtTableIgnored(ExtBTable::NAME));
+          }
+        }
+      }
+    }
+
+    let heuristics: Vec<Heuristic> = self.repair.take().unwrap_or_default();
+
+    let archive: Archive = Archive {
+      handle: self.reader,
+      offset: self.offset,
+      header,
+      udata: self.udata.take(),
+      htable,
+      btable,
+      ext_htable,
+      ext_btable,
+      signature,
+      limits: self.options.limits,
+      extracted: AtomicU64::new(0),
+      attributes: OnceLock::new(),
+      diagnostics: self.diagnostics,
+    };
+
+    Ok((archive, heuristics))
+  }
+
   fn parse_archive(mut self) -> Result<Archive> {
     let header: Header = self.header()?;
     let htable: HTable = self.table(&header)?;
     let btable: BTable = self.table(&header)?;
-    let signature: Option<Signature> = self.signature(&header)?;
 
+    self.inspect(&header, &htable, &btable);
+
+    let mut signature: Option<Signature> = None;
     let mut ext_htable: Option<ExtHTable> = None;
     let mut ext_btable: Option<ExtBTable> = None;
 
-    if let Some(header) = header.v4() {
+    if self.options.signature {
+      signature = self.signature(&header)?;
+    }
+
+    if let (true, Some(header)) = (self.options.ext_tables, header.v4()) {
       if header.het_table_position != 0 {
         ext_htable = Some(self.etable(header)?);
       }
@@ -128,6 +347,10 @@ impl Buffer {
       ext_htable,
       ext_btable,
       signature,
+      limits: self.options.limits,
+      extracted: AtomicU64::new(0),
+      attributes: OnceLock::new(),
+      diagnostics: self.diagnostics,
     })
   }
 
@@ -135,9 +358,15 @@ impl Buffer {
     let header: Header = self.scan_header()?;
 
     // Verify MD5 if we have a V4 header.
-    if let Some(header) = header.v4() {
+    if let (true, Some(header)) = (self.is_verify(), header.v4()) {
       if header.digest() != header.md5_mpq_header {
-        return Err(Error::new(ErrorKind::InvalidMd5("header")));
+        mismatch(
+          &mut self.repair,
+          &mut self.diagnostics,
+          self.options.verify,
+          ErrorKind::InvalidMd5("header"),
+          self.offset,
+        )?;
       }
     }
 
@@ -149,37 +378,55 @@ impl Buffer {
     loop {
       // If we haven't found the correct offset, keep seeking.
       if !self.found {
+        if let Some(max_scan) = self.options.max_scan {
+          if self.offset > self.options.offset.saturating_add(max_scan) {
+            return Err(Error::new(ErrorKind::InvalidMagic));
+          }
+        }
+
         self.reader.seek_start(self.seek(0))?;
         self.reader.read_bytes(&mut self.magic)?;
       }
 
       if self.found || self.magic == MAGIC_ID {
-        return self.reader.parse_context(Magic::ID);
-      } else if self.magic == MAGIC_UD {
-        if self.found {
-          eprintln!("[warning]: Multiple User Data Blocks");
-        }
-
+        return if self.repair.is_some() {
+          self.lenient_header()
+        } else if let Some(version) = self.options.version {
+          self.forced_header(version)
+        } else {
+          self.reader.parse_context(Magic::ID)
+        };
+      } else if self.magic == MAGIC_UD && self.options.user_data {
         // Parse user data block
         let udata: UserData = self.reader.parse_context(Magic::UD)?;
 
         // Sanity Checks
-        assert!(udata.udata_header_size <= udata.udata_size);
-        assert!(udata.udata_size <= udata.header_offset);
+        if !self.is_udata_valid(&udata)? {
+          if self.recover(Heuristic::UserDataIgnored {
+            offset: self.offset,
+          }) {
+            self.offset += HJUMP;
+            continue;
+          }
+
+          return Err(Error::new(ErrorKind::FileCorruptData));
+        }
 
         // Increment the file offset accordingly
         self.offset += u64::from(udata.header_offset);
 
-        // Move the cursor to the new offset and check header "magic"
-        self.reader.seek_start(self.seek(0))?;
-        self.reader.read_bytes(&mut self.magic)?;
-
         // If it looks like a header, flag it and parse on next iteration
         if self.magic == MAGIC_ID {
           self.found = true;
           self.udata = Some(udata);
         } else {
-          return Err(Error::new(ErrorKind::FileCorruptData));
+          Diagnostic::new(
+            "multiple-user-data",
+            Severity::Warning,
+            "user data block points to another user data block",
+          )
+          .with_offset(self.offset)
+          .report(&mut self.diagnostics);
         }
       } else {
         // Didn't find a matching header - skip to the next sector
@@ -188,11 +435,227 @@ impl Buffer {
     }
   }
 
+  // Returns `true` if the user data block points to a real archive header (or
+  // another user data block)
+  fn is_udata_valid(&mut self, udata: &UserData) -> Result<bool> {
+    if udata.header_offset == 0 {
+      return Ok(false);
+    }
+
+    if udata.udata_header_size > udata.udata_size {
+      return Ok(false);
+    }
+
+    if udata.udata_size > udata.header_offset {
+      return Ok(false);
+    }
+
+    let position: u64 = self.seek(u64::from(udata.header_offset));
+
+    // Check there is enough room for a header at the new offset
+    if position + HeaderV1::SIZE as u64 > self.reader.size() {
+      return Ok(false);
+    }
+
+    // Move the cursor to the new offset and check header "magic"
+    self.reader.seek_start(position)?;
+    self.reader.read_bytes(&mut self.magic)?;
+
+    Ok(self.magic == MAGIC_ID || self.magic == MAGIC_UD)
+  }
+
+  // Parse the header, treating unknown or undersized versions as V1
+  fn lenient_header(&mut self) -> Result<Header> {
+    let v1: HeaderV1 = self.reader.parse_context(Magic::ID)?;
+
+    let required: usize = match v1.format_version {
+      Header::VER1 => HeaderV1::SIZE,
+      Header::VER2 => HeaderV2::SIZE,
+      Header::VER3 => HeaderV3::SIZE,
+      Header::VER4 => HeaderV4::SIZE,
+      version => {
+        self.recover(Heuristic::HeaderVersion(version));
+        return Ok(Header::V1(v1));
+      }
+    };
+
+    if (v1.header_size as usize) < required {
+      self.recover(Heuristic::HeaderSize(v1.header_size));
+      return Ok(Header::V1(v1));
+    }
+
+    self.header_as(v1, v1.format_version)
+  }
+
+  // Parse the header as the given format `version`, ignoring the stored value
+  fn forced_header(&mut self, version: u16) -> Result<Header> {
+    let v1: HeaderV1 = self.reader.parse_context(Magic::ID)?;
+
+    self.header_as(v1, version)
+  }
+
+  fn header_as(&mut self, v1: HeaderV1, version: u16) -> Result<Header> {
+    match version {
+      Header::VER1 => Ok(Header::V1(v1)),
+      Header::VER2 => self.reader.parse_context(v1).map(Header::V2),
+      Header::VER3 => self.reader.parse_context(v1).map(Header::V3),
+      Header::VER4 => self.reader.parse_context(v1).map(Header::V4),
+      _ => Err(Error::message(format_args!(
+        "unknown header version: {version}"
+      ))),
+    }
+  }
+
+  // Report non-standard (but tolerated) table data
+  fn inspect(&mut self, header: &Header, htable: &HTable, btable: &BTable) {
+    if header.header_size as usize != header.size() {
+      Diagnostic::new(
+        "header-size",
+        Severity::Info,
+        format_args!(
+          "header size {:#X} does not match version size {:#X}",
+          header.header_size,
+          header.size(),
+        ),
+      )
+      .with_offset(self.offset)
+      .report(&mut self.diagnostics);
+    }
+
+    if !htable.len().is_power_of_two() {
+      Diagnostic::new(
+        "hash-table-size",
+        Severity::Warning,
+        format_args!("hash table size {} is not a power of two", htable.len()),
+      )
+      .with_offset(self.seek(u64::from(header.htable_offset)))
+      .report(&mut self.diagnostics);
+    }
+
+    let masked: usize = htable
+      .iter()
+      .filter(|entry| !entry.is_empty() && entry.position > BT_MASK)
+      .count();
+
+    if masked != 0 {
+      Diagnostic::new(
+        "block-index-masked",
+        Severity::Warning,
+        format_args!("{masked} hash table entries have block index bits above BT_MASK"),
+      )
+      .with_offset(self.seek(u64::from(header.htable_offset)))
+      .report(&mut self.diagnostics);
+    }
+
+    let file_end: u64 = self.reader.size();
+
+    for (index, entry) in btable.iter().enumerate() {
+      let start: u64 = self.seek(u64::from(entry.offset));
+      let end: u64 = start + u64::from(entry.comp_size);
+
+      if entry.is_exists() && end > file_end {
+        Diagnostic::new(
+          "block-outside",
+          Severity::Warning,
+          format_args!("block {index} extends beyond the end of the file"),
+        )
+        .with_offset(start)
+        .report(&mut self.diagnostics);
+      }
+    }
+  }
+
+  // Apply post-parse recovery heuristics (lenient mode only)
+  fn repair_tables(&mut self, header: &mut Header, htable: &mut HTable, btable: &mut BTable) {
+    if !htable.len().is_power_of_two() {
+      self.recover(Heuristic::HashTableSize(htable.len() as u32));
+    }
+
+    // Protectors set the high bits of block indices - the game ignores them
+    for (slot, entry) in htable.data.iter_mut().enumerate() {
+      if !entry.is_empty() && entry.position > BT_MASK {
+        entry.position &= BT_MASK;
+
+        self.recover(Heuristic::BlockIndexMasked { slot: slot as u32 });
+      }
+    }
+
+    let limit: u64 = self.reader.size() - self.offset;
+    let mut actual: u64 = u64::from(header.header_size).min(limit);
+
+    for (index, entry) in btable.data.iter_mut().enumerate() {
+      if !entry.is_exists() {
+        continue;
+      }
+
+      let end: u64 = u64::from(entry.offset) + u64::from(entry.comp_size);
+
+      if end > limit {
+        entry.bitflags.remove(BTableEntryFlags::EXISTS);
+
+        self.recover(Heuristic::BlockOutOfBounds {
+          block: index as u32,
+        });
+      } else {
+        actual = actual.max(end);
+      }
+    }
+
+    let htable_end: u64 =
+      u64::from(header.htable_offset) + (htable.len() * HTableEntry::SIZE) as u64;
+    let btable_end: u64 = u64::from(header.btable_offset) + btable.size() as u64;
+
+    actual = actual.max(htable_end).max(btable_end);
+
+    if let Some(v2) = header.v2() {
+      if v2.hi_btable_offset != 0 {
+        actual = actual.max(v2.hi_btable_offset + btable.len() as u64 * 2);
+      }
+    }
+
+    // Protectors use a fake archive size to hide the signature and tables
+    //
+    // Note: Trailing free space and tables not covered above (HET/BET, 64-bit
+    //       offsets) are legitimate, so only sizes that can't be right are
+    //       replaced.
+    let claimed: u64 = u64::from(header.archive_size);
+
+    if claimed < actual || claimed > limit {
+      let actual: u32 = if claimed < actual { actual } else { limit } as u32;
+      let claimed: u32 = header.archive_size;
+
+      header.v1_mut().archive_size = actual;
+
+      self.recover(Heuristic::ArchiveSize { claimed, actual });
+    }
+  }
+
   fn table<T: Table>(&mut self, header: &Header) -> Result<T> {
-    let entries: usize = T::entries(header) as usize;
-    let capacity: usize = entries * T::Entry::SIZE;
+    let mut entries: usize = T::entries(header) as usize;
     let position: u64 = self.seek(u64::from(T::offset(header)));
 
+    // Don't allocate for a table that can't fit in the file
+    //
+    // Note: Protectors use oversized tables that extend beyond the end of the
+    //       file, which are truncated when parsing leniently.
+    let available: u64 = self.reader.size().saturating_sub(position) / T::Entry::SIZE as u64;
+
+    if entries as u64 > available {
+      entries = available as usize;
+
+      let heuristic: Heuristic = Heuristic::TableTruncated {
+        table: T::NAME,
+        entries: entries as u32,
+      };
+
+      if !self.recover(heuristic) {
+        return Err(Error::new(ErrorKind::InvalidLen(T::NAME)).with_offset(position));
+      }
+    }
+
+    let capacity: usize = entries * T::Entry::SIZE;
+    let verify: bool = self.is_verify();
+
     // Clear the buffer and ensure we have enough capacity
     self.buffer.clear();
     self.buffer.resize(capacity, 0);
@@ -202,17 +665,34 @@ impl Buffer {
 
     // Seek to the table and read into the buffer
     self.reader.seek_start(position)?;
-    self.reader.read_bytes(window)?;
+    self
+      .reader
+      .read_bytes(window)
+      .map_err(|error| Error::from(error).with_offset(position))?;
 
-    if let Some(header) = header.v4() {
+    if let (true, Some(header)) = (verify, header.v4()) {
       // Verify table size if we have a V4 header.
       if window.len() as u64 != T::comp_size(header) {
-        return Err(Error::new(ErrorKind::InvalidLen(T::NAME)));
+        let kind: ErrorKind = ErrorKind::InvalidLen(T::NAME);
+        mismatch(
+          &mut self.repair,
+          &mut self.diagnostics,
+          self.options.verify,
+          kind,
+          position,
+        )?;
       }
 
       // Verify MD5 if we have a V4 header.
       if DigestMd5::new(window) != T::digest(header) {
-        return Err(Error::new(ErrorKind::InvalidMd5(T::NAME)));
+        let kind: ErrorKind = ErrorKind::InvalidMd5(T::NAME);
+        mismatch(
+          &mut self.repair,
+          &mut self.diagnostics,
+          self.options.verify,
+          kind,
+          position,
+        )?;
       }
     }
 
@@ -236,6 +716,12 @@ impl Buffer {
   fn etable<T: ExtTable>(&mut self, header: &HeaderV4) -> Result<T> {
     let capacity: usize = T::comp_size(header) as usize;
     let position: u64 = self.seek(T::offset(header));
+    let verify: bool = self.is_verify();
+
+    // Don't allocate for a table that can't fit in the file
+    if position.saturating_add(capacity as u64) > self.reader.size() {
+      return Err(Error::new(ErrorKind::InvalidLen(T::NAME)));
+    }
 
     // Clear the buffer and ensure we have enough capacity
     self.buffer.clear();
@@ -246,16 +732,35 @@ impl Buffer {
 
     // Seek to the table and read into the buffer
     self.reader.seek_start(position)?;
-    self.reader.read_bytes(window)?;
+    self
+      .reader
+      .read_bytes(window)
+      .map_err(|error| Error::from(error).with_offset(position))?;
 
-    // Verify table size.
-    if window.len() as u64 != T::comp_size(header) {
-      return Err(Error::new(ErrorKind::InvalidLen(T::NAME)));
-    }
+    if verify {
+      // Verify table size.
+      if window.len() as u64 != T::comp_size(header) {
+        let kind: ErrorKind = ErrorKind::InvalidLen(T::NAME);
+        mismatch(
+          &mut self.repair,
+          &mut self.diagnostics,
+          self.options.verify,
+          kind,
+          position,
+        )?;
+      }
 
-    // Verify MD5
-    if DigestMd5::new(window) != T::digest(header) {
-      return Err(Error::new(ErrorKind::InvalidMd5(T::NAME)));
+      // Verify MD5
+      if DigestMd5::new(window) != T::digest(header) {
+        let kind: ErrorKind = ErrorKind::InvalidMd5(T::NAME);
+        mismatch(
+          &mut self.repair,
+          &mut self.diagnostics,
+          self.options.verify,
+          kind,
+          position,
+        )?;
+      }
     }
 
     // Read the table header
@@ -293,3 +798,34 @@ impl Buffer {
     }
   }
 }
+
+// Handle a failed V4 size/digest check according to the parse mode
+fn mismatch(
+  repair: &mut Option<Vec<Heuristic>>,
+  diagnostics: &mut Vec<Diagnostic>,
+  verify: Verify,
+  kind: ErrorKind,
+  offset: u64,
+) -> Result<()> {
+  if let Some(repair) = repair {
+    match kind {
+      ErrorKind::InvalidLen(name) => repair.push(Heuristic::LengthMismatch(name)),
+      ErrorKind::InvalidMd5(name) => repair.push(Heuristic::DigestMismatch(name)),
+      _ => {}
+    }
+
+    return Ok(());
+  }
+
+  match verify {
+    Verify::Strict => Err(Error::new(kind).with_offset(offset)),
+    Verify::Warn => {
+      Diagnostic::new("verify-mismatch", Severity::Warning, Error::new(kind))
+        .with_offset(offset)
+        .report(diagnostics);
+
+      Ok(())
+    }
+    Verify::Off => Ok(()),
+  }
+}
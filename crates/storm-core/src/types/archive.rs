@@ -1,19 +1,36 @@
 use std::path::Path;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::OnceLock;
 
 use crate::error::Result;
+use crate::extract::extract_all;
 use crate::extract::find_file;
 use crate::extract::FilePtr;
+use crate::extract::Limits;
+use crate::extract::NameIndex;
+use crate::extract::Pattern;
+use crate::extract::Search;
+use crate::extract::Tracker;
 use crate::parse::read_archive;
+use crate::parse::read_archive_with;
+use crate::parse::scan_archives;
 use crate::parse::Handle;
+use crate::parse::OpenOptions;
 use crate::types::AttrFile;
 use crate::types::BTable;
+use crate::types::Diagnostic;
 use crate::types::ExtBTable;
 use crate::types::ExtHTable;
 use crate::types::File;
 use crate::types::HTable;
 use crate::types::Header;
+use crate::types::Layout;
 use crate::types::ListFile;
+use crate::types::Orphan;
+use crate::types::Repair;
 use crate::types::Signature;
+use crate::types::Stats;
 use crate::types::UserData;
 
 // =============================================================================
@@ -41,6 +58,14 @@ pub struct Archive {
   pub ext_btable: Option<ExtBTable>,
   /// Strong Digital Signature.
   pub signature: Option<Signature>,
+  /// Resource limits enforced when extracting files.
+  pub limits: Limits,
+  /// Total uncompressed size of all files extracted so far.
+  pub(crate) extracted: AtomicU64,
+  /// The `(attributes)`, parsed on first use.
+  pub(crate) attributes: OnceLock<Option<AttrFile>>,
+  /// Non-fatal oddities found while parsing.
+  pub(crate) diagnostics: Vec<Diagnostic>,
 }
 
 impl Archive {
@@ -86,12 +111,50 @@ impl Archive {
     self.signature.as_ref()
   }
 
+  /// Returns the non-fatal oddities found while parsing.
+  #[inline]
+  pub fn diagnostics(&self) -> &[Diagnostic] {
+    &self.diagnostics
+  }
+
+  /// Returns the total uncompressed size (bytes) of all files extracted so far.
+  #[inline]
+  pub fn extracted(&self) -> u64 {
+    self.extracted.load(Ordering::Relaxed)
+  }
+
   /// Returns the size of each logical sector in the archive.
   #[inline]
   pub fn sector_size(&self) -> u32 {
     0x200 << self.header.sector_size_shift
   }
 
+  /// Returns a map of every byte range in the archive file.
+  #[inline]
+  pub fn layout(&self) -> Layout {
+    Layout::new(self)
+  }
+
+  /// Find and try to extract blocks with file data that no live hash table
+  /// entry points to (e.g. deleted files).
+  #[inline]
+  pub fn orphans(&self) -> Vec<Orphan> {
+    Orphan::find_all(self)
+  }
+
+  /// Like [`Archive::orphans`], reporting progress and checking for
+  /// cancellation before each block.
+  #[inline]
+  pub fn orphans_with(&self, tracker: &mut Tracker<'_>) -> Result<Vec<Orphan>> {
+    Orphan::find_all_with(self, tracker)
+  }
+
+  /// Returns usage and health statistics of the archive.
+  #[inline]
+  pub fn stats(&self) -> Stats {
+    Stats::new(self)
+  }
+
   /// Parse an archive from the file at the given `path`.
   #[inline]
   pub fn open<P>(path: &P) -> Result<Self>
@@ -101,12 +164,75 @@ impl Archive {
     read_archive(path)
   }
 
+  /// Parse an archive from the file at the given `path` with custom `options`.
+  #[inline]
+  pub fn open_with<P>(path: &P, options: &OpenOptions) -> Result<Self>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    read_archive_with(path, options)
+  }
+
+  /// Find the offset (absolute) of every archive embedded in the file at the
+  /// given `path`.
+  ///
+  /// Each archive can be opened with [`OpenOptions::offset`].
+  #[inline]
+  pub fn scan_all<P>(path: &P) -> Result<Vec<u64>>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    scan_archives(path)
+  }
+
+  /// Leniently parse a protected or damaged archive from the file at the given
+  /// `path`, applying every known recovery heuristic.
+  ///
+  /// See [`Repair`] for writing a normalised copy of the archive.
+  #[inline]
+  pub fn open_repair<P>(path: &P) -> Result<Repair>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    Repair::open(path)
+  }
+
   /// Search the archive for a file with the given `name`.
   #[inline]
   pub fn find_file<'a>(&'a self, name: &'a str) -> Result<FilePtr<'a>> {
     find_file(self, name)
   }
 
+  /// Build an index of the hash table (and HET table) for fast repeated
+  /// lookups.
+  #[inline]
+  pub fn name_index(&self) -> NameIndex<'_> {
+    NameIndex::new(self)
+  }
+
+  /// Search the archive for files with names matching the given `pattern`.
+  ///
+  /// See [`Search`] for which names are searched.
+  #[inline]
+  pub fn find_files<P>(&self, pattern: P) -> Search<'_>
+  where
+    P: Into<Pattern>,
+  {
+    Search::new(self, pattern.into())
+  }
+
+  /// Extract every file to the directory at the given `path`, reporting
+  /// progress and checking for cancellation before each file.
+  ///
+  /// See [`Search`] for which names are extracted; names that would escape
+  /// the directory are skipped. Returns the number of files written.
+  pub fn extract_all<P>(&self, path: &P, tracker: &mut Tracker<'_>) -> Result<u64>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    extract_all(self, path.as_ref(), tracker)
+  }
+
   /// Find and load a file with the given `name`.
   pub fn load_file(&self, name: &str) -> Result<File> {
     self.find_file(name).and_then(FilePtr::read)
@@ -122,6 +248,15 @@ impl Archive {
     self.find_file("(attributes)").and_then(AttrFile::try_from)
   }
 
+  /// Returns the `(attributes)`, loaded once and cached; `None` if missing or
+  /// damaged.
+  pub(crate) fn attributes(&self) -> Option<&AttrFile> {
+    self
+      .attributes
+      .get_or_init(|| self.load_attributes().ok())
+      .as_ref()
+  }
+
   /// Load the `(signature)` from the archive.
   pub fn load_signature(&self) -> Result<File> {
     self.load_file("(signature)")
@@ -140,7 +275,7 @@ only_serde! {
 
   impl Serialize for Archive {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-      let mut state: S::SerializeStruct = serializer.serialize_struct("Archive", 9)?;
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Archive", 10)?;
       state.serialize_field("handle", &self.handle)?;
       state.serialize_field("offset", &self.offset)?;
       state.serialize_field("header", &self.header)?;
@@ -150,6 +285,7 @@ only_serde! {
       state.serialize_field("ext_htable", &self.ext_htable)?;
       state.serialize_field("ext_btable", &self.ext_btable)?;
       state.serialize_field("signature", &self.signature)?;
+      state.serialize_field("diagnostics", &self.diagnostics)?;
       state.end()
     }
   }
@@ -0,0 +1,141 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+only_serde! {
+  use serde::ser::SerializeStruct;
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// Diagnostic
+// =============================================================================
+
+/// A non-fatal oddity found while reading an archive.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Diagnostic {
+  /// Short, stable identifier of the diagnostic (e.g. `hash-table-size`).
+  pub code: &'static str,
+  /// How serious the diagnostic is.
+  pub severity: Severity,
+  /// Offset in the file the diagnostic relates to (absolute), if any.
+  pub offset: Option<u64>,
+  /// Human-readable description.
+  pub message: String,
+}
+
+impl Diagnostic {
+  /// Create a new `Diagnostic`.
+  #[inline]
+  pub fn new(code: &'static str, severity: Severity, message: impl Display) -> Self {
+    Self {
+      code,
+      severity,
+      offset: None,
+      message: message.to_string(),
+    }
+  }
+
+  /// Set the offset the diagnostic relates to.
+  #[inline]
+  pub fn with_offset(mut self, offset: u64) -> Self {
+    self.offset = Some(offset);
+    self
+  }
+
+  /// Emit the diagnostic through the enabled logging facade and add it to
+  /// `output`.
+  pub fn report(self, output: &mut Vec<Self>) {
+    self.emit();
+    output.push(self);
+  }
+
+  /// Emit the diagnostic through the enabled logging facade.
+  ///
+  /// Does nothing unless the `log` or `tracing` feature is enabled.
+  #[inline]
+  pub fn emit(&self) {
+    #[cfg(feature = "log")]
+    log::log!(target: "storm", self.severity.log_level(), "{self}");
+
+    #[cfg(feature = "tracing")]
+    match self.severity {
+      Severity::Info => tracing::info!(code = self.code, offset = self.offset, "{}", self.message),
+      Severity::Warning => {
+        tracing::warn!(code = self.code, offset = self.offset, "{}", self.message)
+      }
+      Severity::Error => {
+        tracing::error!(code = self.code, offset = self.offset, "{}", self.message)
+      }
+    }
+  }
+}
+
+impl Display for Diagnostic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "[{}] {}: {}",
+      self.severity.as_str(),
+      self.code,
+      self.message
+    )?;
+
+    if let Some(offset) = self.offset {
+      write!(f, " (at {offset:#X})")?;
+    }
+
+    Ok(())
+  }
+}
+
+only_serde! {
+  impl Serialize for Diagnostic {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Diagnostic", 4)?;
+      state.serialize_field("code", self.code)?;
+      state.serialize_field("severity", self.severity.as_str())?;
+      state.serialize_field("offset", &self.offset)?;
+      state.serialize_field("message", &self.message)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Diagnostic Severity
+// =============================================================================
+
+/// The severity of a [`Diagnostic`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+  /// Unusual, but harmless.
+  Info,
+  /// Non-standard data that was tolerated.
+  Warning,
+  /// Data that was skipped or could not be used.
+  Error,
+}
+
+impl Severity {
+  /// Returns the name of the severity.
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Info => "info",
+      Self::Warning => "warning",
+      Self::Error => "error",
+    }
+  }
+
+  #[cfg(feature = "log")]
+  #[inline]
+  const fn log_level(&self) -> log::Level {
+    match self {
+      Self::Info => log::Level::Info,
+      Self::Warning => log::Level::Warn,
+      Self::Error => log::Level::Error,
+    }
+  }
+}
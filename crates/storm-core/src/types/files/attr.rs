@@ -5,6 +5,7 @@ use storm_utils::utils::DigestMd5;
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::extract::read_metadata;
 use crate::extract::FilePtr;
 use crate::types::File;
 use crate::utils::convert_filetime;
@@ -28,7 +29,7 @@ pub struct AttrFile {
   pub bitflags: AttrFlags,
   /// CRC32s of the file data (uncompressed) for each block in the archive.
   pub crc: Box<[u32]>,
-  /// Timestamps for each block in the archive.
+  /// Timestamps (unix) for each block in the archive, `0` if unset.
   pub time: Box<[u64]>,
   /// MD5s of the file data (uncompressed) for each block in the archive.
   pub md5: Box<[DigestMd5]>,
@@ -57,7 +58,7 @@ impl TryFrom<FilePtr<'_>> for AttrFile {
   #[inline]
   fn try_from(other: FilePtr<'_>) -> Result<Self, Self::Error> {
     let size: u32 = other.archive.header.btable_entries;
-    let data: File = other.read()?;
+    let data: File = read_metadata(other)?;
 
     Self::new(data, size)
   }
@@ -138,11 +139,8 @@ fn read_time<R: ReadExt>(reader: &mut R, entries: u32, bitflags: AttrFlags) -> R
     let lo: u32 = reader.read_u32_le()?;
     let hi: u32 = reader.read_u32_le()?;
 
-    if lo == 0 && hi == 0 {
-      data.push(0);
-    } else {
-      data.push(convert_filetime(lo, hi));
-    }
+    // Note: Damaged timestamps are treated as unset
+    data.push(convert_filetime(lo, hi).unwrap_or(0));
   }
 
   Ok(data.into_boxed_slice())
@@ -10,6 +10,7 @@ use core::str::from_utf8;
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::extract::read_metadata;
 use crate::extract::FilePtr;
 use crate::types::File;
 
@@ -108,7 +109,7 @@ impl TryFrom<FilePtr<'_>> for ListFile {
 
   #[inline]
   fn try_from(other: FilePtr<'_>) -> Result<Self, Self::Error> {
-    other.read().map(Self::new)
+    read_metadata(other).map(Self::new)
   }
 }
 
@@ -75,6 +75,16 @@ impl Header {
     }
   }
 
+  #[inline]
+  pub(crate) fn v1_mut(&mut self) -> &mut HeaderV1 {
+    match self {
+      Self::V1(header) => header,
+      Self::V2(header) => &mut header.v1,
+      Self::V3(header) => &mut header.v2.v1,
+      Self::V4(header) => &mut header.v3.v2.v1,
+    }
+  }
+
   /// Returns a reference to the the V2 header.
   #[inline]
   pub const fn v2(&self) -> Option<&HeaderV2> {
@@ -131,7 +141,9 @@ impl ParseContext<Magic> for Header {
       Self::VER2 => reader.parse_context(v1).map(Self::V2),
       Self::VER3 => reader.parse_context(v1).map(Self::V3),
       Self::VER4 => reader.parse_context(v1).map(Self::V4),
-      _ => panic!("Handle Unknown Version"),
+      version => Err(Error::message(format_args!(
+        "unknown header version: {version}"
+      ))),
     }
   }
 }
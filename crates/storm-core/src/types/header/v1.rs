@@ -92,10 +92,27 @@ impl ParseContext<Magic> for HeaderV1 {
 }
 
 only_serde! {
+  use serde::ser::SerializeMap;
   use serde::ser::SerializeStruct;
   use serde::Serialize;
   use serde::Serializer;
 
+  impl HeaderV1 {
+    /// Write the fields of this header as entries of an enclosing map.
+    pub(crate) fn serialize_entries<M: SerializeMap>(&self, state: &mut M) -> Result<(), M::Error> {
+      state.serialize_entry("magic", &self.magic)?;
+      state.serialize_entry("header_size", &self.header_size)?;
+      state.serialize_entry("archive_size", &self.archive_size)?;
+      state.serialize_entry("format_version", &self.format_version)?;
+      state.serialize_entry("sector_size_shift", &self.sector_size_shift)?;
+      state.serialize_entry("htable_offset", &self.htable_offset)?;
+      state.serialize_entry("btable_offset", &self.btable_offset)?;
+      state.serialize_entry("htable_entries", &self.htable_entries)?;
+      state.serialize_entry("btable_entries", &self.btable_entries)?;
+      Ok(())
+    }
+  }
+
   impl Serialize for HeaderV1 {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
       let mut state: S::SerializeStruct = serializer.serialize_struct("HeaderV1", 9)?;
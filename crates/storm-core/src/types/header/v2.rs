@@ -70,18 +70,25 @@ impl ParseContext<HeaderV1> for HeaderV2 {
 }
 
 only_serde! {
-  use serde::__private::ser::FlatMapSerializer;
   use serde::ser::SerializeMap;
   use serde::Serialize;
   use serde::Serializer;
 
-  impl Serialize for HeaderV2 {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-      let mut state: S::SerializeMap = serializer.serialize_map(Some(12))?;
-      self.v1.serialize(FlatMapSerializer(&mut state))?;
+  impl HeaderV2 {
+    /// Write the fields of this header as entries of an enclosing map.
+    pub(crate) fn serialize_entries<M: SerializeMap>(&self, state: &mut M) -> Result<(), M::Error> {
+      self.v1.serialize_entries(state)?;
       state.serialize_entry("hi_btable_offset", &self.hi_btable_offset)?;
       state.serialize_entry("htable_offset_hi", &self.htable_offset_hi)?;
       state.serialize_entry("btable_offset_hi", &self.btable_offset_hi)?;
+      Ok(())
+    }
+  }
+
+  impl Serialize for HeaderV2 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeMap = serializer.serialize_map(Some(12))?;
+      self.serialize_entries(&mut state)?;
       state.end()
     }
   }
@@ -174,15 +174,14 @@ impl ParseContext<HeaderV3> for HeaderV4 {
 }
 
 only_serde! {
-  use serde::__private::ser::FlatMapSerializer;
   use serde::ser::SerializeMap;
   use serde::Serialize;
   use serde::Serializer;
 
-  impl Serialize for HeaderV4 {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-      let mut state: S::SerializeMap = serializer.serialize_map(Some(27))?;
-      self.v3.serialize(FlatMapSerializer(&mut state))?;
+  impl HeaderV4 {
+    /// Write the fields of this header as entries of an enclosing map.
+    pub(crate) fn serialize_entries<M: SerializeMap>(&self, state: &mut M) -> Result<(), M::Error> {
+      self.v3.serialize_entries(state)?;
       state.serialize_entry("htable_size", &self.htable_size)?;
       state.serialize_entry("btable_size", &self.btable_size)?;
       state.serialize_entry("hi_btable_size", &self.hi_btable_size)?;
@@ -195,6 +194,14 @@ only_serde! {
       state.serialize_entry("md5_bet_table", &self.md5_bet_table)?;
       state.serialize_entry("md5_het_table", &self.md5_het_table)?;
       state.serialize_entry("md5_mpq_header", &self.md5_mpq_header)?;
+      Ok(())
+    }
+  }
+
+  impl Serialize for HeaderV4 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeMap = serializer.serialize_map(Some(27))?;
+      self.serialize_entries(&mut state)?;
       state.end()
     }
   }
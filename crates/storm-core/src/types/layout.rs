@@ -0,0 +1,442 @@
+use core::cmp::Ordering;
+
+use crate::types::Archive;
+use crate::types::BTableEntry;
+use crate::types::HTableEntry;
+use crate::types::Header;
+use crate::types::Signature;
+use crate::types::UserData;
+
+only_serde! {
+  use serde::ser::SerializeStruct;
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// Archive Layout
+// =============================================================================
+
+/// A map of every byte range in the archive file and what owns it.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Layout {
+  /// The size of the archive file (bytes).
+  pub size: u64,
+  /// All regions in the file (including gaps), ordered by position.
+  pub regions: Box<[Region]>,
+  /// Pairs of regions claiming the same bytes.
+  pub overlaps: Box<[Overlap]>,
+}
+
+impl Layout {
+  /// Build the layout map of the given `archive`.
+  pub fn new(archive: &Archive) -> Self {
+    let size: u64 = archive.handle.size();
+    let mut regions: Vec<Region> = claimed_regions(archive);
+
+    regions.sort_by(Region::cmp_position);
+
+    let overlaps: Box<[Overlap]> = find_overlaps(&regions);
+
+    regions.extend(find_gaps(&regions, size));
+    regions.sort_by(Region::cmp_position);
+
+    Self {
+      size,
+      regions: regions.into_boxed_slice(),
+      overlaps,
+    }
+  }
+
+  /// Returns an iterator over regions not owned by any known structure.
+  #[inline]
+  pub fn gaps(&self) -> impl Iterator<Item = &Region> + '_ {
+    self
+      .regions
+      .iter()
+      .filter(|region| region.kind == RegionKind::Gap)
+  }
+
+  /// Returns an iterator over regions extending beyond the end of the file.
+  #[inline]
+  pub fn outside(&self) -> impl Iterator<Item = &Region> + '_ {
+    self
+      .regions
+      .iter()
+      .filter(|region| region.is_outside(self.size))
+  }
+
+  /// Returns `true` if no regions overlap or extend beyond the end of the file.
+  #[inline]
+  pub fn is_clean(&self) -> bool {
+    self.overlaps.is_empty() && self.outside().next().is_none()
+  }
+}
+
+only_serde! {
+  impl Serialize for Layout {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Layout", 3)?;
+      state.serialize_field("size", &self.size)?;
+      state.serialize_field("regions", &self.regions)?;
+      state.serialize_field("overlaps", &self.overlaps)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Layout Region
+// =============================================================================
+
+/// A contiguous byte range in the archive file.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Region {
+  /// The owner of the region.
+  pub kind: RegionKind,
+  /// Offset of the first byte in the region (absolute).
+  pub start: u64,
+  /// Offset one past the last byte in the region (absolute).
+  pub end: u64,
+}
+
+impl Region {
+  /// Create a new `Region`.
+  #[inline]
+  pub const fn new(kind: RegionKind, start: u64, end: u64) -> Self {
+    Self { kind, start, end }
+  }
+
+  /// Returns the size of the region (bytes).
+  #[inline]
+  pub const fn size(&self) -> u64 {
+    self.end - self.start
+  }
+
+  /// Returns `true` if the region extends beyond a file of `size` bytes.
+  #[inline]
+  pub const fn is_outside(&self, size: u64) -> bool {
+    self.end > size
+  }
+
+  #[inline]
+  fn cmp_position(&self, other: &Self) -> Ordering {
+    self
+      .start
+      .cmp(&other.start)
+      .then_with(|| self.end.cmp(&other.end))
+  }
+}
+
+only_serde! {
+  impl Serialize for Region {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Region", 4)?;
+      state.serialize_field("kind", self.kind.as_str())?;
+      state.serialize_field("block", &self.kind.block())?;
+      state.serialize_field("start", &self.start)?;
+      state.serialize_field("end", &self.end)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Layout Region Kind
+// =============================================================================
+
+/// The owner of a [`Region`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RegionKind {
+  /// MPQ user data block (including the shunt header).
+  UserData,
+  /// MPQ header.
+  Header,
+  /// Sector offset table of the file at the given block index.
+  SectorTable(u32),
+  /// Data of the file at the given block index.
+  FileData(u32),
+  /// Block table entry with data that is not flagged as an existing file.
+  FreeBlock(u32),
+  /// Hash table.
+  HashTable,
+  /// Block table.
+  BlockTable,
+  /// Hi-block table.
+  HiBlockTable,
+  /// HET table.
+  HetTable,
+  /// BET table.
+  BetTable,
+  /// Strong digital signature.
+  Signature,
+  /// Bytes not claimed by any known structure.
+  Gap,
+}
+
+impl RegionKind {
+  /// Returns a human-readable name of the region owner.
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::UserData => "user data",
+      Self::Header => "header",
+      Self::SectorTable(_) => "sector table",
+      Self::FileData(_) => "file data",
+      Self::FreeBlock(_) => "free block",
+      Self::HashTable => "hash table",
+      Self::BlockTable => "block table",
+      Self::HiBlockTable => "hi-block table",
+      Self::HetTable => "HET table",
+      Self::BetTable => "BET table",
+      Self::Signature => "signature",
+      Self::Gap => "gap",
+    }
+  }
+
+  /// Returns the block index of the region, if owned by a block.
+  #[inline]
+  pub const fn block(&self) -> Option<u32> {
+    match self {
+      Self::SectorTable(index) | Self::FileData(index) | Self::FreeBlock(index) => Some(*index),
+      _ => None,
+    }
+  }
+}
+
+// =============================================================================
+// Layout Overlap
+// =============================================================================
+
+/// Two regions claiming the same bytes.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Overlap {
+  /// The region starting first.
+  pub first: Region,
+  /// The region starting second.
+  pub second: Region,
+}
+
+impl Overlap {
+  /// Returns the offset of the first shared byte.
+  #[inline]
+  pub const fn start(&self) -> u64 {
+    self.second.start
+  }
+
+  /// Returns the offset one past the last shared byte.
+  #[inline]
+  pub const fn end(&self) -> u64 {
+    if self.first.end < self.second.end {
+      self.first.end
+    } else {
+      self.second.end
+    }
+  }
+}
+
+only_serde! {
+  impl Serialize for Overlap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Overlap", 2)?;
+      state.serialize_field("first", &self.first)?;
+      state.serialize_field("second", &self.second)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Region Discovery
+// =============================================================================
+
+fn claimed_regions(archive: &Archive) -> Vec<Region> {
+  let mut output: Vec<Region> = Vec::new();
+  let header: &Header = archive.header();
+  let base: u64 = archive.offset;
+
+  let mut push = |kind: RegionKind, start: u64, size: u64| {
+    if size != 0 {
+      output.push(Region::new(kind, start, start.saturating_add(size)));
+    }
+  };
+
+  if let Some(udata) = archive.udata() {
+    let start: u64 = base.saturating_sub(u64::from(udata.header_offset));
+    let size: u64 = UserData::SIZE as u64 + u64::from(udata.udata_size);
+
+    push(RegionKind::UserData, start, size);
+  }
+
+  push(RegionKind::Header, base, u64::from(header.header_size));
+
+  // Hash Table
+  let (offset, size) = htable_position(header);
+  push(RegionKind::HashTable, base + offset, size);
+
+  // Block Table
+  let (offset, size) = btable_position(header);
+  push(RegionKind::BlockTable, base + offset, size);
+
+  // Hi-Block Table
+  if let Some(v2) = header.v2() {
+    if v2.hi_btable_offset != 0 {
+      let size: u64 = match header.v4() {
+        Some(v4) => v4.hi_btable_size,
+        None => u64::from(header.btable_entries) * 2,
+      };
+
+      push(RegionKind::HiBlockTable, base + v2.hi_btable_offset, size);
+    }
+  }
+
+  // HET/BET Tables
+  if let Some(v3) = header.v3() {
+    if v3.het_table_position != 0 {
+      let size: u64 = match (header.v4(), archive.ext_htable()) {
+        (Some(v4), _) => v4.het_table_size,
+        (None, Some(table)) => u64::from(table.table_size),
+        (None, None) => 0,
+      };
+
+      push(RegionKind::HetTable, base + v3.het_table_position, size);
+    }
+
+    if v3.bet_table_position != 0 {
+      let size: u64 = match (header.v4(), archive.ext_btable()) {
+        (Some(v4), _) => v4.bet_table_size,
+        (None, Some(table)) => u64::from(table.table_size),
+        (None, None) => 0,
+      };
+
+      push(RegionKind::BetTable, base + v3.bet_table_position, size);
+    }
+  }
+
+  // Signature
+  if archive.signature().is_some() {
+    push(
+      RegionKind::Signature,
+      base + u64::from(header.archive_size),
+      Signature::SIZE as u64,
+    );
+  }
+
+  // File Data
+  let sector_size: u32 = archive.sector_size();
+
+  for (index, entry) in archive.btable().iter().enumerate() {
+    let index: u32 = index as u32;
+    let start: u64 = base + u64::from(entry.offset);
+    let size: u64 = u64::from(entry.comp_size);
+
+    if !entry.is_exists() {
+      push(RegionKind::FreeBlock(index), start, size);
+      continue;
+    }
+
+    let table: u64 = sector_table_size(entry, sector_size).min(size);
+
+    push(RegionKind::SectorTable(index), start, table);
+    push(RegionKind::FileData(index), start + table, size - table);
+  }
+
+  output
+}
+
+fn find_overlaps(regions: &[Region]) -> Box<[Overlap]> {
+  let mut output: Vec<Overlap> = Vec::new();
+
+  // Note: `regions` is sorted by start offset, so we only need to look
+  //       forward until we find a region starting after the current one ends.
+  for (index, first) in regions.iter().enumerate() {
+    for second in regions[index + 1..].iter() {
+      if second.start >= first.end {
+        break;
+      }
+
+      output.push(Overlap {
+        first: *first,
+        second: *second,
+      });
+    }
+  }
+
+  output.into_boxed_slice()
+}
+
+fn find_gaps(regions: &[Region], size: u64) -> Vec<Region> {
+  let mut output: Vec<Region> = Vec::new();
+  let mut cursor: u64 = 0;
+
+  for region in regions.iter() {
+    if region.start >= size {
+      break;
+    }
+
+    if region.start > cursor {
+      output.push(Region::new(RegionKind::Gap, cursor, region.start));
+    }
+
+    cursor = cursor.max(region.end);
+  }
+
+  if cursor < size {
+    output.push(Region::new(RegionKind::Gap, cursor, size));
+  }
+
+  output
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+// Returns the (relative) position and size of the hash table
+pub(crate) fn htable_position(header: &Header) -> (u64, u64) {
+  let mut offset: u64 = u64::from(header.htable_offset);
+  let mut length: u64 = u64::from(header.htable_entries) * HTableEntry::SIZE as u64;
+
+  if let Some(v2) = header.v2() {
+    offset |= u64::from(v2.htable_offset_hi) << 32;
+  }
+
+  if let Some(v4) = header.v4() {
+    length = v4.htable_size;
+  }
+
+  (offset, length)
+}
+
+// Returns the (relative) position and size of the block table
+pub(crate) fn btable_position(header: &Header) -> (u64, u64) {
+  let mut offset: u64 = u64::from(header.btable_offset);
+  let mut length: u64 = u64::from(header.btable_entries) * BTableEntry::SIZE as u64;
+
+  if let Some(v2) = header.v2() {
+    offset |= u64::from(v2.btable_offset_hi) << 32;
+  }
+
+  if let Some(v4) = header.v4() {
+    length = v4.btable_size;
+  }
+
+  (offset, length)
+}
+
+// Returns the size of the sector offset table preceding the file data
+fn sector_table_size(entry: &BTableEntry, sector_size: u32) -> u64 {
+  if entry.is_single_unit() || !entry.is_any_compression() || entry.file_size == 0 {
+    return 0;
+  }
+
+  let sector_count: u64 = u64::from((entry.file_size - 1) / sector_size) + 1;
+
+  // Note: last entry is simply total size
+  // Note: checksum adds an additional sector
+  if entry.is_sector_crc() {
+    (sector_count + 2) * 4
+  } else {
+    (sector_count + 1) * 4
+  }
+}
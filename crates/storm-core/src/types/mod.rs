@@ -1,15 +1,26 @@
 mod archive;
+mod diagnostic;
 mod etable;
 mod file;
 mod files;
 mod header;
+mod layout;
 mod locale;
 mod magic;
+mod orphan;
+mod repair;
 mod signature;
+mod stats;
 mod table;
 mod udata;
 
+pub(crate) use self::layout::btable_position;
+pub(crate) use self::layout::htable_position;
+pub(crate) use self::repair::known_names;
+
 pub use self::archive::Archive;
+pub use self::diagnostic::Diagnostic;
+pub use self::diagnostic::Severity;
 pub use self::etable::BETHeader;
 pub use self::etable::ExtBTable;
 pub use self::etable::ExtHTable;
@@ -26,9 +37,18 @@ pub use self::header::HeaderV1;
 pub use self::header::HeaderV2;
 pub use self::header::HeaderV3;
 pub use self::header::HeaderV4;
+pub use self::layout::Layout;
+pub use self::layout::Overlap;
+pub use self::layout::Region;
+pub use self::layout::RegionKind;
 pub use self::locale::Locale;
 pub use self::magic::Magic;
+pub use self::orphan::Orphan;
+pub use self::repair::Heuristic;
+pub use self::repair::Repair;
 pub use self::signature::Signature;
+pub use self::stats::RatioTotals;
+pub use self::stats::Stats;
 pub use self::table::BTable;
 pub use self::table::BTableEntry;
 pub use self::table::BTableEntryFlags;
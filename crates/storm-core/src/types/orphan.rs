@@ -0,0 +1,244 @@
+use storm_utils::traits::ReadExt;
+use storm_utils::traits::SeekExt;
+
+use crate::consts::BT_MASK;
+use crate::error::Result;
+use crate::extract::read_file_with_key;
+use crate::extract::sector_count;
+use crate::extract::FilePtr;
+use crate::extract::Query;
+use crate::extract::Tracker;
+use crate::parse::Handle;
+use crate::types::Archive;
+use crate::types::BTableEntry;
+use crate::types::File;
+use crate::types::HTableEntry;
+use crate::utils;
+
+only_serde! {
+  use serde::ser::SerializeStruct;
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// Orphaned Block
+// =============================================================================
+
+/// A block with file data that no live hash table entry points to.
+///
+/// This is usually a file deleted by only clearing its hash table entry.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Orphan {
+  /// The index of the block in the block table.
+  pub block: u32,
+  /// The offset of the block data in the archive file (absolute).
+  pub offset: u64,
+  /// The block table entry.
+  pub btentry: BTableEntry,
+  /// The encryption key of the block, if encrypted and recovered.
+  pub key: Option<u32>,
+  /// The extracted file data, if recovered.
+  pub data: Option<File>,
+  /// Best-guess file extension of the recovered data.
+  pub kind: Option<&'static str>,
+}
+
+impl Orphan {
+  /// Find and try to extract every orphaned block in the given `archive`.
+  ///
+  /// The encryption key of encrypted blocks is recovered from the sector
+  /// offset table, which only works for compressed, multi-sector files.
+  pub fn find_all(archive: &Archive) -> Vec<Self> {
+    // Can't fail without a cancel token
+    Self::find_all_with(archive, &mut Tracker::new()).unwrap_or_default()
+  }
+
+  /// Like [`Orphan::find_all`], reporting progress and checking for
+  /// cancellation before each block.
+  pub fn find_all_with(archive: &Archive, tracker: &mut Tracker<'_>) -> Result<Vec<Self>> {
+    let btable: &[BTableEntry] = archive.btable();
+    let mut referenced: Vec<bool> = vec![false; btable.len()];
+
+    for entry in archive.htable().iter() {
+      if !entry.is_empty() {
+        if let Some(slot) = referenced.get_mut((entry.position & BT_MASK) as usize) {
+          *slot = true;
+        }
+      }
+    }
+
+    let candidates: Vec<(usize, &BTableEntry)> = btable
+      .iter()
+      .enumerate()
+      .filter(|(index, entry)| !referenced[*index] && is_candidate(archive, entry))
+      .collect();
+
+    let mut output: Vec<Self> = Vec::with_capacity(candidates.len());
+
+    tracker.total(candidates.len() as u64);
+
+    for (index, entry) in candidates {
+      let name: String = format!("block #{index}");
+
+      tracker.start(&name)?;
+      output.push(Self::recover(archive, index as u32, entry));
+      tracker.finish(&name, u64::from(entry.comp_size));
+    }
+
+    Ok(output)
+  }
+
+  fn recover(archive: &Archive, block: u32, btentry: &BTableEntry) -> Self {
+    let pointer: FilePtr<'_> = FilePtr {
+      query: Query::new(""),
+      archive,
+      block,
+      htentry: &ORPHAN,
+      btentry,
+    };
+
+    let mut this: Self = Self {
+      block,
+      offset: pointer.offset(),
+      btentry: *btentry,
+      key: None,
+      data: None,
+      kind: None,
+    };
+
+    let mut key: u32 = 0;
+
+    if btentry.is_encrypted() {
+      match recover_key(&pointer) {
+        Ok(Some(value)) => key = value,
+        Ok(None) | Err(_) => return this,
+      }
+
+      this.key = Some(key);
+    }
+
+    if let Ok(data) = read_file_with_key(pointer, key, false) {
+      this.kind = guess_kind(&data);
+      this.data = Some(data);
+    }
+
+    this
+  }
+
+  /// Returns `true` if the file data was recovered.
+  #[inline]
+  pub const fn is_recovered(&self) -> bool {
+    self.data.is_some()
+  }
+}
+
+only_serde! {
+  impl Serialize for Orphan {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Orphan", 6)?;
+      state.serialize_field("block", &self.block)?;
+      state.serialize_field("offset", &self.offset)?;
+      state.serialize_field("btentry", &self.btentry)?;
+      state.serialize_field("key", &self.key)?;
+      state.serialize_field("size", &self.data.as_ref().map(|data| data.size()))?;
+      state.serialize_field("kind", &self.kind)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+// Placeholder hash table entry for blocks without one
+static ORPHAN: HTableEntry = HTableEntry {
+  hash1: u32::MAX,
+  hash2: u32::MAX,
+  language: 0,
+  platform: 0,
+  _padding: 0,
+  position: HTableEntry::EMPTY_REMOVED,
+};
+
+// Returns `true` if the block may still hold file data
+fn is_candidate(archive: &Archive, entry: &BTableEntry) -> bool {
+  if entry.comp_size == 0 || entry.file_size == 0 || entry.is_patch_file() {
+    return false;
+  }
+
+  let end: u64 = archive.offset + u64::from(entry.offset) + u64::from(entry.comp_size);
+
+  end <= archive.handle.size()
+}
+
+// Recover the file key from the known size of the sector offset table
+//
+// Note: The sector table is encrypted with `key - 1`
+fn recover_key(pointer: &FilePtr<'_>) -> Result<Option<u32>> {
+  if pointer.btentry.is_single_unit() || !pointer.btentry.is_any_compression() {
+    return Ok(None);
+  }
+
+  let mut count: u32 = sector_count(pointer) + 1;
+
+  if pointer.btentry.is_sector_crc() {
+    count += 1;
+  }
+
+  let size: u32 = count * 4;
+  let sector_size: u32 = pointer.archive.sector_size();
+
+  let mut buffer: [u8; 8] = [0; 8];
+  let mut reader: Handle = pointer.archive.handle.duplicate(buffer.len())?;
+
+  reader.seek_start(pointer.offset())?;
+  reader.read_bytes(&mut buffer)?;
+
+  // The first sector starts right after the table and can't be larger than
+  // the sector size
+  let key: Option<u32> = utils::detect_key(&buffer, size, |next| {
+    next > size && next - size <= sector_size
+  });
+
+  Ok(key.map(|key| key.wrapping_add(1)))
+}
+
+// Guess the file extension from the first bytes of the data
+fn guess_kind(data: &[u8]) -> Option<&'static str> {
+  const MAGIC: &[(&[u8], &str)] = &[
+    (b"MPQ\x1A", "mpq"),
+    (b"MPQ\x1B", "mpq"),
+    (b"RIFF", "wav"),
+    (b"OggS", "ogg"),
+    (b"ID3", "mp3"),
+    (b"\x89PNG", "png"),
+    (b"BLP1", "blp"),
+    (b"BLP2", "blp"),
+    (b"DDS ", "dds"),
+    (b"MDLX", "mdx"),
+    (b"SMK2", "smk"),
+    (b"SMK4", "smk"),
+    (b"BIK", "bik"),
+    (b"BM", "bmp"),
+    (b"VER ", "chk"),
+    (b"TYPE", "chk"),
+  ];
+
+  for (magic, kind) in MAGIC {
+    if data.starts_with(magic) {
+      return Some(kind);
+    }
+  }
+
+  // Text files (e.g. listfiles, scripts) only contain printable characters
+  if data
+    .iter()
+    .all(|byte| byte.is_ascii_graphic() || byte.is_ascii_whitespace())
+  {
+    return Some("txt");
+  }
+
+  None
+}
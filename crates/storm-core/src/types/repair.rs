@@ -0,0 +1,540 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use storm_utils::traits::ReadExt;
+use storm_utils::traits::SeekExt;
+
+use crate::consts::HASH_KEY_BT;
+use crate::consts::HASH_KEY_HT;
+use crate::consts::MAGIC_ID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::extract::start_slot;
+use crate::extract::FilePtr;
+use crate::extract::Query;
+use crate::parse::read_archive_repair;
+use crate::parse::Handle;
+use crate::types::Archive;
+use crate::types::BTable;
+use crate::types::BTableEntry;
+use crate::types::BTableEntryFlags;
+use crate::types::HTable;
+use crate::types::HTableEntry;
+use crate::types::HeaderV1;
+use crate::types::ListFile;
+use crate::utils;
+use crate::utils::HashType;
+use crate::utils::NameHash;
+
+only_serde! {
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// Repaired Archive
+// =============================================================================
+
+/// An archive parsed with every known recovery heuristic applied.
+///
+/// The repaired archive can be written back out as a normalised V1 archive with
+/// a power-of-two hash table (unless some names are unknown, see
+/// [`Heuristic::EntryUnnamed`]), sane table positions and no user data.
+#[derive(Debug)]
+pub struct Repair {
+  archive: Archive,
+  heuristics: Vec<Heuristic>,
+  htable: HTable,
+  btable: BTable,
+}
+
+impl Repair {
+  /// Names that are always tried when rebuilding the hash table.
+  pub const KNOWN_NAMES: &'static [&'static str] = &[
+    "(listfile)",
+    "(attributes)",
+    "(signature)",
+    "(user data)",
+    "staredit\\scenario.chk",
+  ];
+
+  /// Leniently parse the archive at the given `path`.
+  pub fn open<P>(path: &P) -> Result<Self>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    let (archive, heuristics): (Archive, Vec<Heuristic>) = read_archive_repair(path)?;
+
+    Ok(Self::new(archive, heuristics))
+  }
+
+  fn new(archive: Archive, mut heuristics: Vec<Heuristic>) -> Self {
+    let btable: BTable = normalize_btable(&archive, &mut heuristics);
+    let htable: HTable = normalize_htable(&archive, &btable, &mut heuristics);
+
+    Self {
+      archive,
+      heuristics,
+      htable,
+      btable,
+    }
+  }
+
+  /// Returns a reference to the (leniently parsed) archive.
+  #[inline]
+  pub const fn archive(&self) -> &Archive {
+    &self.archive
+  }
+
+  /// Consumes the repair and returns the (leniently parsed) archive.
+  #[inline]
+  pub fn into_archive(self) -> Archive {
+    self.archive
+  }
+
+  /// Returns the recovery heuristics applied to the archive.
+  #[inline]
+  pub fn heuristics(&self) -> &[Heuristic] {
+    &self.heuristics
+  }
+
+  /// Returns `true` if no recovery heuristics were needed.
+  #[inline]
+  pub fn is_clean(&self) -> bool {
+    self.heuristics.is_empty()
+  }
+
+  /// Write the normalised archive to the file at the given `path`.
+  pub fn save<P>(&self, path: &P) -> Result<()>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    fs::write(path, self.to_bytes()?).map_err(Into::into)
+  }
+
+  /// Write the normalised archive to the given `writer`.
+  pub fn write<W: Write + ?Sized>(&self, writer: &mut W) -> Result<()> {
+    writer.write_all(&self.to_bytes()?).map_err(Into::into)
+  }
+
+  /// Build the normalised archive in memory.
+  ///
+  /// File data is kept at the same (relative) offsets so encryption keys that
+  /// depend on the block position remain valid. The hash and block tables are
+  /// moved after the last file.
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    let data_end: u64 = self
+      .btable
+      .iter()
+      .filter(|entry| entry.is_exists())
+      .map(|entry| u64::from(entry.offset) + u64::from(entry.comp_size))
+      .fold(HeaderV1::SIZE as u64, u64::max);
+
+    let htable_size: u64 = (self.htable.len() * HTableEntry::SIZE) as u64;
+    let btable_size: u64 = (self.btable.len() * BTableEntry::SIZE) as u64;
+
+    let htable_offset: u64 = data_end;
+    let btable_offset: u64 = htable_offset + htable_size;
+    let archive_size: u64 = btable_offset + btable_size;
+
+    // The normalised archive uses a V1 header, with 32-bit offsets
+    let Ok(archive_size) = u32::try_from(archive_size) else {
+      return Err(Error::message(format_args!(
+        "archive too large for a V1 header: {archive_size} bytes"
+      )));
+    };
+
+    let mut output: Vec<u8> = vec![0; archive_size as usize];
+    let mut reader: Handle = self.archive.handle.duplicate_exact()?;
+
+    // Header
+    let header: HeaderV1 = HeaderV1 {
+      magic: self.archive.header.magic,
+      header_size: HeaderV1::SIZE as u32,
+      archive_size,
+      format_version: 0,
+      sector_size_shift: self.archive.header.sector_size_shift,
+      _padding: 0,
+      htable_offset: htable_offset as u32,
+      btable_offset: btable_offset as u32,
+      htable_entries: self.htable.len() as u32,
+      btable_entries: self.btable.len() as u32,
+    };
+
+    write_header(&header, &mut output[..HeaderV1::SIZE]);
+
+    // File Data
+    for entry in self.btable.iter().filter(|entry| entry.is_exists()) {
+      let start: usize = entry.offset as usize;
+      let window: &mut [u8] = &mut output[start..][..entry.comp_size as usize];
+
+      reader.seek_start(self.archive.offset + u64::from(entry.offset))?;
+      reader.read_bytes(window)?;
+    }
+
+    // Hash Table
+    let window: &mut [u8] = &mut output[htable_offset as usize..][..htable_size as usize];
+
+    for (entry, chunk) in self
+      .htable
+      .iter()
+      .zip(window.chunks_exact_mut(HTableEntry::SIZE))
+    {
+      chunk[0x00..0x04].copy_from_slice(&entry.hash1.to_le_bytes());
+      chunk[0x04..0x08].copy_from_slice(&entry.hash2.to_le_bytes());
+      chunk[0x08..0x0A].copy_from_slice(&entry.language.to_le_bytes());
+      chunk[0x0A] = entry.platform;
+      chunk[0x0B] = entry._padding;
+      chunk[0x0C..0x10].copy_from_slice(&entry.position.to_le_bytes());
+    }
+
+    utils::encrypt(window, HASH_KEY_HT)?;
+
+    // Block Table
+    let window: &mut [u8] = &mut output[btable_offset as usize..][..btable_size as usize];
+
+    for (entry, chunk) in self
+      .btable
+      .iter()
+      .zip(window.chunks_exact_mut(BTableEntry::SIZE))
+    {
+      chunk[0x00..0x04].copy_from_slice(&entry.offset.to_le_bytes());
+      chunk[0x04..0x08].copy_from_slice(&entry.comp_size.to_le_bytes());
+      chunk[0x08..0x0C].copy_from_slice(&entry.file_size.to_le_bytes());
+      chunk[0x0C..0x10].copy_from_slice(&entry.bitflags.bits().to_le_bytes());
+    }
+
+    utils::encrypt(window, HASH_KEY_BT)?;
+
+    Ok(output)
+  }
+}
+
+only_serde! {
+  use serde::ser::SerializeStruct;
+
+  impl Serialize for Repair {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Repair", 2)?;
+      state.serialize_field("archive", &self.archive)?;
+      state.serialize_field("heuristics", &self.heuristics)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Recovery Heuristic
+// =============================================================================
+
+/// A recovery heuristic applied while parsing a damaged or protected archive.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Heuristic {
+  /// A user data block with bogus sizes or header offset was skipped.
+  UserDataIgnored {
+    /// Offset of the user data block (absolute).
+    offset: u64,
+  },
+  /// An unknown header format version was treated as V1.
+  HeaderVersion(u16),
+  /// A header too small for its format version was treated as V1.
+  HeaderSize(u32),
+  /// An MD5 digest did not match; the data was used anyway.
+  DigestMismatch(&'static str),
+  /// A V4 table size did not match; the data was used anyway.
+  LengthMismatch(&'static str),
+  /// A table extended beyond the end of the file and was truncated.
+  TableTruncated {
+    /// The name of the table.
+    table: &'static str,
+    /// The number of entries that could be read.
+    entries: u32,
+  },
+  /// An extended table could not be read and was ignored.
+  ExtTableIgnored(&'static str),
+  /// The archive size in the header did not cover the archive contents or was
+  /// larger than the file.
+  ArchiveSize {
+    /// The size claimed by the header.
+    claimed: u32,
+    /// The size derived from the table and file positions, or the file size.
+    actual: u32,
+  },
+  /// The hash table size is not a power of two.
+  HashTableSize(u32),
+  /// A hash table entry had block index bits above `BT_MASK`.
+  BlockIndexMasked {
+    /// Index of the hash table entry.
+    slot: u32,
+  },
+  /// A block extended beyond the end of the file and was removed.
+  BlockOutOfBounds {
+    /// Index of the block table entry.
+    block: u32,
+  },
+  /// A block overlapped the archive header and was removed.
+  BlockOverlapsHeader {
+    /// Index of the block table entry.
+    block: u32,
+  },
+  /// A block was not referenced by any hash table entry and was removed.
+  BlockUnreferenced {
+    /// Index of the block table entry.
+    block: u32,
+  },
+  /// A hash table entry with an unknown name could not be rehashed, so the
+  /// hash table kept its size.
+  EntryUnnamed {
+    /// Index of the hash table entry.
+    slot: u32,
+  },
+}
+
+impl Display for Heuristic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::UserDataIgnored { offset } => {
+        write!(f, "ignored bogus user data at {offset:#X}")
+      }
+      Self::HeaderVersion(version) => {
+        write!(f, "treated unknown header version {version} as v1")
+      }
+      Self::HeaderSize(size) => {
+        write!(f, "treated header of size {size:#X} as v1")
+      }
+      Self::DigestMismatch(name) => write!(f, "ignored invalid md5 for {name}"),
+      Self::LengthMismatch(name) => write!(f, "ignored invalid len for {name}"),
+      Self::TableTruncated { table, entries } => {
+        write!(f, "truncated {table} to {entries} entries")
+      }
+      Self::ExtTableIgnored(name) => write!(f, "ignored unreadable {name}"),
+      Self::ArchiveSize { claimed, actual } => {
+        write!(f, "replaced archive size {claimed:#X} with {actual:#X}")
+      }
+      Self::HashTableSize(entries) => {
+        write!(f, "hash table size {entries} is not a power of two")
+      }
+      Self::BlockIndexMasked { slot } => {
+        write!(f, "masked block index of hash entry {slot}")
+      }
+      Self::BlockOutOfBounds { block } => {
+        write!(f, "removed block {block} (outside of file)")
+      }
+      Self::BlockOverlapsHeader { block } => {
+        write!(f, "removed block {block} (overlaps header)")
+      }
+      Self::BlockUnreferenced { block } => {
+        write!(f, "removed block {block} (unreferenced)")
+      }
+      Self::EntryUnnamed { slot } => {
+        write!(
+          f,
+          "kept hash table size (unknown name of hash entry {slot})"
+        )
+      }
+    }
+  }
+}
+
+only_serde! {
+  impl Serialize for Heuristic {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      serializer.collect_str(self)
+    }
+  }
+}
+
+// =============================================================================
+// Table Normalization
+// =============================================================================
+
+const EMPTY_BLOCK: BTableEntry = BTableEntry {
+  offset: 0,
+  comp_size: 0,
+  file_size: 0,
+  bitflags: BTableEntryFlags::empty(),
+};
+
+const fn empty_hash(position: u32) -> HTableEntry {
+  HTableEntry {
+    hash1: u32::MAX,
+    hash2: u32::MAX,
+    language: u16::MAX,
+    platform: u8::MAX,
+    _padding: u8::MAX,
+    position,
+  }
+}
+
+// Returns `true` if the hash table entry points to a usable block
+fn is_valid(entry: &HTableEntry, btable: &[BTableEntry]) -> bool {
+  !entry.is_empty()
+    && btable
+      .get(entry.position as usize)
+      .is_some_and(BTableEntry::is_exists)
+}
+
+// Keeps the block indices stable so (attributes) remains valid; only blocks
+// referenced by the hash table and located after the header are kept.
+fn normalize_btable(archive: &Archive, heuristics: &mut Vec<Heuristic>) -> BTable {
+  let mut referenced: Vec<bool> = vec![false; archive.btable.len()];
+
+  for entry in archive.htable.iter() {
+    if is_valid(entry, &archive.btable) {
+      referenced[entry.position as usize] = true;
+    }
+  }
+
+  let mut table: BTable = BTable::with_capacity(archive.btable.len());
+
+  for (index, entry) in archive.btable.iter().enumerate() {
+    let block: u32 = index as u32;
+
+    if !entry.is_exists() {
+      table.data.push(EMPTY_BLOCK);
+    } else if !referenced[index] {
+      heuristics.push(Heuristic::BlockUnreferenced { block });
+      table.data.push(EMPTY_BLOCK);
+    } else if (entry.offset as usize) < HeaderV1::SIZE {
+      heuristics.push(Heuristic::BlockOverlapsHeader { block });
+      table.data.push(EMPTY_BLOCK);
+    } else {
+      table.data.push(*entry);
+    }
+  }
+
+  table
+}
+
+// Keeps the hash table as-is if it is already a power of two; otherwise the
+// table is rebuilt from the names found in the listfile.
+//
+// Note: Entries with an unknown name can't be rehashed into a larger table, so
+//       tables with such entries keep their size.
+fn normalize_htable(archive: &Archive, btable: &BTable, heuristics: &mut Vec<Heuristic>) -> HTable {
+  let source: &HTable = archive.htable();
+
+  if source.len().is_power_of_two() {
+    return clean_htable(source, btable);
+  }
+
+  let names: HashMap<(u32, u32), String> = known_names(archive);
+
+  let unnamed: Vec<Heuristic> = source
+    .iter()
+    .enumerate()
+    .filter(|(_, entry)| is_valid(entry, btable))
+    .filter(|(_, entry)| !names.contains_key(&(entry.hash1, entry.hash2)))
+    .map(|(slot, _)| Heuristic::EntryUnnamed { slot: slot as u32 })
+    .collect();
+
+  if !unnamed.is_empty() {
+    heuristics.extend(unnamed);
+    return clean_htable(source, btable);
+  }
+
+  let length: usize = source.len().next_power_of_two();
+  let mut table: HTable = HTable::with_capacity(length);
+
+  table
+    .data
+    .resize(length, empty_hash(HTableEntry::EMPTY_FOREVER));
+
+  for entry in source.iter().filter(|entry| is_valid(entry, btable)) {
+    let name: &str = &names[&(entry.hash1, entry.hash2)];
+    let start: usize = start_slot(utils::hash(name, HashType::Table), length);
+
+    insert_hash(&mut table, start, entry);
+  }
+
+  table
+}
+
+// Copy the hash table, removing entries that point to invalid blocks
+fn clean_htable(source: &HTable, btable: &BTable) -> HTable {
+  let mut table: HTable = HTable::with_capacity(source.len());
+
+  for entry in source.iter() {
+    if is_valid(entry, btable) || entry.position == HTableEntry::EMPTY_FOREVER {
+      table.data.push(*entry);
+    } else {
+      table.data.push(empty_hash(HTableEntry::EMPTY_REMOVED));
+    }
+  }
+
+  table
+}
+
+// Insert `entry` into the first never-used slot, probing linearly from `start`
+//
+// Note: the new table is at least as large as the old one so there is always
+//       an empty slot available.
+fn insert_hash(table: &mut HTable, start: usize, entry: &HTableEntry) {
+  let length: usize = table.len();
+
+  for probe in (start..length).chain(0..start) {
+    if table.data[probe].position == HTableEntry::EMPTY_FOREVER {
+      table.data[probe] = *entry;
+      break;
+    }
+  }
+}
+
+// Returns every known name (from the listfile and `Repair::KNOWN_NAMES`),
+// keyed by the name hashes
+pub(crate) fn known_names(archive: &Archive) -> HashMap<(u32, u32), String> {
+  let mut output: HashMap<(u32, u32), String> = HashMap::new();
+
+  let mut insert = |name: &str| {
+    let hashes: NameHash = NameHash::new(name);
+
+    output.insert((hashes.hash1, hashes.hash2), name.to_owned());
+  };
+
+  for name in Repair::KNOWN_NAMES {
+    insert(name);
+  }
+
+  if let Some(list) = scan_listfile(archive) {
+    for entry in list.iter() {
+      if let Ok(name) = entry.as_utf8() {
+        insert(name);
+      }
+    }
+  }
+
+  output
+}
+
+// Returns the `(listfile)`, found by scanning every hash table entry since the
+// table of a damaged archive may not be a power of two
+fn scan_listfile(archive: &Archive) -> Option<ListFile> {
+  const NAME: &str = "(listfile)";
+
+  let hashes: NameHash = NameHash::new(NAME);
+
+  archive
+    .htable()
+    .iter()
+    .filter(|entry| entry.hash1 == hashes.hash1 && entry.hash2 == hashes.hash2)
+    .filter(|entry| is_valid(entry, archive.btable()))
+    .find_map(|entry| ListFile::try_from(FilePtr::new(archive, Query::new(NAME), entry)).ok())
+}
+
+fn write_header(header: &HeaderV1, output: &mut [u8]) {
+  output[0x00..0x04].copy_from_slice(&MAGIC_ID);
+  output[0x04..0x08].copy_from_slice(&header.header_size.to_le_bytes());
+  output[0x08..0x0C].copy_from_slice(&header.archive_size.to_le_bytes());
+  output[0x0C..0x0E].copy_from_slice(&header.format_version.to_le_bytes());
+  output[0x0E] = header.sector_size_shift;
+  output[0x0F] = header._padding;
+  output[0x10..0x14].copy_from_slice(&header.htable_offset.to_le_bytes());
+  output[0x14..0x18].copy_from_slice(&header.btable_offset.to_le_bytes());
+  output[0x18..0x1C].copy_from_slice(&header.htable_entries.to_le_bytes());
+  output[0x1C..0x20].copy_from_slice(&header.btable_entries.to_le_bytes());
+}
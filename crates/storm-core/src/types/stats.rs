@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::consts::BT_MASK;
+use crate::extract::read_compression;
+use crate::extract::start_slot;
+use crate::extract::FilePtr;
+use crate::extract::Query;
+use crate::types::known_names;
+use crate::types::Archive;
+use crate::types::BTableEntry;
+use crate::types::HTable;
+use crate::types::HTableEntry;
+use crate::types::Layout;
+use crate::types::RegionKind;
+use crate::utils;
+use crate::utils::HashType;
+
+only_serde! {
+  use serde::ser::SerializeStruct;
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// Archive Statistics
+// =============================================================================
+
+/// Usage and health statistics of an archive.
+///
+/// Names are taken from the `(listfile)` and [`Repair::KNOWN_NAMES`]; values
+/// that depend on file names (probe length, encrypted sectors) only cover
+/// entries with a known name.
+///
+/// [`Repair::KNOWN_NAMES`]: crate::types::Repair::KNOWN_NAMES
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+  /// The number of hash table entries.
+  pub htable_size: u32,
+  /// The number of hash table entries pointing to a block.
+  pub htable_used: u32,
+  /// The number of hash table entries marked as deleted.
+  pub htable_deleted: u32,
+  /// The number of used hash table entries with a known name.
+  pub htable_named: u32,
+  /// Average number of slots visited to find an entry with a known name.
+  pub probe_length: Option<f64>,
+  /// The number of block table entries.
+  pub btable_size: u32,
+  /// The number of block table entries flagged as existing files.
+  pub btable_used: u32,
+  /// The number of block table entries flagged as deletion markers.
+  pub delete_markers: u32,
+  /// Indices of existing blocks not referenced by the hash table.
+  pub orphans: Vec<u32>,
+  /// Bytes in gaps, free blocks and orphaned blocks.
+  pub wasted: u64,
+  /// The number of referenced files.
+  pub files: u32,
+  /// The number of referenced files that are encrypted.
+  pub encrypted: u32,
+  /// The number of referenced files stored as a single unit.
+  pub single_unit: u32,
+  /// The number of referenced files whose sectors could not be inspected.
+  pub uninspected: u32,
+  /// The number of sectors per compression method (see `consts::COMP_*`).
+  pub compression: BTreeMap<u8, u32>,
+  /// The number of sectors stored uncompressed.
+  pub uncompressed: u32,
+  /// Totals of referenced files, by compression ratio.
+  pub ratios: [RatioTotals; 5],
+}
+
+impl Stats {
+  /// Upper bounds (percent, exclusive) of the compression ratio groups.
+  ///
+  /// Files of the last group are not smaller than their uncompressed size.
+  pub const RATIO_BOUNDS: [u32; 4] = [25, 50, 75, 100];
+
+  /// Compute the statistics of the given `archive`.
+  pub fn new(archive: &Archive) -> Self {
+    let htable: &HTable = archive.htable();
+    let btable: &[BTableEntry] = archive.btable();
+    let names: HashMap<(u32, u32), String> = known_names(archive);
+
+    let mut this: Self = Self {
+      htable_size: htable.len() as u32,
+      htable_used: 0,
+      htable_deleted: 0,
+      htable_named: 0,
+      probe_length: None,
+      btable_size: btable.len() as u32,
+      btable_used: 0,
+      delete_markers: 0,
+      orphans: Vec::new(),
+      wasted: 0,
+      files: 0,
+      encrypted: 0,
+      single_unit: 0,
+      uninspected: 0,
+      compression: BTreeMap::new(),
+      uncompressed: 0,
+      ratios: RatioTotals::groups(),
+    };
+
+    let mut referenced: Vec<bool> = vec![false; btable.len()];
+    let mut probes: u64 = 0;
+
+    for (slot, entry) in htable.iter().enumerate() {
+      if entry.position == HTableEntry::EMPTY_REMOVED {
+        this.htable_deleted += 1;
+      }
+
+      if entry.is_empty() {
+        continue;
+      }
+
+      let block: u32 = entry.position & BT_MASK;
+
+      let Some(btentry) = btable.get(block as usize) else {
+        continue;
+      };
+
+      this.htable_used += 1;
+
+      let name: Option<&str> = names.get(&(entry.hash1, entry.hash2)).map(String::as_str);
+
+      if let Some(name) = name {
+        let start: usize = start_slot(utils::hash(name, HashType::Table), htable.len());
+
+        this.htable_named += 1;
+        probes += ((slot + htable.len() - start) % htable.len()) as u64 + 1;
+      }
+
+      // Count each block once, even if referenced by multiple locales
+      if !btentry.is_exists() || referenced[block as usize] {
+        continue;
+      }
+
+      referenced[block as usize] = true;
+
+      let pointer: FilePtr<'_> = FilePtr {
+        query: Query::new(name.unwrap_or_default()),
+        archive,
+        block,
+        htentry: entry,
+        btentry,
+      };
+
+      this.add_file(&pointer, name.is_some());
+    }
+
+    if this.htable_named != 0 {
+      this.probe_length = Some(probes as f64 / f64::from(this.htable_named));
+    }
+
+    for (index, entry) in btable.iter().enumerate() {
+      if entry.is_delete_marker() {
+        this.delete_markers += 1;
+      }
+
+      if entry.is_exists() {
+        this.btable_used += 1;
+
+        if !referenced[index] {
+          this.orphans.push(index as u32);
+        }
+      }
+    }
+
+    for region in Layout::new(archive).regions.iter() {
+      let wasted: bool = match region.kind {
+        RegionKind::Gap | RegionKind::FreeBlock(_) => true,
+        RegionKind::SectorTable(block) | RegionKind::FileData(block) => !referenced[block as usize],
+        _ => false,
+      };
+
+      if wasted {
+        this.wasted += region.size();
+      }
+    }
+
+    this
+  }
+
+  /// Returns the ratio of used hash table entries to the hash table size.
+  #[inline]
+  pub fn load_factor(&self) -> f64 {
+    ratio(self.htable_used, self.htable_size)
+  }
+
+  /// Returns the ratio of existing blocks to the block table size.
+  #[inline]
+  pub fn btable_usage(&self) -> f64 {
+    ratio(self.btable_used, self.btable_size)
+  }
+
+  /// Returns the share of referenced files that are encrypted.
+  #[inline]
+  pub fn encrypted_share(&self) -> f64 {
+    ratio(self.encrypted, self.files)
+  }
+
+  /// Returns the share of referenced files stored as a single unit.
+  #[inline]
+  pub fn single_unit_share(&self) -> f64 {
+    ratio(self.single_unit, self.files)
+  }
+
+  fn add_file(&mut self, pointer: &FilePtr<'_>, named: bool) {
+    let entry: &BTableEntry = pointer.btentry;
+
+    self.files += 1;
+
+    if entry.is_encrypted() {
+      self.encrypted += 1;
+    }
+
+    if entry.is_single_unit() {
+      self.single_unit += 1;
+    }
+
+    let group: usize = RatioTotals::group(entry.comp_size, entry.file_size);
+
+    self.ratios[group].files += 1;
+    self.ratios[group].comp_size += u64::from(entry.comp_size);
+    self.ratios[group].file_size += u64::from(entry.file_size);
+
+    // The encryption key of unnamed files is unknown
+    if entry.is_encrypted() && !named {
+      self.uninspected += 1;
+      return;
+    }
+
+    match read_compression(pointer, pointer.encryption_key()) {
+      Ok(sectors) => {
+        for method in sectors {
+          match method {
+            Some(method) => *self.compression.entry(method).or_default() += 1,
+            None => self.uncompressed += 1,
+          }
+        }
+      }
+      Err(_) => {
+        self.uninspected += 1;
+      }
+    }
+  }
+}
+
+only_serde! {
+  impl Serialize for Stats {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("Stats", 21)?;
+      state.serialize_field("htable_size", &self.htable_size)?;
+      state.serialize_field("htable_used", &self.htable_used)?;
+      state.serialize_field("htable_deleted", &self.htable_deleted)?;
+      state.serialize_field("htable_named", &self.htable_named)?;
+      state.serialize_field("load_factor", &self.load_factor())?;
+      state.serialize_field("probe_length", &self.probe_length)?;
+      state.serialize_field("btable_size", &self.btable_size)?;
+      state.serialize_field("btable_used", &self.btable_used)?;
+      state.serialize_field("btable_usage", &self.btable_usage())?;
+      state.serialize_field("delete_markers", &self.delete_markers)?;
+      state.serialize_field("orphans", &self.orphans)?;
+      state.serialize_field("wasted", &self.wasted)?;
+      state.serialize_field("files", &self.files)?;
+      state.serialize_field("encrypted", &self.encrypted)?;
+      state.serialize_field("encrypted_share", &self.encrypted_share())?;
+      state.serialize_field("single_unit", &self.single_unit)?;
+      state.serialize_field("single_unit_share", &self.single_unit_share())?;
+      state.serialize_field("uninspected", &self.uninspected)?;
+      state.serialize_field("compression", &self.compression)?;
+      state.serialize_field("uncompressed", &self.uncompressed)?;
+      state.serialize_field("ratios", &self.ratios)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Compression Ratio Totals
+// =============================================================================
+
+/// Totals of files within a range of compression ratios.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct RatioTotals {
+  /// Lower bound (percent, inclusive) of the compressed to uncompressed size.
+  pub min: u32,
+  /// Upper bound (percent, exclusive), `None` if unbounded.
+  pub max: Option<u32>,
+  /// The number of files.
+  pub files: u32,
+  /// The total compressed size of the files.
+  pub comp_size: u64,
+  /// The total uncompressed size of the files.
+  pub file_size: u64,
+}
+
+impl RatioTotals {
+  fn groups() -> [Self; 5] {
+    let bounds: [u32; 4] = Stats::RATIO_BOUNDS;
+
+    core::array::from_fn(|index| Self {
+      min: index.checked_sub(1).map_or(0, |index| bounds[index]),
+      max: bounds.get(index).copied(),
+      files: 0,
+      comp_size: 0,
+      file_size: 0,
+    })
+  }
+
+  fn group(comp_size: u32, file_size: u32) -> usize {
+    let percent: u64 = if file_size == 0 {
+      100
+    } else {
+      u64::from(comp_size) * 100 / u64::from(file_size)
+    };
+
+    Stats::RATIO_BOUNDS
+      .iter()
+      .position(|bound| percent < u64::from(*bound))
+      .unwrap_or(Stats::RATIO_BOUNDS.len())
+  }
+}
+
+only_serde! {
+  impl Serialize for RatioTotals {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("RatioTotals", 5)?;
+      state.serialize_field("min", &self.min)?;
+      state.serialize_field("max", &self.max)?;
+      state.serialize_field("files", &self.files)?;
+      state.serialize_field("comp_size", &self.comp_size)?;
+      state.serialize_field("file_size", &self.file_size)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+#[inline]
+fn ratio(value: u32, total: u32) -> f64 {
+  if total == 0 {
+    0.0
+  } else {
+    f64::from(value) / f64::from(total)
+  }
+}
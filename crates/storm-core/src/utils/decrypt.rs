@@ -31,3 +31,72 @@ pub fn decrypt(buffer: &mut [u8], mut key: u32) -> Result<()> {
 
   Ok(())
 }
+
+/// Encrypt `buffer` with the given encryption `key`.
+pub fn encrypt(buffer: &mut [u8], mut key: u32) -> Result<()> {
+  let length: usize = buffer.len() >> 0x2;
+  let mut seed: u32 = SEED;
+
+  for index in 0..length {
+    seed = seed.wrapping_add(CRYPTABLE[0x400 + (key & 0xFF) as usize]);
+
+    let data: &mut [u8] = &mut buffer[index << 0x2..];
+    let word: u32 = LE::read_u32(data);
+
+    LE::write_u32(data, word ^ key.wrapping_add(seed));
+
+    key = (!key << 0x15).wrapping_add(0x11111111) | (key >> 0x0B);
+
+    seed = word
+      .wrapping_add(seed)
+      .wrapping_add(seed << 0x5)
+      .wrapping_add(0x3);
+  }
+
+  Ok(())
+}
+
+/// Recover the key `buffer` was encrypted with from the known value of its
+/// first (decrypted) word.
+///
+/// Every candidate key is checked by decrypting the second word and passing it
+/// to `check`, which should reject implausible values.
+pub fn detect_key<F>(buffer: &[u8], known: u32, check: F) -> Option<u32>
+where
+  F: Fn(u32) -> bool,
+{
+  if buffer.len() < 8 {
+    return None;
+  }
+
+  let word0: u32 = LE::read_u32(&buffer[0x0..]);
+  let word1: u32 = LE::read_u32(&buffer[0x4..]);
+
+  // `word0 ^ known` is `key + seed` where the seed depends on the low byte of
+  // the key - try every low byte.
+  let mixed: u32 = (word0 ^ known).wrapping_sub(SEED);
+
+  for index in 0..0x100 {
+    let key: u32 = mixed.wrapping_sub(CRYPTABLE[0x400 + index]);
+    let mut seed: u32 = SEED.wrapping_add(CRYPTABLE[0x400 + (key & 0xFF) as usize]);
+
+    if word0 ^ key.wrapping_add(seed) != known {
+      continue;
+    }
+
+    let next: u32 = (!key << 0x15).wrapping_add(0x11111111) | (key >> 0x0B);
+
+    seed = known
+      .wrapping_add(seed)
+      .wrapping_add(seed << 0x5)
+      .wrapping_add(0x3);
+
+    seed = seed.wrapping_add(CRYPTABLE[0x400 + (next & 0xFF) as usize]);
+
+    if check(word1 ^ next.wrapping_add(seed)) {
+      return Some(key);
+    }
+  }
+
+  None
+}
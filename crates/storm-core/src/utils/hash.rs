@@ -42,6 +42,176 @@ pub fn hash(name: &str, hash: HashType) -> u32 {
   seed1
 }
 
+// =============================================================================
+// Name Hash
+// =============================================================================
+
+/// The hashes used to find a file name in the hash table.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct NameHash {
+  /// Hash of the name with [`HashType::NameA`].
+  pub hash1: u32,
+  /// Hash of the name with [`HashType::NameB`].
+  pub hash2: u32,
+  /// Hash of the name with [`HashType::Table`].
+  pub table: u32,
+}
+
+impl NameHash {
+  /// Hash `name` with all three hash types in a single pass.
+  pub fn new(name: &str) -> Self {
+    let mut seeds: [(u32, u32); 3] = [(SEED1, SEED2); 3];
+    let kinds: [HashType; 3] = [HashType::NameA, HashType::NameB, HashType::Table];
+
+    for byte in name.bytes() {
+      let ch: u32 = u32::from(ASCII_TO_UPPER_SENSITIVE[usize::from(byte)]);
+
+      for ((seed1, seed2), hash) in seeds.iter_mut().zip(kinds) {
+        *seed1 = CRYPTABLE[(hash as u32 + ch) as usize] ^ seed1.wrapping_add(*seed2);
+
+        *seed2 = ch
+          .wrapping_add(*seed1)
+          .wrapping_add(*seed2)
+          .wrapping_add(*seed2 << 0x5)
+          .wrapping_add(0x3);
+      }
+    }
+
+    Self {
+      hash1: seeds[0].0,
+      hash2: seeds[1].0,
+      table: seeds[2].0,
+    }
+  }
+
+  /// Hash every name in `names`.
+  pub fn batch<I, S>(names: I) -> Vec<Self>
+  where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+  {
+    names
+      .into_iter()
+      .map(|name| Self::new(name.as_ref()))
+      .collect()
+  }
+}
+
+// =============================================================================
+// Jenkins Hash
+// =============================================================================
+
+/// Hash `name` as stored in the HET table (Bob Jenkins' `hashlittle2`).
+///
+/// Names are hashed in lowercase with `/` replaced by `\`.
+pub fn hash_jenkins(name: &str) -> u64 {
+  let data: Vec<u8> = name
+    .bytes()
+    .map(|byte| match byte {
+      b'/' => b'\\',
+      _ => byte.to_ascii_lowercase(),
+    })
+    .collect();
+
+  let (low, high): (u32, u32) = hashlittle2(&data, 2, 1);
+
+  (u64::from(high) << 32) | u64::from(low)
+}
+
+// Returns the primary (`c`) and secondary (`b`) hashes of `data`
+//
+// http://burtleburtle.net/bob/c/lookup3.c
+fn hashlittle2(data: &[u8], pc: u32, pb: u32) -> (u32, u32) {
+  let mut a: u32 = 0xDEADBEEF_u32
+    .wrapping_add(data.len() as u32)
+    .wrapping_add(pc);
+  let mut b: u32 = a;
+  let mut c: u32 = a.wrapping_add(pb);
+
+  // Note: Zero length data requires no mixing
+  if data.is_empty() {
+    return (c, b);
+  }
+
+  // Note: The last block (1 to 12 bytes) is zero-padded and not mixed
+  let split: usize = (data.len() - 1) / 12 * 12;
+  let mut last: [u8; 12] = [0; 12];
+
+  last[..data.len() - split].copy_from_slice(&data[split..]);
+
+  let words = |block: &[u8]| -> [u32; 3] {
+    core::array::from_fn(|index| {
+      u32::from_le_bytes([
+        block[index * 4],
+        block[index * 4 + 1],
+        block[index * 4 + 2],
+        block[index * 4 + 3],
+      ])
+    })
+  };
+
+  for block in data[..split].chunks_exact(12) {
+    let [x, y, z]: [u32; 3] = words(block);
+
+    a = a.wrapping_add(x);
+    b = b.wrapping_add(y);
+    c = c.wrapping_add(z);
+
+    mix(&mut a, &mut b, &mut c);
+  }
+
+  let [x, y, z]: [u32; 3] = words(&last);
+
+  a = a.wrapping_add(x);
+  b = b.wrapping_add(y);
+  c = c.wrapping_add(z);
+
+  // final(a, b, c)
+  c ^= b;
+  c = c.wrapping_sub(b.rotate_left(14));
+  a ^= c;
+  a = a.wrapping_sub(c.rotate_left(11));
+  b ^= a;
+  b = b.wrapping_sub(a.rotate_left(25));
+  c ^= b;
+  c = c.wrapping_sub(b.rotate_left(16));
+  a ^= c;
+  a = a.wrapping_sub(c.rotate_left(4));
+  b ^= a;
+  b = b.wrapping_sub(a.rotate_left(14));
+  c ^= b;
+  c = c.wrapping_sub(b.rotate_left(24));
+
+  (c, b)
+}
+
+#[inline]
+fn mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+  *a = a.wrapping_sub(*c) ^ c.rotate_left(4);
+  *c = c.wrapping_add(*b);
+  *b = b.wrapping_sub(*a) ^ a.rotate_left(6);
+  *a = a.wrapping_add(*c);
+  *c = c.wrapping_sub(*b) ^ b.rotate_left(8);
+  *b = b.wrapping_add(*a);
+  *a = a.wrapping_sub(*c) ^ c.rotate_left(16);
+  *c = c.wrapping_add(*b);
+  *b = b.wrapping_sub(*a) ^ a.rotate_left(19);
+  *a = a.wrapping_add(*c);
+  *c = c.wrapping_sub(*b) ^ b.rotate_left(4);
+  *b = b.wrapping_add(*a);
+}
+
+/// Normalize `name` as seen by [`hash`] (uppercase, `/` replaced by `\`).
+pub fn normalize(name: &str) -> String {
+  name
+    .chars()
+    .map(|ch| match u8::try_from(ch) {
+      Ok(byte) => char::from(ASCII_TO_UPPER_SENSITIVE[usize::from(byte)]),
+      Err(_) => ch,
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -53,4 +223,35 @@ mod tests {
     assert_eq!(hash("(block table)", HashType::File), consts::HASH_KEY_BT);
     assert_eq!(hash("(listfile)", HashType::Table), consts::HASH_KEY_LF);
   }
+
+  #[test]
+  fn test_hashlittle2() {
+    let text: &[u8] = b"Four score and seven years ago";
+
+    assert_eq!(hashlittle2(b"", 0, 0), (0xDEADBEEF, 0xDEADBEEF));
+    assert_eq!(hashlittle2(b"", 0, 0xDEADBEEF), (0xBD5B7DDE, 0xDEADBEEF));
+    assert_eq!(
+      hashlittle2(b"", 0xDEADBEEF, 0xDEADBEEF),
+      (0x9C093CCD, 0xBD5B7DDE)
+    );
+    assert_eq!(hashlittle2(text, 0, 0), (0x17770551, 0xCE7226E6));
+    assert_eq!(hashlittle2(text, 0, 1), (0xE3607CAE, 0xBD371DE4));
+    assert_eq!(hashlittle2(text, 1, 0), (0xCD628161, 0x6CBEA4B3));
+  }
+
+  #[test]
+  fn test_name_hash() {
+    for name in [
+      "(listfile)",
+      "staredit\\scenario.chk",
+      "Unit/Terran/marine.grp",
+      "",
+    ] {
+      let hashes: NameHash = NameHash::new(name);
+
+      assert_eq!(hashes.hash1, hash(name, HashType::NameA));
+      assert_eq!(hashes.hash2, hash(name, HashType::NameB));
+      assert_eq!(hashes.table, hash(name, HashType::Table));
+    }
+  }
 }
@@ -12,6 +12,11 @@ pub use self::decompress::decompress_sparse;
 pub use self::decompress::decompress_zlib;
 pub use self::decompress::CompressionFormat;
 pub use self::decrypt::decrypt;
+pub use self::decrypt::detect_key;
+pub use self::decrypt::encrypt;
 pub use self::hash::hash;
+pub use self::hash::hash_jenkins;
+pub use self::hash::normalize;
 pub use self::hash::HashType;
+pub use self::hash::NameHash;
 pub use self::time::convert_filetime;
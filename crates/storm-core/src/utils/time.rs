@@ -2,17 +2,25 @@ const WINDOWS_TICK: u64 = 10000000;
 const WINDOWS_EPOCH: u64 = 11644473600;
 const WINDOWS_OFFSET: u64 = WINDOWS_EPOCH * WINDOWS_TICK;
 
-// Convert windows FILETIME to unix time
+// Convert windows FILETIME to unix time, `None` if before the unix epoch
 //
 // https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime
-pub fn convert_filetime(lo: u32, hi: u32) -> u64 {
-  let mut time: u64 = ((hi as u64) << 32) + lo as u64;
+pub fn convert_filetime(lo: u32, hi: u32) -> Option<u64> {
+  let time: u64 = ((hi as u64) << 32) + lo as u64;
 
-  if time < WINDOWS_OFFSET {
-    panic!("FILETIME Out of Range")
-  }
-
-  time -= WINDOWS_OFFSET;
-  time /= WINDOWS_TICK;
   time
+    .checked_sub(WINDOWS_OFFSET)
+    .map(|time| time / WINDOWS_TICK)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_convert_filetime() {
+    assert_eq!(convert_filetime(0xD53E8000, 0x019DB1DE), Some(0));
+    assert_eq!(convert_filetime(0x00000000, 0x00000000), None);
+    assert_eq!(convert_filetime(0xFFFFFFFF, 0x00000000), None);
+  }
 }
@@ -0,0 +1,165 @@
+use storm_core::consts;
+use storm_core::error::Error;
+use storm_core::error::ErrorKind;
+use storm_core::extract::FileInfo;
+use storm_core::extract::Limits;
+use storm_core::parse::read_archive_from;
+use storm_core::parse::Handle;
+use storm_core::parse::OpenOptions;
+use storm_core::types::Archive;
+use storm_core::utils;
+use storm_core::utils::HashType;
+
+const EXISTS_SINGLE_UNIT: u32 = 0x81000000;
+
+// Build a V1 archive of stored, single-unit files
+fn archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+  let hsize: usize = files.len().next_power_of_two() * 2;
+
+  let mut output: Vec<u8> = vec![0; 0x20];
+  let mut htable: Vec<[u32; 4]> = vec![[u32::MAX; 4]; hsize];
+  let mut btable: Vec<[u32; 4]> = Vec::new();
+
+  for (index, (name, data)) in files.iter().enumerate() {
+    let start: usize = utils::hash(name, HashType::Table) as usize & (hsize - 1);
+
+    let slot: usize = (start..hsize)
+      .chain(0..start)
+      .find(|slot| htable[*slot][3] == u32::MAX)
+      .unwrap();
+
+    htable[slot] = [
+      utils::hash(name, HashType::NameA),
+      utils::hash(name, HashType::NameB),
+      0,
+      index as u32,
+    ];
+
+    btable.push([
+      output.len() as u32,
+      data.len() as u32,
+      data.len() as u32,
+      EXISTS_SINGLE_UNIT,
+    ]);
+
+    output.extend_from_slice(data);
+  }
+
+  let htable_offset: u32 = output.len() as u32;
+  let btable_offset: u32 = htable_offset + (hsize * 0x10) as u32;
+
+  for (table, key) in [
+    (&htable, consts::HASH_KEY_HT),
+    (&btable, consts::HASH_KEY_BT),
+  ] {
+    let mut data: Vec<u8> = table
+      .iter()
+      .flatten()
+      .flat_map(|value| value.to_le_bytes())
+      .collect();
+
+    utils::encrypt(&mut data, key).unwrap();
+    output.extend_from_slice(&data);
+  }
+
+  let header: [u32; 7] = [
+    0x20,
+    output.len() as u32,
+    0,
+    htable_offset,
+    btable_offset,
+    hsize as u32,
+    files.len() as u32,
+  ];
+
+  output[0x00..0x04].copy_from_slice(&consts::MAGIC_ID);
+
+  for (chunk, value) in output[0x04..0x20].chunks_exact_mut(4).zip(header) {
+    chunk.copy_from_slice(&value.to_le_bytes());
+  }
+
+  output
+}
+
+fn open(data: Vec<u8>, limits: Limits) -> Archive {
+  let mut options: OpenOptions = OpenOptions::new();
+
+  options.limits(limits);
+
+  read_archive_from(Handle::with_bytes("test.mpq", data).unwrap(), &options).unwrap()
+}
+
+#[test]
+fn test_total_size_ignores_metadata() {
+  let mut attributes: Vec<u8> = Vec::new();
+
+  attributes.extend_from_slice(&100_u32.to_le_bytes());
+  attributes.extend_from_slice(&3_u32.to_le_bytes());
+  attributes.extend_from_slice(&[0xAA; 12]);
+  attributes.extend_from_slice(&[0x01; 24]);
+
+  let data: Vec<u8> = archive(&[
+    ("file.txt", &[0x55; 0x40]),
+    ("(listfile)", b"file.txt\r\n"),
+    ("(attributes)", &attributes),
+  ]);
+
+  let mut limits: Limits = Limits::new();
+
+  limits.total_size(Some(0x40));
+
+  let archive: Archive = open(data, limits);
+
+  for _ in 0..16 {
+    let info: FileInfo = archive.find_file("file.txt").unwrap().info().unwrap();
+
+    assert_eq!(info.crc, Some(0xAAAAAAAA));
+    assert_eq!(info.time, None);
+  }
+
+  assert_eq!(archive.find_files("*").iter().count(), 3);
+  assert_eq!(archive.stats().files, 3);
+  assert_eq!(archive.extracted(), 0);
+
+  assert!(archive.load_file("file.txt").is_ok());
+  assert_eq!(archive.extracted(), 0x40);
+
+  let error: Error = archive.load_file("file.txt").unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::LimitTotalSize));
+  assert_eq!(archive.extracted(), 0x40);
+}
+
+#[test]
+fn test_oversized_table_rejected() {
+  let mut data: Vec<u8> = archive(&[("file.txt", &[0x55; 0x40])]);
+
+  // A hash table of 4 GiB, rejected before allocating it
+  data[0x18..0x1C].copy_from_slice(&0x0FFF_FFFF_u32.to_le_bytes());
+
+  let handle: Handle = Handle::with_bytes("test.mpq", data).unwrap();
+  let error: Error = read_archive_from(handle, &OpenOptions::new()).unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidLen(_)));
+}
+
+#[test]
+fn test_max_scan_from_offset() {
+  let data: Vec<u8> = [vec![0; 0x400], archive(&[("file.txt", &[0x55; 0x40])])].concat();
+  let mut options: OpenOptions = OpenOptions::new();
+
+  options.offset(0x200);
+  options.max_scan(Some(0x200));
+
+  let handle: Handle = Handle::with_bytes("test.mpq", data.clone()).unwrap();
+  let archive: Archive = read_archive_from(handle, &options).unwrap();
+
+  assert_eq!(archive.offset, 0x400);
+
+  // The header is past the scanned range
+  options.max_scan(Some(0x100));
+
+  let handle: Handle = Handle::with_bytes("test.mpq", data).unwrap();
+
+  assert!(read_archive_from(handle, &options).is_err());
+}
@@ -1,10 +1,13 @@
 use std::path::Path;
-use storm_chk::types::ChunkFile;
+use storm_chk::types::Scenario;
 use storm_core::error::Result;
+use storm_core::extract::Tracker;
 use storm_core::types::Archive;
 use storm_core::types::AttrFile;
+use storm_core::types::Diagnostic;
 use storm_core::types::File;
 use storm_core::types::ListFile;
+use storm_core::types::Severity;
 
 use crate::bundle::BundleData;
 use crate::bundle::BundleFile;
@@ -19,20 +22,31 @@ pub struct Bundle {
   pub archive: Archive,
   pub list: ListFile,
   pub attr: Option<AttrFile>,
-  pub schk: Option<ChunkFile>,
+  pub schk: Option<Scenario>,
   pub data: Box<[BundleFile]>,
+  pub(crate) diagnostics: Vec<Diagnostic>,
 }
 
 impl Bundle {
   pub fn open<P>(path: &P) -> Result<Self>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    Self::open_with(path, &mut Tracker::new())
+  }
+
+  /// Like [`Bundle::open`], reporting progress and checking for cancellation
+  /// before each file is loaded.
+  pub fn open_with<P>(path: &P, tracker: &mut Tracker<'_>) -> Result<Self>
   where
     P: AsRef<Path> + ?Sized,
   {
     let archive: Archive = Archive::open(path)?;
     let list: ListFile = fetch_list(&archive)?;
     let attr: Option<AttrFile> = fetch_attr(&archive)?;
-    let schk: Option<ChunkFile> = fetch_chk(&archive)?;
-    let data: Box<[BundleFile]> = fetch_data(&archive, &list)?;
+    let schk: Option<Scenario> = fetch_chk(&archive)?;
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let data: Box<[BundleFile]> = fetch_data(&archive, &list, &mut diagnostics, tracker)?;
 
     Ok(Self {
       archive,
@@ -40,8 +54,18 @@ impl Bundle {
       attr,
       schk,
       data,
+      diagnostics,
     })
   }
+
+  /// Returns an iterator over the diagnostics of the bundle and its archive.
+  pub fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> + '_ {
+    self
+      .archive
+      .diagnostics()
+      .iter()
+      .chain(self.diagnostics.iter())
+  }
 }
 
 fn fetch_list(archive: &Archive) -> Result<ListFile> {
@@ -60,7 +84,7 @@ fn fetch_attr(archive: &Archive) -> Result<Option<AttrFile>> {
   }
 }
 
-fn fetch_chk(archive: &Archive) -> Result<Option<ChunkFile>> {
+fn fetch_chk(archive: &Archive) -> Result<Option<Scenario>> {
   if let Ok(pointer) = archive.find_file("staredit\\scenario.chk") {
     pointer.try_into().map(Some)
   } else {
@@ -68,8 +92,14 @@ fn fetch_chk(archive: &Archive) -> Result<Option<ChunkFile>> {
   }
 }
 
-fn fetch_data(archive: &Archive, list: &ListFile) -> Result<Box<[BundleFile]>> {
+fn fetch_data(
+  archive: &Archive,
+  list: &ListFile,
+  diagnostics: &mut Vec<Diagnostic>,
+  tracker: &mut Tracker<'_>,
+) -> Result<Box<[BundleFile]>> {
   let mut output: Vec<BundleFile> = Vec::with_capacity(list.len());
+  let mut files: Vec<(&str, &Format)> = Vec::with_capacity(list.len());
 
   for entry in list.iter() {
     let name: &str = entry.as_utf8()?;
@@ -79,12 +109,27 @@ fn fetch_data(archive: &Archive, list: &ListFile) -> Result<Box<[BundleFile]>> {
     }
 
     let Some(format) = Format::find(name) else {
-      eprintln!("[unknown format]: {:?}", name);
+      Diagnostic::new(
+        "unknown-format",
+        Severity::Info,
+        format_args!("unknown format: {name:?}"),
+      )
+      .report(diagnostics);
+
       continue;
     };
 
+    files.push((name, format));
+  }
+
+  tracker.total(files.len() as u64);
+
+  for (name, format) in files {
+    tracker.start(name)?;
+
     // Parse raw content
     let file: File = archive.load_file(name)?;
+    let size: u64 = file.size() as u64;
 
     // Parse format-specific content
     let data: BundleData = if let Some(parser) = format.parser() {
@@ -97,6 +142,8 @@ fn fetch_data(archive: &Archive, list: &ListFile) -> Result<Box<[BundleFile]>> {
       data,
       name: name.to_owned(),
     });
+
+    tracker.finish(name, size);
   }
 
   Ok(output.into_boxed_slice())
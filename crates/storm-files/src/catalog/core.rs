@@ -0,0 +1,347 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fs;
+use std::fs::DirEntry;
+use std::fs::FileType;
+use std::fs::Metadata;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+use storm_core::consts::BT_MASK;
+use storm_core::error::Result;
+use storm_core::extract::FilePtr;
+use storm_core::extract::Pattern;
+use storm_core::extract::Tracker;
+use storm_core::types::Archive;
+use storm_core::types::BTableEntry;
+use storm_utils::utils::DigestMd5;
+
+use crate::catalog::store;
+use crate::catalog::CatalogArchive;
+use crate::catalog::CatalogFile;
+
+only_serde! {
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// Catalog
+// =============================================================================
+
+/// An index of the content of every archive in a directory tree.
+///
+/// The catalog is updated incrementally - only archives added or modified
+/// (by mtime and size) since the last update are opened again.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Catalog {
+  /// Every archive found, keyed by path.
+  pub archives: BTreeMap<PathBuf, CatalogArchive>,
+}
+
+impl Catalog {
+  /// File extensions (lowercase) of the archives added by [`Catalog::update`].
+  pub const EXTENSIONS: &'static [&'static str] = &["mpq", "scm", "scx", "s2ma", "sc2map"];
+
+  /// Create a new, empty `Catalog`.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      archives: BTreeMap::new(),
+    }
+  }
+
+  /// Load a catalog from the index file at the given `path`.
+  pub fn load<P>(path: &P) -> Result<Self>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    store::read(&fs::read(path)?)
+  }
+
+  /// Save the catalog to an index file at the given `path`.
+  pub fn save<P>(&self, path: &P) -> Result<()>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    fs::write(path, store::write(self)).map_err(Into::into)
+  }
+
+  /// Walk the directory tree at `root` and record every new or modified
+  /// archive.
+  ///
+  /// Archives under `root` that no longer exist are removed from the catalog.
+  /// Symbolic links to directories are not followed. Directories and archives
+  /// that can't be read are recorded as failures instead of aborting the
+  /// update. Returns the number of archives (re-)opened.
+  pub fn update<P>(&mut self, root: &P) -> Result<usize>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    self.update_with(root, &mut Tracker::new())
+  }
+
+  /// Like [`Catalog::update`], reporting progress and checking for
+  /// cancellation before each archive.
+  ///
+  /// Progress counts archives and archive file bytes. Archives updated before
+  /// a cancellation are kept.
+  pub fn update_with<P>(&mut self, root: &P, tracker: &mut Tracker<'_>) -> Result<usize>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    let root: &Path = root.as_ref();
+    let mut paths: BTreeSet<PathBuf> = BTreeSet::new();
+    let mut failed: Vec<(PathBuf, String)> = Vec::new();
+    let mut count: usize = 0;
+
+    for entry in fs::read_dir(root)? {
+      walk_entry(root, entry, &mut paths, &mut failed);
+    }
+
+    // Forget archives that were removed, keeping those in unreadable directories
+    self.archives.retain(|path, _| {
+      !path.starts_with(root)
+        || paths.contains(path)
+        || failed
+          .iter()
+          .any(|(dir, _)| path != dir && path.starts_with(dir))
+    });
+
+    for (path, error) in failed {
+      self.archives.insert(path, CatalogArchive::failure(error));
+    }
+
+    tracker.total(paths.len() as u64);
+
+    for path in paths {
+      let name: String = path.to_string_lossy().into_owned();
+
+      tracker.start(&name)?;
+
+      let meta: Metadata = match fs::metadata(&path) {
+        Ok(meta) => meta,
+        Err(error) => {
+          self
+            .archives
+            .insert(path, CatalogArchive::failure(error.to_string()));
+          tracker.finish(&name, 0);
+          continue;
+        }
+      };
+
+      let mtime: u64 = modified(&meta);
+
+      if let Some(archive) = self.archives.get(&path) {
+        if archive.mtime == mtime && archive.size == meta.len() {
+          tracker.finish(&name, 0);
+          continue;
+        }
+      }
+
+      let archive: CatalogArchive = scan(&path, mtime, meta.len());
+
+      self.archives.insert(path, archive);
+      tracker.finish(&name, meta.len());
+      count += 1;
+    }
+
+    Ok(count)
+  }
+
+  /// Returns an iterator over the files with names matching `pattern`, along
+  /// with the path of the archive containing them.
+  pub fn find_name<P>(&self, pattern: P) -> impl Iterator<Item = (&Path, &CatalogFile)> + '_
+  where
+    P: Into<Pattern>,
+  {
+    let pattern: Pattern = pattern.into();
+
+    self
+      .files()
+      .filter(move |(_, file)| pattern.is_match(&file.name))
+  }
+
+  /// Returns an iterator over the files with the given content `md5`, along
+  /// with the path of the archive containing them.
+  pub fn find_md5(&self, md5: DigestMd5) -> impl Iterator<Item = (&Path, &CatalogFile)> + '_ {
+    self.files().filter(move |(_, file)| file.md5 == Some(md5))
+  }
+
+  /// Returns every file content found more than once, with all its copies.
+  pub fn duplicates(&self) -> BTreeMap<DigestMd5, Vec<(&Path, &CatalogFile)>> {
+    let mut output: HashMap<DigestMd5, Vec<(&Path, &CatalogFile)>> = HashMap::new();
+
+    for (path, file) in self.files() {
+      if let Some(md5) = file.md5 {
+        output.entry(md5).or_default().push((path, file));
+      }
+    }
+
+    output
+      .into_iter()
+      .filter(|(_, files)| files.len() > 1)
+      .collect()
+  }
+
+  /// Returns an iterator over the archives that failed to parse (and the
+  /// directories that couldn't be read), along with the reason.
+  pub fn failures(&self) -> impl Iterator<Item = (&Path, &str)> + '_ {
+    self
+      .archives
+      .iter()
+      .filter_map(|(path, archive)| Some((path.as_path(), archive.error.as_deref()?)))
+  }
+
+  /// Returns an iterator over every file, along with the path of the archive
+  /// containing it.
+  pub fn files(&self) -> impl Iterator<Item = (&Path, &CatalogFile)> + '_ {
+    self
+      .archives
+      .iter()
+      .flat_map(|(path, archive)| archive.files.iter().map(move |file| (path.as_path(), file)))
+  }
+}
+
+only_serde! {
+  impl Serialize for Catalog {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      self.archives.serialize(serializer)
+    }
+  }
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+// Collect the paths of every archive in the directory tree, along with the
+// directories that couldn't be read
+fn walk(path: &Path, output: &mut BTreeSet<PathBuf>, failed: &mut Vec<(PathBuf, String)>) {
+  match fs::read_dir(path) {
+    Ok(entries) => {
+      for entry in entries {
+        walk_entry(path, entry, output, failed);
+      }
+    }
+    Err(error) => {
+      failed.push((path.to_path_buf(), error.to_string()));
+    }
+  }
+}
+
+fn walk_entry(
+  parent: &Path,
+  entry: io::Result<DirEntry>,
+  output: &mut BTreeSet<PathBuf>,
+  failed: &mut Vec<(PathBuf, String)>,
+) {
+  let entry: DirEntry = match entry {
+    Ok(entry) => entry,
+    Err(error) => {
+      failed.push((parent.to_path_buf(), error.to_string()));
+      return;
+    }
+  };
+
+  let path: PathBuf = entry.path();
+
+  // Doesn't follow symbolic links, which could form a cycle
+  let is_dir: bool = entry.file_type().is_ok_and(|kind: FileType| kind.is_dir());
+
+  if is_dir {
+    walk(&path, output, failed);
+  } else if is_archive(&path) {
+    output.insert(path);
+  }
+}
+
+fn is_archive(path: &Path) -> bool {
+  let Some(extension) = path.extension().and_then(|extension| extension.to_str()) else {
+    return false;
+  };
+
+  Catalog::EXTENSIONS
+    .iter()
+    .any(|known| extension.eq_ignore_ascii_case(known))
+}
+
+fn modified(meta: &Metadata) -> u64 {
+  meta
+    .modified()
+    .ok()
+    .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+    .map_or(0, |time| time.as_nanos() as u64)
+}
+
+fn scan(path: &Path, mtime: u64, size: u64) -> CatalogArchive {
+  let mut output: CatalogArchive = CatalogArchive {
+    mtime,
+    size,
+    error: None,
+    files: Vec::new(),
+  };
+
+  let archive: Archive = match Archive::open(path) {
+    Ok(archive) => archive,
+    Err(error) => {
+      output.error = Some(error.to_string());
+      return output;
+    }
+  };
+
+  let mut named: Vec<bool> = vec![false; archive.btable().len()];
+
+  for pointer in archive.find_files("*").iter() {
+    let entry: &BTableEntry = pointer.btentry();
+
+    named[pointer.block() as usize] = true;
+
+    output.files.push(CatalogFile {
+      name: pointer.name().to_owned(),
+      comp_size: entry.comp_size,
+      file_size: entry.file_size,
+      bitflags: entry.bitflags,
+      md5: FilePtr::read(pointer)
+        .ok()
+        .map(|data| DigestMd5::new(&data)),
+    });
+  }
+
+  // Files missing from the listfile can't be read without their name
+  for entry in archive.htable().iter() {
+    if entry.is_empty() {
+      continue;
+    }
+
+    let block: usize = (entry.position & BT_MASK) as usize;
+
+    let Some(btentry) = archive.btable().get(block) else {
+      continue;
+    };
+
+    if !btentry.is_exists() || named[block] {
+      continue;
+    }
+
+    named[block] = true;
+
+    output.files.push(CatalogFile {
+      name: unnamed(block),
+      comp_size: btentry.comp_size,
+      file_size: btentry.file_size,
+      bitflags: btentry.bitflags,
+      md5: None,
+    });
+  }
+
+  output
+}
+
+// Placeholder name of a file missing from the listfile
+fn unnamed(block: usize) -> String {
+  format!("File{block:08}.xxx")
+}
@@ -0,0 +1,91 @@
+use storm_core::types::BTableEntryFlags;
+use storm_utils::utils::DigestMd5;
+
+only_serde! {
+  use serde::ser::SerializeStruct;
+  use serde::Serialize;
+  use serde::Serializer;
+}
+
+// =============================================================================
+// Catalog Archive
+// =============================================================================
+
+/// An archive recorded in a [`Catalog`][crate::catalog::Catalog].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CatalogArchive {
+  /// Last modification time of the archive file (nanoseconds since the unix
+  /// epoch).
+  pub mtime: u64,
+  /// The size of the archive file (bytes).
+  pub size: u64,
+  /// The reason the archive (or directory) failed to be read or parsed, if it
+  /// did.
+  pub error: Option<String>,
+  /// Every file found in the archive.
+  pub files: Vec<CatalogFile>,
+}
+
+impl CatalogArchive {
+  // An archive (or directory) that couldn't be read
+  pub(crate) const fn failure(error: String) -> Self {
+    Self {
+      mtime: 0,
+      size: 0,
+      error: Some(error),
+      files: Vec::new(),
+    }
+  }
+
+  /// Returns `true` if the archive failed to parse.
+  #[inline]
+  pub const fn is_error(&self) -> bool {
+    self.error.is_some()
+  }
+}
+
+only_serde! {
+  impl Serialize for CatalogArchive {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("CatalogArchive", 4)?;
+      state.serialize_field("mtime", &self.mtime)?;
+      state.serialize_field("size", &self.size)?;
+      state.serialize_field("error", &self.error)?;
+      state.serialize_field("files", &self.files)?;
+      state.end()
+    }
+  }
+}
+
+// =============================================================================
+// Catalog File
+// =============================================================================
+
+/// A file recorded in a [`Catalog`][crate::catalog::Catalog].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CatalogFile {
+  /// The name of the file.
+  pub name: String,
+  /// The compressed size of the file.
+  pub comp_size: u32,
+  /// The uncompressed size of the file.
+  pub file_size: u32,
+  /// The block table flags of the file.
+  pub bitflags: BTableEntryFlags,
+  /// MD5 of the file data (uncompressed), if it could be extracted.
+  pub md5: Option<DigestMd5>,
+}
+
+only_serde! {
+  impl Serialize for CatalogFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      let mut state: S::SerializeStruct = serializer.serialize_struct("CatalogFile", 5)?;
+      state.serialize_field("name", &self.name)?;
+      state.serialize_field("comp_size", &self.comp_size)?;
+      state.serialize_field("file_size", &self.file_size)?;
+      state.serialize_field("bitflags", &self.bitflags)?;
+      state.serialize_field("md5", &self.md5)?;
+      state.end()
+    }
+  }
+}
@@ -0,0 +1,7 @@
+mod core;
+mod entry;
+mod store;
+
+pub use self::core::Catalog;
+pub use self::entry::CatalogArchive;
+pub use self::entry::CatalogFile;
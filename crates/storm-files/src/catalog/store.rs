@@ -0,0 +1,205 @@
+//! Catalog index file format.
+//!
+//! All values are little-endian; strings are UTF-8 with a `u32` length prefix.
+//! Paths are stored like strings, with the platform encoding of the path
+//! (see [`OsStr::as_encoded_bytes`]) instead of UTF-8.
+//!
+//! ```text
+//! magic    [u8; 4]  "STCA"
+//! version  u32
+//! count    u32
+//! archives:
+//!   path   path
+//!   mtime  u64
+//!   size   u64
+//!   error  u8 (0/1) [+ string]
+//!   count  u32
+//!   files:
+//!     name       string
+//!     comp_size  u32
+//!     file_size  u32
+//!     bitflags   u32
+//!     md5        u8 (0/1) [+ [u8; 16]]
+//! ```
+
+use std::ffi::OsStr;
+use std::io::Cursor;
+use std::path::PathBuf;
+use storm_core::error::Error;
+use storm_core::error::ErrorKind;
+use storm_core::error::Result;
+use storm_core::types::BTableEntryFlags;
+use storm_utils::traits::ReadExt;
+use storm_utils::utils::DigestMd5;
+
+use crate::catalog::Catalog;
+use crate::catalog::CatalogArchive;
+use crate::catalog::CatalogFile;
+
+const MAGIC: [u8; 4] = *b"STCA";
+const VERSION: u32 = 1;
+
+// =============================================================================
+// Read
+// =============================================================================
+
+pub(crate) fn read(data: &[u8]) -> Result<Catalog> {
+  let mut reader: Cursor<&[u8]> = Cursor::new(data);
+
+  if reader.read_array_u8::<4>()? != MAGIC {
+    return Err(Error::new(ErrorKind::InvalidMagic));
+  }
+
+  let version: u32 = reader.read_u32_le()?;
+
+  if version != VERSION {
+    return Err(Error::message(format!(
+      "unsupported catalog version: {version}"
+    )));
+  }
+
+  let mut catalog: Catalog = Catalog::new();
+
+  for _ in 0..reader.read_u32_le()? {
+    let path: PathBuf = read_path(&mut reader)?;
+    let mtime: u64 = reader.read_u64_le()?;
+    let size: u64 = reader.read_u64_le()?;
+
+    let error: Option<String> = match reader.read_u8()? {
+      0 => None,
+      _ => Some(read_string(&mut reader)?),
+    };
+
+    let count: u32 = reader.read_u32_le()?;
+    let mut files: Vec<CatalogFile> = Vec::new();
+
+    for _ in 0..count {
+      files.push(read_file(&mut reader)?);
+    }
+
+    catalog.archives.insert(
+      path,
+      CatalogArchive {
+        mtime,
+        size,
+        error,
+        files,
+      },
+    );
+  }
+
+  Ok(catalog)
+}
+
+fn read_file(reader: &mut Cursor<&[u8]>) -> Result<CatalogFile> {
+  let name: String = read_string(reader)?;
+  let comp_size: u32 = reader.read_u32_le()?;
+  let file_size: u32 = reader.read_u32_le()?;
+  let bitflags: BTableEntryFlags = BTableEntryFlags::from_bits_retain(reader.read_u32_le()?);
+
+  let md5: Option<DigestMd5> = match reader.read_u8()? {
+    0 => None,
+    _ => Some(DigestMd5::from(
+      reader.read_array_u8::<{ DigestMd5::SIZE }>()?,
+    )),
+  };
+
+  Ok(CatalogFile {
+    name,
+    comp_size,
+    file_size,
+    bitflags,
+    md5,
+  })
+}
+
+fn read_string(reader: &mut Cursor<&[u8]>) -> Result<String> {
+  String::from_utf8(read_bytes(reader)?.to_vec()).map_err(Into::into)
+}
+
+#[cfg(unix)]
+fn read_path(reader: &mut Cursor<&[u8]>) -> Result<PathBuf> {
+  use std::os::unix::ffi::OsStrExt;
+
+  Ok(PathBuf::from(OsStr::from_bytes(read_bytes(reader)?)))
+}
+
+// Note: Paths that are not valid UTF-8 can't be decoded safely elsewhere
+#[cfg(not(unix))]
+fn read_path(reader: &mut Cursor<&[u8]>) -> Result<PathBuf> {
+  read_string(reader).map(PathBuf::from)
+}
+
+fn read_bytes<'a>(reader: &mut Cursor<&'a [u8]>) -> Result<&'a [u8]> {
+  let size: usize = reader.read_u32_le()? as usize;
+  let start: usize = reader.position() as usize;
+  let data: &'a [u8] = reader.get_ref();
+
+  let Some(bytes) = data.get(start..start + size) else {
+    return Err(Error::new(ErrorKind::InvalidLen("catalog string")));
+  };
+
+  reader.set_position((start + size) as u64);
+
+  Ok(bytes)
+}
+
+// =============================================================================
+// Write
+// =============================================================================
+
+pub(crate) fn write(catalog: &Catalog) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::new();
+
+  output.extend_from_slice(&MAGIC);
+  output.extend_from_slice(&VERSION.to_le_bytes());
+  output.extend_from_slice(&(catalog.archives.len() as u32).to_le_bytes());
+
+  for (path, archive) in catalog.archives.iter() {
+    write_bytes(&mut output, path.as_os_str().as_encoded_bytes());
+
+    output.extend_from_slice(&archive.mtime.to_le_bytes());
+    output.extend_from_slice(&archive.size.to_le_bytes());
+
+    match archive.error {
+      Some(ref error) => {
+        output.push(1);
+        write_string(&mut output, error);
+      }
+      None => {
+        output.push(0);
+      }
+    }
+
+    output.extend_from_slice(&(archive.files.len() as u32).to_le_bytes());
+
+    for file in archive.files.iter() {
+      write_string(&mut output, &file.name);
+
+      output.extend_from_slice(&file.comp_size.to_le_bytes());
+      output.extend_from_slice(&file.file_size.to_le_bytes());
+      output.extend_from_slice(&file.bitflags.bits().to_le_bytes());
+
+      match file.md5 {
+        Some(ref md5) => {
+          output.push(1);
+          output.extend_from_slice(md5.as_slice());
+        }
+        None => {
+          output.push(0);
+        }
+      }
+    }
+  }
+
+  output
+}
+
+fn write_string(output: &mut Vec<u8>, value: &str) {
+  write_bytes(output, value.as_bytes());
+}
+
+fn write_bytes(output: &mut Vec<u8>, value: &[u8]) {
+  output.extend_from_slice(&(value.len() as u32).to_le_bytes());
+  output.extend_from_slice(value);
+}
@@ -6,5 +6,6 @@
 extern crate storm_utils;
 
 pub mod bundle;
+pub mod catalog;
 pub mod document;
 pub mod image;
@@ -3,6 +3,7 @@ mod json;
 mod parse;
 mod read;
 mod seek;
+mod write;
 
 #[cfg(feature = "json")]
 pub use self::json::ExportJson;
@@ -10,3 +11,4 @@ pub use self::parse::Parse;
 pub use self::parse::ParseContext;
 pub use self::read::ReadExt;
 pub use self::seek::SeekExt;
+pub use self::write::WriteExt;
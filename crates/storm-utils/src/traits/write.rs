@@ -0,0 +1,80 @@
+use byteorder::WriteBytesExt;
+use byteorder::BE;
+use byteorder::LE;
+use std::io;
+use std::io::Write;
+
+pub trait WriteExt: Write {
+  #[inline]
+  fn write_bytes(&mut self, buffer: &[u8]) -> io::Result<()> {
+    <Self as Write>::write_all(self, buffer)
+  }
+
+  // ===========================================================================
+  // Integer Writers
+  // ===========================================================================
+
+  #[inline]
+  fn write_u8(&mut self, value: u8) -> io::Result<()> {
+    WriteBytesExt::write_u8(self, value)
+  }
+
+  #[inline]
+  fn write_u16_le(&mut self, value: u16) -> io::Result<()> {
+    WriteBytesExt::write_u16::<LE>(self, value)
+  }
+
+  #[inline]
+  fn write_u32_le(&mut self, value: u32) -> io::Result<()> {
+    WriteBytesExt::write_u32::<LE>(self, value)
+  }
+
+  #[inline]
+  fn write_u64_le(&mut self, value: u64) -> io::Result<()> {
+    WriteBytesExt::write_u64::<LE>(self, value)
+  }
+
+  #[inline]
+  fn write_u16_be(&mut self, value: u16) -> io::Result<()> {
+    WriteBytesExt::write_u16::<BE>(self, value)
+  }
+
+  #[inline]
+  fn write_u32_be(&mut self, value: u32) -> io::Result<()> {
+    WriteBytesExt::write_u32::<BE>(self, value)
+  }
+
+  #[inline]
+  fn write_u64_be(&mut self, value: u64) -> io::Result<()> {
+    WriteBytesExt::write_u64::<BE>(self, value)
+  }
+
+  // ===========================================================================
+  // Slice Writers
+  // ===========================================================================
+
+  #[inline]
+  fn write_slice<T, F>(&mut self, slice: &[T], f: F) -> io::Result<()>
+  where
+    F: Fn(&mut Self, &T) -> io::Result<()>,
+  {
+    slice.iter().try_for_each(|item| f(self, item))
+  }
+
+  #[inline]
+  fn write_slice_u16(&mut self, slice: &[u16]) -> io::Result<()> {
+    self.write_slice(slice, |this, item| this.write_u16_le(*item))
+  }
+
+  #[inline]
+  fn write_slice_u32(&mut self, slice: &[u32]) -> io::Result<()> {
+    self.write_slice(slice, |this, item| this.write_u32_le(*item))
+  }
+
+  #[inline]
+  fn write_slice_u64(&mut self, slice: &[u64]) -> io::Result<()> {
+    self.write_slice(slice, |this, item| this.write_u64_le(*item))
+  }
+}
+
+impl<W: WriteBytesExt + ?Sized> WriteExt for W {}